@@ -12,7 +12,7 @@
 use policy_core::{
     actions,
     audit::{AuditEvent, AuditEventKind, AuditOutcome, AuditTrail},
-    Authenticated, Authorized, PolicyGate, Principal, RequestMeta,
+    AllowAuthenticated, Authenticated, Authorized, PolicyGate, Principal, RequestMeta,
 };
 
 fn main() {
@@ -57,13 +57,11 @@ fn main() {
 
     let meta = RequestMeta {
         request_id: "req-admin-001".to_string(),
-        principal: Some(Principal {
-            id: "user-admin".to_string(),
-            name: "Admin User".to_string(),
-        }),
+        principal: Some(Principal::new("user-admin", "Admin User")),
     };
 
     let ctx_result = PolicyGate::new(meta.clone())
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action(actions::AUDIT))
         .build();
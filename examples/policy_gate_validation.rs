@@ -8,7 +8,9 @@
 //!
 //! Run with: `cargo run --example policy_gate_validation`
 
-use policy_core::{actions, Authenticated, Authorized, PolicyGate, Principal, RequestMeta};
+use policy_core::{
+    actions, AllowAuthenticated, Authenticated, Authorized, PolicyGate, Principal, RequestMeta,
+};
 
 fn main() {
     println!("=== Policy Gate Validation Example ===\n");
@@ -33,13 +35,11 @@ fn main() {
     println!("\n--- Scenario 2: Authenticated but Unauthorized ---");
     let authenticated_meta = RequestMeta {
         request_id: "req-002".to_string(),
-        principal: Some(Principal {
-            id: "user-123".to_string(),
-            name: "Alice".to_string(),
-        }),
+        principal: Some(Principal::new("user-123", "Alice")),
     };
 
     let result = PolicyGate::new(authenticated_meta.clone())
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action(actions::LOG))
         .build();
@@ -53,13 +53,11 @@ fn main() {
     println!("\n--- Scenario 3: Multiple Capabilities ---");
     let authorized_meta = RequestMeta {
         request_id: "req-003".to_string(),
-        principal: Some(Principal {
-            id: "admin-456".to_string(),
-            name: "Bob".to_string(),
-        }),
+        principal: Some(Principal::new("admin-456", "Bob")),
     };
 
     let authorized_ctx = PolicyGate::new(authorized_meta.clone())
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action(actions::LOG))
         .require(Authorized::for_action(actions::HTTP))
@@ -90,6 +88,7 @@ fn main() {
     // Scenario 4: Partial authorization (only some capabilities)
     println!("\n--- Scenario 4: Partial Authorization ---");
     let partial_authorized = PolicyGate::new(authenticated_meta.clone())
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action(actions::LOG))
         // Note: NOT authorized for HTTP or AUDIT
@@ -114,6 +113,7 @@ fn main() {
     // Scenario 5: Multiple policy requirements
     println!("\n--- Scenario 5: Multiple Policy Requirements ---");
     let result = PolicyGate::new(authorized_meta.clone())
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action(actions::LOG))
         .require(Authorized::for_action(actions::AUDIT))
@@ -10,18 +10,15 @@
 //! Run with: `cargo run --example web_request_flow`
 
 use policy_core::{
-    actions, Authenticated, Authorized, PolicyGate, Principal, RequestMeta, Sanitizer, Sink,
-    StringSanitizer, Tainted, VecSink,
+    actions, AllowAuthenticated, Authenticated, Authorized, PolicyGate, Principal, RequestMeta,
+    Sanitizer, Sink, StringSanitizer, Tainted, VecSink,
 };
 
 /// Simulates extracting metadata from an HTTP request
 fn extract_request_metadata(auth_header: Option<&str>) -> RequestMeta {
     let principal = auth_header.and_then(|header| {
         if header.starts_with("Bearer ") {
-            Some(Principal {
-                id: "user-123".to_string(),
-                name: "Alice".to_string(),
-            })
+            Some(Principal::new("user-123", "Alice"))
         } else {
             None
         }
@@ -66,6 +63,7 @@ fn handle_user_input_request(
 
     // Step 3: Validate policies
     let ctx = PolicyGate::new(metadata)
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action(actions::LOG))
         .build()?;
@@ -0,0 +1,623 @@
+//! Procedural macros for `policy-core`.
+//!
+//! This crate is not meant to be used directly. Enable the `macros` feature of
+//! `policy-core` and import the macros from there:
+//!
+//! ```toml
+//! [dependencies]
+//! policy-core = { version = "1", features = ["macros"] }
+//! ```
+//!
+//! # Macros
+//!
+//! - [`macro@require`]: Declares a handler's policy requirements and generates the
+//!   `extract_authed` → `PolicyGate` → `build()` sequence
+//! - [`macro@Sanitize`]: Derives `policy_core::Sanitize`, sanitizing a whole
+//!   struct from a request's tainted inputs
+
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{
+    parenthesized, parse_macro_input, Data, DeriveInput, Expr, Fields, FnArg, GenericArgument,
+    Ident, ItemFn, LitStr, Path, PathArguments, Token, Type,
+};
+
+/// Declares the policy requirements of a web handler.
+///
+/// The annotated function takes the authorized context and the request's
+/// tainted inputs. The macro rewrites it into a function that takes a
+/// `&RequestAdapter`, runs the gate, and only then calls the original body:
+///
+/// ```text
+/// extract_authed(adapter)?                      // fails if no principal
+/// PolicyGate::new(adapter.extract_metadata())
+///     .with_authorizer(..)                      // if `authorizer = ..` is given
+///     .require(..)                              // one per requirement
+///     .build()?                                 // fails on any violation
+/// body(ctx, extraction.inputs)
+/// ```
+///
+/// The function must return `Result<T, E>` where `E: From<Violation>`.
+///
+/// # Requirements
+///
+/// | Attribute item           | Generated call                                   |
+/// |--------------------------|--------------------------------------------------|
+/// | `Authenticated`          | `.require(Authenticated)`                        |
+/// | `Authorized("log")`      | `.require(Authorized::for_action("log"))`        |
+/// | `HasRole("admin")`       | `.require(HasRole::named("admin"))`              |
+/// | `HasScope("items:read")` | `.require(HasScope::named("items:read"))`        |
+/// | `Grant(LogCap)`          | `.grant::<LogCap>()` (typed capability set)      |
+/// | `authorizer = expr`      | `.with_authorizer(expr)`                         |
+///
+/// Without an `authorizer` item the gate keeps its default `DenyAll`
+/// authorizer, so every `Authorized` or `Grant` item fails.
+///
+/// Requirements stay visible at the top of the handler, and the generated
+/// gate is the same builder chain you would write by hand, so
+/// `PolicyGate` remains the single path to a `Ctx<Authorized>`.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::web::{RequestAdapter, TaintedInputs};
+/// use policy_core::{require, AllowAuthenticated, Ctx, Principal, Violation};
+///
+/// #[require(Authenticated, Authorized("log"), authorizer = AllowAuthenticated)]
+/// fn greet(ctx: Ctx, inputs: TaintedInputs) -> Result<String, Violation> {
+///     ctx.log()?.info(format_args!("greeting"));
+///     Ok(format!("hello {}", ctx.principal().unwrap().name))
+/// }
+///
+/// let mut adapter = RequestAdapter::new("req-1".to_string());
+/// adapter.set_principal(Some(Principal::new("u1", "Alice")));
+///
+/// assert_eq!(greet(&adapter).unwrap(), "hello Alice");
+/// assert!(greet(&RequestAdapter::new("req-2".to_string())).is_err());
+/// ```
+///
+/// Unknown requirements are rejected at compile time:
+///
+/// ```compile_fail
+/// use policy_core::web::TaintedInputs;
+/// use policy_core::{require, Ctx, Violation};
+///
+/// #[require(Authenticated, IsAdmin)]
+/// fn handler(ctx: Ctx, inputs: TaintedInputs) -> Result<(), Violation> {
+///     Ok(())
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn require(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let requirements = parse_macro_input!(attr as Requirements);
+    let function = parse_macro_input!(item as ItemFn);
+
+    expand_require(requirements, function)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Comma-separated list of requirements in `#[require(...)]`.
+struct Requirements {
+    items: Punctuated<Requirement, Token![,]>,
+}
+
+impl Parse for Requirements {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        Ok(Self {
+            items: Punctuated::parse_terminated(input)?,
+        })
+    }
+}
+
+/// A single item in `#[require(...)]`.
+enum Requirement {
+    Authenticated(Span),
+    Authorized(LitStr),
+    HasRole(LitStr),
+    HasScope(LitStr),
+    Grant(Path),
+    Authorizer(Expr),
+}
+
+impl Parse for Requirement {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+
+        if name == "authorizer" {
+            input.parse::<Token![=]>()?;
+            return Ok(Requirement::Authorizer(input.parse()?));
+        }
+        if name == "Authenticated" {
+            return Ok(Requirement::Authenticated(name.span()));
+        }
+
+        let content;
+        match name.to_string().as_str() {
+            "Authorized" => {
+                parenthesized!(content in input);
+                Ok(Requirement::Authorized(content.parse()?))
+            }
+            "HasRole" => {
+                parenthesized!(content in input);
+                Ok(Requirement::HasRole(content.parse()?))
+            }
+            "HasScope" => {
+                parenthesized!(content in input);
+                Ok(Requirement::HasScope(content.parse()?))
+            }
+            "Grant" => {
+                parenthesized!(content in input);
+                Ok(Requirement::Grant(content.parse()?))
+            }
+            _ => Err(syn::Error::new(
+                name.span(),
+                "unknown requirement; expected `Authenticated`, `Authorized(\"..\")`, \
+                 `HasRole(\"..\")`, `HasScope(\"..\")`, `Grant(Cap)` or `authorizer = ..`",
+            )),
+        }
+    }
+}
+
+impl Requirement {
+    /// The builder call this requirement adds to the gate chain.
+    fn to_call(&self) -> TokenStream2 {
+        match self {
+            Requirement::Authenticated(span) => {
+                quote_spanned!(*span=> .require(::policy_core::Authenticated))
+            }
+            Requirement::Authorized(action) => {
+                quote_spanned!(action.span()=> .require(::policy_core::Authorized::for_action(#action)))
+            }
+            Requirement::HasRole(role) => {
+                quote_spanned!(role.span()=> .require(::policy_core::HasRole::named(#role)))
+            }
+            Requirement::HasScope(scope) => {
+                quote_spanned!(scope.span()=> .require(::policy_core::HasScope::named(#scope)))
+            }
+            Requirement::Grant(cap) => quote_spanned!(cap.span()=> .grant::<#cap>()),
+            Requirement::Authorizer(expr) => quote_spanned!(expr.span()=> .with_authorizer(#expr)),
+        }
+    }
+}
+
+fn expand_require(requirements: Requirements, function: ItemFn) -> syn::Result<TokenStream2> {
+    if requirements.items.is_empty() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "#[require] needs at least one requirement",
+        ));
+    }
+    if requirements
+        .items
+        .iter()
+        .filter(|r| matches!(r, Requirement::Authorizer(_)))
+        .count()
+        > 1
+    {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "#[require] accepts at most one `authorizer = ..`",
+        ));
+    }
+
+    let sig = &function.sig;
+    if let Some(FnArg::Receiver(receiver)) = sig.inputs.first() {
+        return Err(syn::Error::new(
+            receiver.span(),
+            "#[require] handlers must be free functions, not methods",
+        ));
+    }
+    if !sig.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            sig.generics.span(),
+            "#[require] does not support generic handlers",
+        ));
+    }
+    if sig.inputs.len() != 2 {
+        return Err(syn::Error::new(
+            sig.inputs.span(),
+            "#[require] handlers take exactly two parameters: (ctx, inputs: TaintedInputs)",
+        ));
+    }
+
+    let attrs = &function.attrs;
+    let vis = &function.vis;
+    let name = &sig.ident;
+    let asyncness = &sig.asyncness;
+    let output = &sig.output;
+    let inputs = &sig.inputs;
+    let body = &function.block;
+    let await_inner = sig.asyncness.map(|_| quote!(.await));
+
+    // Authorizer first so it applies regardless of where it appears in the list
+    let mut calls: Vec<TokenStream2> = requirements
+        .items
+        .iter()
+        .filter(|r| matches!(r, Requirement::Authorizer(_)))
+        .map(Requirement::to_call)
+        .collect();
+    calls.extend(
+        requirements
+            .items
+            .iter()
+            .filter(|r| !matches!(r, Requirement::Authorizer(_)))
+            .map(Requirement::to_call),
+    );
+
+    Ok(quote! {
+        #(#attrs)*
+        #vis #asyncness fn #name(
+            adapter: &::policy_core::web::RequestAdapter,
+        ) #output {
+            #asyncness fn __policy_core_handler(#inputs) #output #body
+
+            let extraction = ::policy_core::web::extract_authed(adapter)?;
+            let meta = ::policy_core::web::ExtractMetadata::extract_metadata(adapter);
+            let ctx = ::policy_core::PolicyGate::new(meta)
+                #(#calls)*
+                .build()?;
+
+            __policy_core_handler(ctx, extraction.inputs) #await_inner
+        }
+    })
+}
+
+/// Derives `policy_core::Sanitize` for a struct with named fields.
+///
+/// Every field declares its sanitizer and where its value comes from:
+///
+/// ```text
+/// #[input(with = <sanitizer expr>)]                  // query parameter named like the field
+/// #[input(query = "q", with = <sanitizer expr>)]     // query parameter "q"
+/// #[input(header = "x-tenant", with = ...)]          // header (exact name)
+/// #[input(path = "id", with = ...)]                  // path parameter
+/// ```
+///
+/// `query`, `header` and `path` without a name use the field name. The
+/// sanitizer must implement `Sanitizer<String, F>` where `F` is the field
+/// type. A field of type `Option<F>` is optional: an absent input becomes
+/// `None`, a present one must still pass the sanitizer.
+///
+/// The generated `Sanitize::from_inputs` sanitizes every field through a
+/// `Validation` session, so all missing or invalid fields are reported at
+/// once, and returns `Labeled<Verified<Self>, UserSupplied>`: the struct is
+/// labeled like the request values it was built from.
+///
+/// With `#[input(tainted)]` on the struct, the macro also generates
+/// `from_tainted(Tainted<Self>) -> Result<Verified<Self>, ValidationErrors>`,
+/// which sanitizes the fields of an already-built tainted struct. Each
+/// field's own value is passed to its sanitizer, which must therefore accept
+/// and return the field type (`Sanitizer<F, F>`), as `StringSanitizer` does
+/// for `String` fields.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::web::{ExtractTaintedInputs, RequestAdapter};
+/// use policy_core::{IdentifierSanitizer, Sanitize, SanitizationErrorKind, StringSanitizer};
+///
+/// #[derive(Debug, Sanitize)]
+/// #[input(tainted)]
+/// struct AdminAction {
+///     #[input(query, with = IdentifierSanitizer::new(32).unwrap())]
+///     action: String,
+///     #[input(query = "target", with = StringSanitizer::new(100).unwrap())]
+///     target_user: String,
+///     #[input(header = "x-reason", with = StringSanitizer::new(200).unwrap())]
+///     reason: Option<String>,
+/// }
+///
+/// let mut request = RequestAdapter::new("req-1".to_string());
+/// request.add_query_param("action".to_string(), "disable account".to_string());
+///
+/// let report = AdminAction::from_inputs(&request.extract_tainted_inputs()).unwrap_err();
+/// assert_eq!(report.fields().collect::<Vec<_>>(), ["action", "target"]);
+/// assert_eq!(report.get("target").unwrap().kind(), SanitizationErrorKind::Empty);
+/// ```
+///
+/// Fields without a sanitizer are rejected at compile time:
+///
+/// ```compile_fail
+/// use policy_core::Sanitize;
+///
+/// #[derive(Sanitize)]
+/// struct Search {
+///     #[input(query)]
+///     q: String,
+/// }
+/// ```
+#[proc_macro_derive(Sanitize, attributes(input))]
+pub fn derive_sanitize(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+
+    expand_sanitize(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Where a field's tainted value is read from in `TaintedInputs`.
+enum Source {
+    Query,
+    Header,
+    Path,
+}
+
+impl Source {
+    fn getter(&self) -> Ident {
+        let name = match self {
+            Source::Query => "get_query",
+            Source::Header => "get_header",
+            Source::Path => "get_path_param",
+        };
+        Ident::new(name, Span::call_site())
+    }
+}
+
+/// A single item in a field's `#[input(...)]`.
+enum FieldOption {
+    Source(Source, Option<LitStr>, Span),
+    With(Expr),
+}
+
+impl Parse for FieldOption {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+
+        if name == "with" {
+            input.parse::<Token![=]>()?;
+            return Ok(FieldOption::With(input.parse()?));
+        }
+
+        let source = match name.to_string().as_str() {
+            "query" => Source::Query,
+            "header" => Source::Header,
+            "path" => Source::Path,
+            _ => {
+                return Err(syn::Error::new(
+                    name.span(),
+                    "unknown option; expected `with = ..`, `query`, `header` or `path`",
+                ))
+            }
+        };
+        let key = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        Ok(FieldOption::Source(source, key, name.span()))
+    }
+}
+
+/// A parsed field of a `#[derive(Sanitize)]` struct.
+struct SanitizedField {
+    ident: Ident,
+    source: Source,
+    key: String,
+    sanitizer: Expr,
+    optional: bool,
+    ty: Type,
+}
+
+impl SanitizedField {
+    fn parse(field: &syn::Field) -> syn::Result<Self> {
+        let ident = field.ident.clone().expect("named field");
+        let mut source = None;
+        let mut sanitizer = None;
+
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("input")) {
+            let options =
+                attr.parse_args_with(Punctuated::<FieldOption, Token![,]>::parse_terminated)?;
+            for option in options {
+                match option {
+                    FieldOption::Source(_, _, span) if source.is_some() => {
+                        return Err(syn::Error::new(span, "field has more than one source"));
+                    }
+                    FieldOption::Source(kind, key, _) => source = Some((kind, key)),
+                    FieldOption::With(expr) if sanitizer.is_some() => {
+                        return Err(syn::Error::new(
+                            expr.span(),
+                            "field has more than one sanitizer",
+                        ));
+                    }
+                    FieldOption::With(expr) => sanitizer = Some(expr),
+                }
+            }
+        }
+
+        let sanitizer = sanitizer.ok_or_else(|| {
+            syn::Error::new(ident.span(), "field needs a sanitizer: #[input(with = ..)]")
+        })?;
+        let (source, key) = source.unwrap_or((Source::Query, None));
+        let key = key.map_or_else(|| ident.to_string(), |key| key.value());
+
+        Ok(Self {
+            optional: is_option(&field.ty),
+            ident,
+            source,
+            key,
+            sanitizer,
+            ty: field.ty.clone(),
+        })
+    }
+
+    /// Local variable holding the session's value for this field.
+    fn binding(&self) -> Ident {
+        quote::format_ident!("__sanitize_{}", self.ident)
+    }
+}
+
+/// Returns `true` for `Option<T>`, `std::option::Option<T>` and similar.
+fn is_option(ty: &Type) -> bool {
+    let Type::Path(path) = ty else {
+        return false;
+    };
+    path.qself.is_none()
+        && path.path.segments.last().is_some_and(|segment| {
+            segment.ident == "Option"
+                && matches!(
+                    &segment.arguments,
+                    PathArguments::AngleBracketed(args)
+                        if args.args.len() == 1
+                            && matches!(args.args[0], GenericArgument::Type(_))
+                )
+        })
+}
+
+/// Nests `items` into tuples of at most 12, the largest `FieldValues` tuple.
+fn nest(items: Vec<TokenStream2>) -> TokenStream2 {
+    if items.len() <= 12 {
+        return quote!((#(#items,)*));
+    }
+    let chunks = items.chunks(12).map(|chunk| nest(chunk.to_vec())).collect();
+    nest(chunks)
+}
+
+/// Parses the struct-level `#[input(tainted)]` flag.
+fn wants_tainted(attrs: &[syn::Attribute]) -> syn::Result<bool> {
+    let mut tainted = false;
+    for attr in attrs.iter().filter(|a| a.path().is_ident("input")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("tainted") {
+                tainted = true;
+                Ok(())
+            } else {
+                Err(meta.error("unknown option; expected `tainted`"))
+            }
+        })?;
+    }
+    Ok(tainted)
+}
+
+fn expand_sanitize(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            input.generics.span(),
+            "#[derive(Sanitize)] does not support generic structs",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) if !fields.named.is_empty() => &fields.named,
+            _ => {
+                return Err(syn::Error::new(
+                    name.span(),
+                    "#[derive(Sanitize)] needs a struct with at least one named field",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new(
+                name.span(),
+                "#[derive(Sanitize)] only supports structs",
+            ))
+        }
+    };
+    let fields = fields
+        .iter()
+        .map(SanitizedField::parse)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let bindings = nest(
+        fields
+            .iter()
+            .map(|f| f.binding().to_token_stream())
+            .collect(),
+    );
+    let types = nest(fields.iter().map(|f| f.ty.to_token_stream()).collect());
+    let idents = fields.iter().map(|f| &f.ident);
+    let values = fields.iter().map(SanitizedField::binding);
+
+    let from_inputs = fields.iter().map(|field| {
+        let binding = field.binding();
+        let key = &field.key;
+        let getter = field.source.getter();
+        let sanitizer = &field.sanitizer;
+        let method = if field.optional {
+            quote!(optional)
+        } else {
+            quote!(required)
+        };
+        quote_spanned! {sanitizer.span()=>
+            let #binding = validation.#method(#key, inputs.#getter(#key), &(#sanitizer));
+        }
+    });
+
+    let mut expanded = quote! {
+        // Moves the verified values into the fields; finish_derived trusts this
+        impl ::policy_core::__private::DerivedSanitize for #name {
+            type Fields = #types;
+
+            fn assemble(#bindings: Self::Fields) -> Self {
+                Self { #(#idents: #values,)* }
+            }
+        }
+
+        impl ::policy_core::Sanitize for #name {
+            fn from_inputs(
+                inputs: &::policy_core::web::TaintedInputs,
+            ) -> ::core::result::Result<
+                ::policy_core::labels::Labeled<
+                    ::policy_core::Verified<Self>,
+                    ::policy_core::labels::UserSupplied,
+                >,
+                ::policy_core::ValidationErrors,
+            > {
+                let mut validation = ::policy_core::Validation::new();
+                #(#from_inputs)*
+                ::policy_core::__private::finish_derived(validation, #bindings)
+            }
+        }
+    };
+
+    if wants_tainted(&input.attrs)? {
+        let from_tainted = fields.iter().map(|field| {
+            let binding = field.binding();
+            let ident = &field.ident;
+            let key = ident.to_string();
+            let sanitizer = &field.sanitizer;
+            if field.optional {
+                quote_spanned! {sanitizer.span()=>
+                    let #binding = validation.optional_field_of(
+                        #key, &input, |raw: &Self| raw.#ident.as_ref(), &(#sanitizer),
+                    );
+                }
+            } else {
+                quote_spanned! {sanitizer.span()=>
+                    let #binding = validation.field_of(
+                        #key, &input, |raw: &Self| &raw.#ident, &(#sanitizer),
+                    );
+                }
+            }
+        });
+
+        expanded.extend(quote! {
+            impl #name {
+                /// Sanitizes every field of a tainted value of this struct,
+                /// reporting all invalid fields by field name.
+                pub fn from_tainted(
+                    input: ::policy_core::Tainted<Self>,
+                ) -> ::core::result::Result<
+                    ::policy_core::Verified<Self>,
+                    ::policy_core::ValidationErrors,
+                > {
+                    let mut validation = ::policy_core::Validation::new();
+                    #(#from_tainted)*
+                    ::policy_core::__private::finish_derived(validation, #bindings)
+                        .map(::policy_core::labels::Labeled::into_inner)
+                }
+            }
+        });
+    }
+
+    Ok(expanded)
+}
@@ -0,0 +1,149 @@
+use std::future::Future;
+
+use policy_core::audit::AuditEvent;
+use policy_core::audit::{AuditEventKind, AuditOutcome};
+use policy_core::web::{RequestAdapter, TaintedInputs};
+use policy_core::{
+    require, AllowAuthenticated, AuthorizedState, Ctx, Error, LogCap, Principal, StringSanitizer,
+    Violation, ViolationKind,
+};
+
+fn adapter_for(principal: Option<Principal>) -> RequestAdapter {
+    let mut adapter = RequestAdapter::new("req-macro".to_string());
+    adapter.set_principal(principal);
+    adapter
+}
+
+#[require(Authenticated, Authorized("log"), authorizer = AllowAuthenticated)]
+fn user_data(ctx: Ctx, _inputs: TaintedInputs) -> Result<String, Violation> {
+    ctx.log()?.info(format_args!("fetching user data"));
+    Ok(ctx.principal().unwrap().id.clone())
+}
+
+#[test]
+fn require_builds_context_before_calling_handler() {
+    let adapter = adapter_for(Some(Principal::new("user-1", "Alice")));
+
+    assert_eq!(user_data(&adapter).unwrap(), "user-1");
+}
+
+#[test]
+fn require_rejects_unauthenticated_requests() {
+    let adapter = adapter_for(None);
+
+    let err = user_data(&adapter).unwrap_err();
+    assert_eq!(err.kind, ViolationKind::Unauthenticated);
+}
+
+#[require(
+    Authenticated,
+    HasRole("admin"),
+    Authorized("audit"),
+    Authorized("secrets:declassify"),
+    authorizer = AllowAuthenticated
+)]
+fn admin_action(ctx: Ctx, inputs: TaintedInputs) -> Result<String, Violation> {
+    let action = inputs
+        .get_query("action")
+        .unwrap()
+        .sanitize(&StringSanitizer::new(64).unwrap())
+        .map_err(|_| Violation::new(ViolationKind::InvalidInput, "bad action"))?;
+    let action = ctx
+        .declassify()?
+        .unlabel(action, "admin action is recorded in the audit log")?;
+
+    let event = AuditEvent::new(
+        ctx.request_id(),
+        ctx.principal().map(|p| &p.id),
+        AuditEventKind::AdminAction,
+        AuditOutcome::Success,
+    )
+    .with_action(action.as_ref());
+    ctx.audit()?.emit(&event);
+
+    Ok(action.as_ref().clone())
+}
+
+#[test]
+fn require_passes_tainted_inputs_to_handler() {
+    let mut adapter = adapter_for(Some(Principal::new("admin-1", "Admin").with_role("admin")));
+    adapter.add_query_param("action".to_string(), "  rotate_keys ".to_string());
+
+    assert_eq!(admin_action(&adapter).unwrap(), "rotate_keys");
+}
+
+#[test]
+fn require_checks_roles() {
+    let mut adapter = adapter_for(Some(Principal::new("user-2", "Bob")));
+    adapter.add_query_param("action".to_string(), "rotate_keys".to_string());
+
+    let err = admin_action(&adapter).unwrap_err();
+    assert_eq!(err.kind, ViolationKind::MissingRole { role: "admin" });
+}
+
+#[require(
+    Authenticated,
+    Authorized("log"),
+    authorizer = |p: &Principal, _action: &str| p.id.starts_with("svc-")
+)]
+fn service_only(ctx: Ctx, _inputs: TaintedInputs) -> Result<(), Violation> {
+    ctx.log()?.info(format_args!("service call"));
+    Ok(())
+}
+
+#[test]
+fn require_uses_configured_authorizer() {
+    assert!(service_only(&adapter_for(Some(Principal::new("svc-1", "Svc")))).is_ok());
+
+    let err = service_only(&adapter_for(Some(Principal::new("user-3", "Carol")))).unwrap_err();
+    assert_eq!(err.kind, ViolationKind::Unauthorized { action: "log" });
+}
+
+#[require(Authenticated, Grant(LogCap), authorizer = AllowAuthenticated)]
+fn typed_handler(
+    ctx: Ctx<AuthorizedState, (LogCap,)>,
+    _inputs: TaintedInputs,
+) -> Result<(), Error> {
+    // No `?`: the typed capability set proves LogCap was granted
+    ctx.log().info(format_args!("typed"));
+    Ok(())
+}
+
+#[require(Authenticated, Authorized("log"))]
+fn no_authorizer(_ctx: Ctx, _inputs: TaintedInputs) -> Result<(), Violation> {
+    Ok(())
+}
+
+#[test]
+fn require_without_authorizer_denies_actions() {
+    let err = no_authorizer(&adapter_for(Some(Principal::new("user-6", "Fay")))).unwrap_err();
+    assert_eq!(err.kind, ViolationKind::Unauthorized { action: "log" });
+}
+
+#[test]
+fn require_supports_typed_grants_and_error_conversion() {
+    assert!(typed_handler(&adapter_for(Some(Principal::new("user-4", "Dan")))).is_ok());
+
+    let err = typed_handler(&adapter_for(None)).unwrap_err();
+    assert!(matches!(err, Error::Violation(v) if v.kind == ViolationKind::Unauthenticated));
+}
+
+#[require(Authenticated, Authorized("log"), authorizer = AllowAuthenticated)]
+async fn async_handler(ctx: Ctx, _inputs: TaintedInputs) -> Result<String, Violation> {
+    Ok(ctx.request_id().to_string())
+}
+
+#[test]
+fn require_supports_async_handlers() {
+    let adapter = adapter_for(Some(Principal::new("user-5", "Eve")));
+    let future = async_handler(&adapter);
+
+    // Poll once with a no-op waker; the handler never awaits anything else
+    let waker = std::task::Waker::noop();
+    let mut cx = std::task::Context::from_waker(waker);
+    let mut future = std::pin::pin!(future);
+    match future.as_mut().poll(&mut cx) {
+        std::task::Poll::Ready(result) => assert_eq!(result.unwrap(), "req-macro"),
+        std::task::Poll::Pending => panic!("handler should complete immediately"),
+    }
+}
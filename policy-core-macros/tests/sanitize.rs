@@ -0,0 +1,223 @@
+use policy_core::labels::{Labeled, UserSupplied};
+use policy_core::web::{ExtractTaintedInputs, RequestAdapter, TaintedInputs};
+use policy_core::{
+    actions, AllowAuthenticated, Authorized, EmailSanitizer, EnumSanitizer, IdentifierSanitizer,
+    IntegerSanitizer, PolicyGate, Principal, RequestMeta, SanitizationErrorKind, Sanitize,
+    StringSanitizer, Tainted, Verified, Violation, ViolationKind,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Role {
+    Viewer,
+    Editor,
+}
+
+#[derive(Debug, PartialEq, Sanitize)]
+struct Invite {
+    #[input(path = "team", with = IdentifierSanitizer::new(32).unwrap())]
+    team: String,
+    #[input(with = EmailSanitizer::new())]
+    email: String,
+    #[input(query = "role", with = EnumSanitizer::new([("viewer", Role::Viewer), ("editor", Role::Editor)]))]
+    role: Role,
+    #[input(query = "days", with = IntegerSanitizer::new(1u16, 30).unwrap())]
+    expires_in_days: Option<u16>,
+    #[input(header = "x-request-note", with = StringSanitizer::new(64).unwrap())]
+    note: Option<String>,
+}
+
+fn inputs(
+    query: &[(&str, &str)],
+    headers: &[(&str, &str)],
+    path: &[(&str, &str)],
+) -> TaintedInputs {
+    let mut adapter = RequestAdapter::new("req-derive".to_string());
+    for (key, value) in query {
+        adapter.add_query_param(key.to_string(), value.to_string());
+    }
+    for (key, value) in headers {
+        adapter.add_header(key.to_string(), value.to_string());
+    }
+    for (key, value) in path {
+        adapter.add_path_param(key.to_string(), value.to_string());
+    }
+    adapter.extract_tainted_inputs()
+}
+
+/// Declassifies a derived struct so its fields can be compared.
+fn unlabel<T>(value: Labeled<Verified<T>, UserSupplied>) -> T {
+    let meta = RequestMeta {
+        request_id: "req-derive".to_string(),
+        principal: Some(Principal::new("tester", "Tester")),
+    };
+    let ctx = PolicyGate::new(meta)
+        .with_authorizer(AllowAuthenticated)
+        .require(Authorized::for_action(actions::DECLASSIFY))
+        .build()
+        .unwrap();
+    ctx.declassify()
+        .unwrap()
+        .unlabel(value, "compare derived fields in tests")
+        .unwrap()
+        .into_inner()
+}
+
+#[test]
+fn derive_reads_each_field_from_its_source() {
+    let inputs = inputs(
+        &[
+            ("email", "Ann@Example.com"),
+            ("role", "editor"),
+            ("days", "7"),
+        ],
+        &[("x-request-note", " welcome ")],
+        &[("team", "platform")],
+    );
+
+    let invite = Invite::from_inputs(&inputs).unwrap();
+    assert_eq!(invite.labels(), ["user"]);
+    assert_eq!(
+        unlabel(invite),
+        Invite {
+            team: "platform".to_string(),
+            email: "Ann@example.com".to_string(),
+            role: Role::Editor,
+            expires_in_days: Some(7),
+            note: Some("welcome".to_string()),
+        }
+    );
+}
+
+#[test]
+fn derive_leaves_absent_optional_fields_empty() {
+    let inputs = inputs(
+        &[("email", "ann@example.com"), ("role", "viewer")],
+        &[],
+        &[("team", "platform")],
+    );
+
+    let invite = unlabel(Invite::from_inputs(&inputs).unwrap());
+    assert_eq!(invite.expires_in_days, None);
+    assert_eq!(invite.note, None);
+}
+
+#[test]
+fn derive_reports_every_missing_and_invalid_field() {
+    let inputs = inputs(
+        &[("role", "owner"), ("days", "90")],
+        &[("x-request-note", "line\nbreak")],
+        &[],
+    );
+
+    let report = Invite::from_inputs(&inputs).unwrap_err();
+    assert_eq!(
+        report.fields().collect::<Vec<_>>(),
+        ["team", "email", "role", "days", "x-request-note"]
+    );
+    assert_eq!(
+        report.get("team").unwrap().kind(),
+        SanitizationErrorKind::Empty
+    );
+    assert_eq!(
+        report.get("role").unwrap().kind(),
+        SanitizationErrorKind::NotAllowed
+    );
+    assert_eq!(
+        report.get("days").unwrap().kind(),
+        SanitizationErrorKind::OutOfRange
+    );
+    assert_eq!(
+        report.get("x-request-note").unwrap().kind(),
+        SanitizationErrorKind::ContainsControlChars
+    );
+
+    let violation = Violation::from(report);
+    assert_eq!(violation.kind, ViolationKind::InvalidInput);
+}
+
+#[derive(Debug, Sanitize)]
+#[input(tainted)]
+struct Profile {
+    #[input(with = StringSanitizer::new(32).unwrap())]
+    display_name: String,
+    #[input(query = "mail", with = EmailSanitizer::new())]
+    email: String,
+    #[input(with = StringSanitizer::new(140).unwrap())]
+    bio: Option<String>,
+}
+
+#[test]
+fn derive_sanitizes_tainted_structs_by_field_name() {
+    let profile = Tainted::new(Profile {
+        display_name: "  Ann  ".to_string(),
+        email: "ann@EXAMPLE.com".to_string(),
+        bio: None,
+    });
+    let verified = Profile::from_tainted(profile).unwrap();
+    assert_eq!(verified.as_ref().display_name, "Ann");
+    assert_eq!(verified.as_ref().email, "ann@example.com");
+
+    let profile = Tainted::new(Profile {
+        display_name: "Ann".to_string(),
+        email: "ann".to_string(),
+        bio: Some(" ".to_string()),
+    });
+    let report = Profile::from_tainted(profile).unwrap_err();
+    assert_eq!(report.fields().collect::<Vec<_>>(), ["email", "bio"]);
+}
+
+#[derive(Debug, Sanitize)]
+struct Wide {
+    #[input(with = IntegerSanitizer::new(0u8, 9).unwrap())]
+    a: u8,
+    #[input(with = IntegerSanitizer::new(0u8, 9).unwrap())]
+    b: u8,
+    #[input(with = IntegerSanitizer::new(0u8, 9).unwrap())]
+    c: u8,
+    #[input(with = IntegerSanitizer::new(0u8, 9).unwrap())]
+    d: u8,
+    #[input(with = IntegerSanitizer::new(0u8, 9).unwrap())]
+    e: u8,
+    #[input(with = IntegerSanitizer::new(0u8, 9).unwrap())]
+    f: u8,
+    #[input(with = IntegerSanitizer::new(0u8, 9).unwrap())]
+    g: u8,
+    #[input(with = IntegerSanitizer::new(0u8, 9).unwrap())]
+    h: u8,
+    #[input(with = IntegerSanitizer::new(0u8, 9).unwrap())]
+    i: u8,
+    #[input(with = IntegerSanitizer::new(0u8, 9).unwrap())]
+    j: u8,
+    #[input(with = IntegerSanitizer::new(0u8, 9).unwrap())]
+    k: u8,
+    #[input(with = IntegerSanitizer::new(0u8, 9).unwrap())]
+    l: u8,
+    #[input(with = IntegerSanitizer::new(0u8, 9).unwrap())]
+    m: u8,
+}
+
+#[test]
+fn derive_supports_more_fields_than_the_largest_tuple() {
+    let names = [
+        "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m",
+    ];
+    let query: Vec<(&str, &str)> = names.iter().map(|name| (*name, "7")).collect();
+
+    let wide = unlabel(Wide::from_inputs(&inputs(&query, &[], &[])).unwrap());
+    let Wide {
+        a,
+        b,
+        c,
+        d,
+        e,
+        f,
+        g,
+        h,
+        i,
+        j,
+        k,
+        l,
+        m,
+    } = wide;
+    assert_eq!([a, b, c, d, e, f, g, h, i, j, k, l, m], [7; 13]);
+}
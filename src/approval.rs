@@ -0,0 +1,157 @@
+//! Which sanitizer approved a verified value.
+//!
+//! An [`Approval`] is attached to every [`Verified`](crate::Verified) value a
+//! built-in sanitizer produces. It names the sanitizer, fingerprints its
+//! configuration and records whether the accepted value differs from the
+//! input, so that after an incident the rule that let a payload through can
+//! be identified from an [`AuditEvent`](crate::audit::AuditEvent):
+//!
+//! ```
+//! use policy_core::audit::{AuditEvent, AuditEventKind, AuditOutcome};
+//! use policy_core::{Sanitizer, StringSanitizer, Tainted};
+//!
+//! let sanitizer = StringSanitizer::new(100).unwrap();
+//! let name = sanitizer.sanitize(Tainted::new("  alice ".to_string())).unwrap();
+//!
+//! let approval = *name.approval().unwrap();
+//! assert_eq!(approval.sanitizer(), "StringSanitizer");
+//! assert!(approval.transformed()); // trimmed
+//!
+//! let event = AuditEvent::new(
+//!     "req-1",
+//!     Some("alice"),
+//!     AuditEventKind::StateChange,
+//!     AuditOutcome::Success,
+//! )
+//! .with_approval(approval);
+//! assert!(event.to_string().contains("approved_by=StringSanitizer#"));
+//! ```
+//!
+//! Fingerprints are 64-bit FNV-1a hashes of the sanitizer's settings. They
+//! are stable across runs and builds of the same crate version, so two
+//! events with the same fingerprint were approved under the same limits.
+//! They identify a configuration; they are not a security boundary.
+
+use std::fmt;
+
+/// Record of the sanitizer that produced a [`Verified`](crate::Verified) value.
+///
+/// Contains no part of the value, so it is safe to log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Approval {
+    sanitizer: &'static str,
+    fingerprint: u64,
+    transformed: bool,
+}
+
+impl Approval {
+    /// Records that `sanitizer`, configured as described by `config`,
+    /// approved a value.
+    pub(crate) fn new(sanitizer: &'static str, config: &dyn fmt::Debug, transformed: bool) -> Self {
+        let mut hasher = Fnv1a::new();
+        // Writing to the hasher cannot fail
+        let _ = fmt::write(&mut hasher, format_args!("{:?}", config));
+        Self {
+            sanitizer,
+            fingerprint: hasher.0,
+            transformed,
+        }
+    }
+
+    /// Returns the name of the sanitizer, e.g. `StringSanitizer`.
+    pub fn sanitizer(&self) -> &'static str {
+        self.sanitizer
+    }
+
+    /// Returns the fingerprint of the sanitizer's configuration.
+    ///
+    /// Sanitizers of the same kind with the same settings share a
+    /// fingerprint; changing any limit or rule changes it.
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    /// Returns whether the verified value differs from the input, for
+    /// example because it was trimmed, normalized or escaped.
+    pub fn transformed(&self) -> bool {
+        self.transformed
+    }
+
+    /// Marks the value as transformed after approval.
+    pub(crate) fn and_transformed(mut self, transformed: bool) -> Self {
+        self.transformed |= transformed;
+        self
+    }
+}
+
+/// Writes `StringSanitizer#<fingerprint>`, followed by ` (transformed)` if
+/// the value was changed.
+impl fmt::Display for Approval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}#{:016x}", self.sanitizer, self.fingerprint)?;
+        if self.transformed {
+            write!(f, " (transformed)")?;
+        }
+        Ok(())
+    }
+}
+
+/// 64-bit FNV-1a, fed through `fmt::Write` so configurations are hashed
+/// without being formatted into a buffer first.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+}
+
+impl fmt::Write for Fnv1a {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for byte in s.bytes() {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(Self::PRIME);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fingerprint_is_fnv1a_of_the_debug_output() {
+        // Reference values for 64-bit FNV-1a
+        assert_eq!(
+            Approval::new("Empty", &format_args!(""), false).fingerprint(),
+            0xcbf29ce484222325
+        );
+        assert_eq!(
+            Approval::new("A", &format_args!("a"), false).fingerprint(),
+            0xaf63dc4c8601ec8c
+        );
+    }
+
+    #[test]
+    fn fingerprint_depends_on_configuration_only() {
+        let a = Approval::new("Limit", &100usize, false);
+        let b = Approval::new("Limit", &100usize, true);
+        let c = Approval::new("Limit", &101usize, false);
+
+        assert_eq!(a.fingerprint(), b.fingerprint());
+        assert_ne!(a.fingerprint(), c.fingerprint());
+    }
+
+    #[test]
+    fn display_names_sanitizer_fingerprint_and_transformation() {
+        let approval = Approval::new("A", &format_args!("a"), false);
+        assert_eq!(approval.to_string(), "A#af63dc4c8601ec8c");
+        assert_eq!(
+            approval.and_transformed(true).to_string(),
+            "A#af63dc4c8601ec8c (transformed)"
+        );
+    }
+}
@@ -17,6 +17,7 @@ mod policy_audit;
 mod trail;
 
 pub use capability::AuditCap;
-pub use event::{AuditEvent, AuditEventKind, AuditOutcome};
+pub use event::{AuditEvent, AuditEventKind, AuditOutcome, RedactedUrl};
+pub(crate) use policy_audit::emit_event;
 pub use policy_audit::PolicyAudit;
 pub use trail::AuditTrail;
@@ -4,6 +4,9 @@
 //! authorized to emit audit events. It cannot be constructed outside this
 //! crate, ensuring all audit operations go through policy validation.
 
+use crate::capability::{Capability, Grant};
+use crate::policy::actions;
+
 /// Capability proving authorization to emit audit events.
 ///
 /// This is a zero-sized type that acts as compile-time proof of authorization.
@@ -13,16 +16,14 @@
 /// # Example
 ///
 /// ```
-/// use policy_core::{PolicyGate, RequestMeta, Principal, Authorized};
+/// use policy_core::{AllowAuthenticated, PolicyGate, RequestMeta, Principal, Authorized};
 ///
 /// let meta = RequestMeta {
 ///     request_id: "req-123".to_string(),
-///     principal: Some(Principal {
-///         id: "admin-1".to_string(),
-///         name: "Admin".to_string(),
-///     }),
+///     principal: Some(Principal::new("admin-1", "Admin")),
 /// };
 /// let ctx = PolicyGate::new(meta)
+///     .with_authorizer(AllowAuthenticated)
 ///     .require(Authorized::for_action("audit"))
 ///     .build()
 ///     .unwrap();
@@ -52,6 +53,14 @@ impl AuditCap {
     }
 }
 
+impl Capability for AuditCap {
+    const ACTION: &'static str = actions::AUDIT;
+
+    fn from_grant(_grant: Grant<Self>) -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -17,13 +17,14 @@ use std::marker::PhantomData;
 /// # Example
 ///
 /// ```no_run
-/// # use policy_core::{PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
+/// # use policy_core::{AllowAuthenticated, PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
 /// # use policy_core::audit::{AuditEvent, AuditEventKind, AuditOutcome};
 /// # let meta = RequestMeta {
 /// #     request_id: "req-1".to_string(),
-/// #     principal: Some(Principal { id: "u1".to_string(), name: "Admin".to_string() }),
+/// #     principal: Some(Principal::new("u1", "Admin")),
 /// # };
 /// # let ctx = PolicyGate::new(meta)
+/// #     .with_authorizer(AllowAuthenticated)
 /// #     .require(Authenticated)
 /// #     .require(Authorized::for_action("audit"))
 /// #     .build()
@@ -64,13 +65,14 @@ impl<'a> PolicyAudit<'a> {
     /// # Example
     ///
     /// ```no_run
-    /// # use policy_core::{PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
+    /// # use policy_core::{AllowAuthenticated, PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
     /// # use policy_core::audit::{AuditEvent, AuditEventKind, AuditOutcome};
     /// # let meta = RequestMeta {
     /// #     request_id: "req-1".to_string(),
-    /// #     principal: Some(Principal { id: "u1".to_string(), name: "Admin".to_string() }),
+    /// #     principal: Some(Principal::new("u1", "Admin")),
     /// # };
     /// # let ctx = PolicyGate::new(meta)
+    /// #     .with_authorizer(AllowAuthenticated)
     /// #     .require(Authenticated)
     /// #     .require(Authorized::for_action("audit"))
     /// #     .build()
@@ -88,20 +90,7 @@ impl<'a> PolicyAudit<'a> {
     /// audit.emit(&event);
     /// ```
     pub fn emit(&self, event: &AuditEvent) {
-        // Emit the event through tracing with structured fields
-        tracing::info!(
-            target: "policy_audit",
-            request_id = %event.request_id(),
-            principal = ?event.principal(),
-            kind = %event.kind(),
-            outcome = %event.outcome(),
-            action = ?event.action(),
-            resource_id = ?event.resource_id(),
-            method = ?event.method(),
-            redacted_url = ?event.redacted_url(),
-            body_len = ?event.body_len(),
-            "audit event"
-        );
+        emit_event(event);
     }
 
     /// Emits an audit event and also records it to the provided trail.
@@ -112,13 +101,14 @@ impl<'a> PolicyAudit<'a> {
     /// # Example
     ///
     /// ```no_run
-    /// # use policy_core::{PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
+    /// # use policy_core::{AllowAuthenticated, PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
     /// # use policy_core::audit::{AuditEvent, AuditEventKind, AuditOutcome, AuditTrail};
     /// # let meta = RequestMeta {
     /// #     request_id: "req-1".to_string(),
-    /// #     principal: Some(Principal { id: "u1".to_string(), name: "Admin".to_string() }),
+    /// #     principal: Some(Principal::new("u1", "Admin")),
     /// # };
     /// # let ctx = PolicyGate::new(meta)
+    /// #     .with_authorizer(AllowAuthenticated)
     /// #     .require(Authenticated)
     /// #     .require(Authorized::for_action("audit"))
     /// #     .build()
@@ -143,6 +133,30 @@ impl<'a> PolicyAudit<'a> {
     }
 }
 
+/// Emits an audit event through tracing.
+///
+/// Shared by `PolicyAudit::emit` and the security events policy-core records
+/// on its own (e.g. egress denials), which do not require `AuditCap`.
+pub(crate) fn emit_event(event: &AuditEvent) {
+    // Emit the event through tracing with structured fields
+    tracing::info!(
+        target: "policy_audit",
+        request_id = %event.request_id(),
+        principal = ?event.principal(),
+        tenant_id = ?event.tenant_id(),
+        kind = %event.kind(),
+        outcome = %event.outcome(),
+        action = ?event.action(),
+        resource_id = ?event.resource_id(),
+        method = ?event.method(),
+        redacted_url = ?event.redacted_url(),
+        body_len = ?event.body_len(),
+        reason = ?event.reason(),
+        location = event.location().map(tracing::field::display),
+        "audit event"
+    );
+}
+
 #[cfg(test)]
 #[allow(deprecated)]
 mod tests {
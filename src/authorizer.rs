@@ -0,0 +1,155 @@
+//! Pluggable authorization decisions for `PolicyGate`.
+//!
+//! `PolicyGate` answers *whether* a requirement such as
+//! `Authorized::for_action("log")` holds by asking an [`Authorizer`].
+//! The authorizer sees the authenticated [`Principal`] and the action name,
+//! and nothing else: it cannot mint capabilities, it only approves or denies.
+
+use std::sync::Arc;
+
+use crate::request::Principal;
+
+/// Decides whether a principal may perform an action.
+///
+/// `PolicyGate` consults the authorizer once for every
+/// `Authorized::for_action(..)` requirement, after authentication has been
+/// established. A denial fails the whole gate with
+/// [`ViolationKind::Unauthorized`](crate::ViolationKind::Unauthorized) and no
+/// capabilities are granted.
+///
+/// Implementations must be `Send + Sync` so a single authorizer can be shared
+/// across request handlers. Closures of the form
+/// `Fn(&Principal, &str) -> bool` implement this trait directly.
+///
+/// # Security Considerations
+///
+/// - **Fail closed**: return `false` for actions you do not recognize
+/// - **No side effects**: decisions should be deterministic for a given input
+/// - **Principal is trusted**: the gate only calls this for authenticated principals
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{Authorizer, Principal};
+///
+/// struct AdminsOnly;
+///
+/// impl Authorizer for AdminsOnly {
+///     fn is_authorized(&self, principal: &Principal, action: &str) -> bool {
+///         action == "audit" && principal.id.starts_with("admin-")
+///     }
+/// }
+/// ```
+pub trait Authorizer: Send + Sync {
+    /// Returns `true` if `principal` may perform `action`.
+    fn is_authorized(&self, principal: &Principal, action: &str) -> bool;
+}
+
+impl<F> Authorizer for F
+where
+    F: Fn(&Principal, &str) -> bool + Send + Sync,
+{
+    fn is_authorized(&self, principal: &Principal, action: &str) -> bool {
+        self(principal, action)
+    }
+}
+
+impl<A: Authorizer + ?Sized> Authorizer for Arc<A> {
+    fn is_authorized(&self, principal: &Principal, action: &str) -> bool {
+        (**self).is_authorized(principal, action)
+    }
+}
+
+/// Authorizer that denies every action.
+///
+/// This is the authorizer `PolicyGate` uses when none is configured, so a
+/// gate with `Authorized` requirements fails closed until an application
+/// chooses an authorizer with
+/// [`PolicyGate::with_authorizer`](crate::PolicyGate::with_authorizer).
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{Authorizer, DenyAll, Principal};
+///
+/// let principal = Principal::new("user-1", "Alice");
+///
+/// assert!(!DenyAll.is_authorized(&principal, "log"));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DenyAll;
+
+impl Authorizer for DenyAll {
+    fn is_authorized(&self, _principal: &Principal, _action: &str) -> bool {
+        false
+    }
+}
+
+/// Authorizer that approves every action for any authenticated principal.
+///
+/// This reproduces the coarse-grained model of earlier releases, where being
+/// logged in was enough to obtain any capability. It must be opted into
+/// explicitly with
+/// [`PolicyGate::with_authorizer`](crate::PolicyGate::with_authorizer).
+///
+/// **WARNING:** This performs no access control beyond authentication.
+/// Production systems should configure a real authorizer instead.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{AllowAuthenticated, Authorizer, Principal};
+///
+/// let principal = Principal::new("user-1", "Alice");
+///
+/// assert!(AllowAuthenticated.is_authorized(&principal, "anything"));
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AllowAuthenticated;
+
+impl Authorizer for AllowAuthenticated {
+    fn is_authorized(&self, _principal: &Principal, _action: &str) -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn principal(id: &str) -> Principal {
+        Principal::new(id.to_string(), "Test")
+    }
+
+    #[test]
+    fn deny_all_denies_everything() {
+        let p = principal("admin");
+        assert!(!DenyAll.is_authorized(&p, "log"));
+        assert!(!DenyAll.is_authorized(&p, "audit"));
+    }
+
+    #[test]
+    fn allow_authenticated_approves_everything() {
+        let p = principal("user-1");
+        assert!(AllowAuthenticated.is_authorized(&p, "log"));
+        assert!(AllowAuthenticated.is_authorized(&p, "delete_everything"));
+    }
+
+    #[test]
+    fn closures_are_authorizers() {
+        let authz = |p: &Principal, action: &str| p.id == "admin" && action == "audit";
+
+        assert!(authz.is_authorized(&principal("admin"), "audit"));
+        assert!(!authz.is_authorized(&principal("admin"), "http"));
+        assert!(!authz.is_authorized(&principal("user"), "audit"));
+    }
+
+    #[test]
+    fn shared_authorizers_delegate() {
+        let shared: Arc<dyn Authorizer> = Arc::new(|_: &Principal, action: &str| action == "log");
+        let cloned = Arc::clone(&shared);
+
+        assert!(shared.is_authorized(&principal("u"), "log"));
+        assert!(!cloned.is_authorized(&principal("u"), "http"));
+    }
+}
@@ -1,3 +1,144 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::policy::actions;
+
+/// A zero-sized proof of authorization bound to a single action.
+///
+/// Every capability names the action it authorizes through [`ACTION`](Self::ACTION).
+/// `PolicyGate` mints a capability only when an `Authorized::for_action(C::ACTION)`
+/// requirement was declared and satisfied, and `Ctx<Authorized>` hands it out
+/// through [`Ctx::capability`](crate::Ctx::capability).
+///
+/// # Declaring Capabilities
+///
+/// Applications declare their own capabilities with the
+/// [`capability!`](crate::capability!) macro. The generated type wraps a
+/// [`Grant`], which cannot be constructed outside policy-core, so the new
+/// capability is exactly as unforgeable as `LogCap`.
+///
+/// Implementing this trait by hand is possible but gives no guarantee unless
+/// the type holds a private `Grant<Self>` field and `from_grant` is its only
+/// constructor.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{capability, AllowAuthenticated, Authorized, Capability, PolicyGate, Principal, RequestMeta};
+///
+/// capability! {
+///     /// Permission to write to the primary database.
+///     pub struct DbWriteCap => "db:write";
+/// }
+///
+/// let meta = RequestMeta {
+///     request_id: "req-1".to_string(),
+///     principal: Some(Principal::new("u1", "Alice")),
+/// };
+/// let ctx = PolicyGate::new(meta)
+///     .with_authorizer(AllowAuthenticated)
+///     .require(Authorized::for_action(DbWriteCap::ACTION))
+///     .build()
+///     .unwrap();
+///
+/// let cap: DbWriteCap = ctx.capability().expect("db:write granted");
+/// ```
+pub trait Capability: Copy + fmt::Debug + Sized {
+    /// The action this capability authorizes.
+    const ACTION: &'static str;
+
+    /// Builds the capability from a gate-issued grant.
+    ///
+    /// Only policy-core can create a [`Grant`], so this is only reachable
+    /// after the action has been authorized.
+    fn from_grant(grant: Grant<Self>) -> Self;
+}
+
+/// Unforgeable token from which a [`Capability`] is built.
+///
+/// A `Grant<C>` is created only by policy-core after `C::ACTION` has been
+/// authorized. Capability types store it in a field, which makes them
+/// impossible to construct outside `Capability::from_grant`.
+pub struct Grant<C> {
+    // BREAKING CHANGE WARNING: This field MUST remain private and Grant::new MUST stay
+    // pub(crate). A publicly constructible Grant lets external code forge every
+    // user-defined capability (CRITICAL BYPASS, CWE-863).
+    _marker: PhantomData<fn() -> C>,
+}
+
+impl<C> Grant<C> {
+    /// Creates a grant for capability `C`.
+    ///
+    /// BREAKING CHANGE WARNING: Changing visibility to `pub` allows CAPABILITY FORGERY.
+    pub(crate) fn new() -> Self {
+        Self {
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C> Clone for Grant<C> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> Copy for Grant<C> {}
+
+impl<C> fmt::Debug for Grant<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Grant")
+    }
+}
+
+/// Declares a zero-sized capability type bound to an action.
+///
+/// The generated struct wraps a private [`Grant`], implements [`Capability`],
+/// `Debug`, `Clone` and `Copy`, and can only be obtained from a
+/// `Ctx<Authorized>` whose gate authorized the action.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{capability, Capability};
+///
+/// capability! {
+///     /// Permission to send email.
+///     pub struct EmailCap => "email:send";
+/// }
+///
+/// assert_eq!(EmailCap::ACTION, "email:send");
+/// ```
+///
+/// Capabilities declared this way cannot be built with a struct literal:
+///
+/// ```compile_fail
+/// use policy_core::capability;
+///
+/// capability! {
+///     /// Permission to send email.
+///     pub struct EmailCap => "email:send";
+/// }
+///
+/// let forged = EmailCap(policy_core::Grant::new()); // Error: Grant::new is private
+/// ```
+#[macro_export]
+macro_rules! capability {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident => $action:expr;) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy)]
+        $vis struct $name($crate::Grant<$name>);
+
+        impl $crate::Capability for $name {
+            const ACTION: &'static str = $action;
+
+            fn from_grant(grant: $crate::Grant<Self>) -> Self {
+                Self(grant)
+            }
+        }
+    };
+}
+
 /// Capability granting permission to perform logging operations.
 ///
 /// This is a zero-sized type that acts as proof that logging
@@ -22,7 +163,6 @@ impl LogCap {
     /// BREAKING CHANGE WARNING: Changing visibility to `pub` allows CAPABILITY FORGERY.
     /// External code could create LogCap without passing PolicyGate validation, bypassing
     /// authentication/authorization checks entirely (CWE-306: Missing Authentication).
-    #[allow(dead_code)] // Used in tests and will be used in Milestone 2
     pub(crate) fn new() -> Self {
         Self { _private: () }
     }
@@ -57,6 +197,22 @@ impl HttpCap {
     }
 }
 
+impl Capability for LogCap {
+    const ACTION: &'static str = actions::LOG;
+
+    fn from_grant(_grant: Grant<Self>) -> Self {
+        Self::new()
+    }
+}
+
+impl Capability for HttpCap {
+    const ACTION: &'static str = actions::HTTP;
+
+    fn from_grant(_grant: Grant<Self>) -> Self {
+        Self::new()
+    }
+}
+
 /// A minimal gated function that requires LogCap to execute.
 ///
 /// This proves the capability pattern works: you cannot call this function
@@ -108,6 +264,26 @@ mod tests {
         // let fake_cap = HttpCap { _private: () }; // Error: _private is private
     }
 
+    crate::capability! {
+        /// Test capability.
+        struct PaymentCap => "payment";
+    }
+
+    #[test]
+    fn declared_capability_is_zero_sized_and_bound_to_action() {
+        assert_eq!(std::mem::size_of::<PaymentCap>(), 0);
+        assert_eq!(PaymentCap::ACTION, "payment");
+
+        let cap = PaymentCap::from_grant(Grant::new());
+        assert!(format!("{:?}", cap).contains("PaymentCap"));
+    }
+
+    #[test]
+    fn builtin_capabilities_are_bound_to_their_actions() {
+        assert_eq!(LogCap::ACTION, actions::LOG);
+        assert_eq!(HttpCap::ACTION, actions::HTTP);
+    }
+
     #[test]
     fn http_cap_can_be_created_internally() {
         // Inside the crate, we CAN create HttpCap
@@ -0,0 +1,228 @@
+//! Type-level capability sets for `Ctx<Authorized, C>`.
+//!
+//! The second type parameter of [`Ctx`](crate::Ctx) records which capabilities
+//! the context is known to hold:
+//!
+//! - [`Dynamic`] (the default): capabilities are checked at runtime and
+//!   accessors such as `ctx.log()` return `Result<_, Violation>`.
+//! - A tuple of capability types, e.g. `(LogCap, HttpCap)`: the listed
+//!   capabilities are guaranteed by the type, their accessors return the
+//!   value directly, and asking for anything else is a compile error.
+//!
+//! Tuple sets are built with [`PolicyGate::grant`](crate::PolicyGate::grant)
+//! and hold up to eight capabilities.
+//!
+//! ```
+//! use policy_core::{AllowAuthenticated, HttpCap, LogCap, PolicyGate, Principal, RequestMeta};
+//!
+//! let meta = RequestMeta {
+//!     request_id: "req-1".to_string(),
+//!     principal: Some(Principal::new("u1", "Alice")),
+//! };
+//!
+//! let ctx = PolicyGate::new(meta)
+//!     .with_authorizer(AllowAuthenticated)
+//!     .grant::<LogCap>()
+//!     .grant::<HttpCap>()
+//!     .build()
+//!     .unwrap();
+//!
+//! // No Result: the type proves LogCap was granted
+//! ctx.log().info(format_args!("typed context"));
+//! ```
+//!
+//! Asking for a capability outside the set does not compile:
+//!
+//! ```compile_fail
+//! use policy_core::{AllowAuthenticated, LogCap, PolicyGate, Principal, RequestMeta};
+//!
+//! let meta = RequestMeta {
+//!     request_id: "req-1".to_string(),
+//!     principal: Some(Principal::new("u1", "Alice")),
+//! };
+//! let ctx = PolicyGate::new(meta).with_authorizer(AllowAuthenticated).grant::<LogCap>().build().unwrap();
+//!
+//! ctx.http(); // Error: `(LogCap,)` does not grant `HttpCap`
+//! ```
+
+use crate::capability::Capability;
+use crate::error::Violation;
+
+/// Capability set whose contents are only known at runtime.
+///
+/// This is the default for `Ctx<Authorized>` and preserves the behaviour of
+/// contexts built with `.require(Authorized::for_action(..))`: every
+/// capability accessor returns `Result<_, Violation>`.
+#[derive(Debug, Clone, Copy)]
+pub struct Dynamic {
+    _private: (),
+}
+
+/// Index marker used when resolving a capability in a [`Dynamic`] set.
+#[derive(Debug)]
+pub enum Runtime {}
+
+macro_rules! index_markers {
+    ($($name:ident => $pos:literal),* $(,)?) => {
+        $(
+            #[doc = concat!("Index marker for the capability at tuple position ", $pos, ".")]
+            #[derive(Debug)]
+            pub enum $name {}
+        )*
+    };
+}
+
+index_markers!(I0 => "0", I1 => "1", I2 => "2", I3 => "3", I4 => "4", I5 => "5", I6 => "6", I7 => "7");
+
+mod sealed {
+    // BREAKING CHANGE WARNING: This module MUST remain private.
+    // `Grants` impls decide whether a capability accessor skips its runtime check.
+    // A user-implementable `Grants` would let external code claim capabilities
+    // that were never authorized (CRITICAL BYPASS, CWE-863).
+    pub trait Sealed<X, I> {}
+    pub trait SealedAppend<X> {}
+}
+
+/// Proof that a capability set contains capability `X`.
+///
+/// `I` is an index marker inferred by the compiler; callers never name it.
+/// [`Access<T>`](Self::Access) is `Result<T, Violation>` for [`Dynamic`] sets
+/// and plain `T` for tuple sets, which is what lets `ctx.log()` return a
+/// logger directly when `LogCap` is part of the context type.
+///
+/// This trait is sealed and cannot be implemented outside policy-core.
+#[diagnostic::on_unimplemented(
+    message = "capability set `{Self}` does not grant `{X}`",
+    label = "`{X}` was not granted to this context",
+    note = "add `.grant::<{X}>()` to the PolicyGate that builds this context"
+)]
+pub trait Grants<X: Capability, I>: sealed::Sealed<X, I> {
+    /// What an accessor for `X` returns for this set.
+    type Access<T>;
+
+    /// Produces the accessor result.
+    ///
+    /// `granted` is the runtime answer recorded by the gate; tuple sets rely on
+    /// the type instead and ignore it.
+    #[doc(hidden)]
+    fn access<T>(
+        granted: bool,
+        make: impl FnOnce() -> T,
+        missing: impl FnOnce() -> Violation,
+    ) -> Self::Access<T>;
+}
+
+impl<X: Capability> sealed::Sealed<X, Runtime> for Dynamic {}
+
+impl<X: Capability> Grants<X, Runtime> for Dynamic {
+    type Access<T> = Result<T, Violation>;
+
+    fn access<T>(
+        granted: bool,
+        make: impl FnOnce() -> T,
+        missing: impl FnOnce() -> Violation,
+    ) -> Self::Access<T> {
+        if granted {
+            Ok(make())
+        } else {
+            Err(missing())
+        }
+    }
+}
+
+macro_rules! tuple_grants {
+    ($idx:ident: ($($T:ident),+) => $X:ident) => {
+        impl<$($T: Capability),+> sealed::Sealed<$X, $idx> for ($($T,)+) {}
+
+        impl<$($T: Capability),+> Grants<$X, $idx> for ($($T,)+) {
+            type Access<V> = V;
+
+            fn access<V>(
+                granted: bool,
+                make: impl FnOnce() -> V,
+                _missing: impl FnOnce() -> Violation,
+            ) -> Self::Access<V> {
+                // Tuple sets are only produced by PolicyGate::grant, which adds an
+                // Authorized requirement for every capability in the tuple.
+                debug_assert!(granted, "typed capability set out of sync with gate");
+                make()
+            }
+        }
+    };
+}
+
+tuple_grants!(I0: (A) => A);
+tuple_grants!(I0: (A, B) => A);
+tuple_grants!(I1: (A, B) => B);
+tuple_grants!(I0: (A, B, C) => A);
+tuple_grants!(I1: (A, B, C) => B);
+tuple_grants!(I2: (A, B, C) => C);
+tuple_grants!(I0: (A, B, C, D) => A);
+tuple_grants!(I1: (A, B, C, D) => B);
+tuple_grants!(I2: (A, B, C, D) => C);
+tuple_grants!(I3: (A, B, C, D) => D);
+tuple_grants!(I0: (A, B, C, D, E) => A);
+tuple_grants!(I1: (A, B, C, D, E) => B);
+tuple_grants!(I2: (A, B, C, D, E) => C);
+tuple_grants!(I3: (A, B, C, D, E) => D);
+tuple_grants!(I4: (A, B, C, D, E) => E);
+tuple_grants!(I0: (A, B, C, D, E, F) => A);
+tuple_grants!(I1: (A, B, C, D, E, F) => B);
+tuple_grants!(I2: (A, B, C, D, E, F) => C);
+tuple_grants!(I3: (A, B, C, D, E, F) => D);
+tuple_grants!(I4: (A, B, C, D, E, F) => E);
+tuple_grants!(I5: (A, B, C, D, E, F) => F);
+tuple_grants!(I0: (A, B, C, D, E, F, G) => A);
+tuple_grants!(I1: (A, B, C, D, E, F, G) => B);
+tuple_grants!(I2: (A, B, C, D, E, F, G) => C);
+tuple_grants!(I3: (A, B, C, D, E, F, G) => D);
+tuple_grants!(I4: (A, B, C, D, E, F, G) => E);
+tuple_grants!(I5: (A, B, C, D, E, F, G) => F);
+tuple_grants!(I6: (A, B, C, D, E, F, G) => G);
+tuple_grants!(I0: (A, B, C, D, E, F, G, H) => A);
+tuple_grants!(I1: (A, B, C, D, E, F, G, H) => B);
+tuple_grants!(I2: (A, B, C, D, E, F, G, H) => C);
+tuple_grants!(I3: (A, B, C, D, E, F, G, H) => D);
+tuple_grants!(I4: (A, B, C, D, E, F, G, H) => E);
+tuple_grants!(I5: (A, B, C, D, E, F, G, H) => F);
+tuple_grants!(I6: (A, B, C, D, E, F, G, H) => G);
+tuple_grants!(I7: (A, B, C, D, E, F, G, H) => H);
+
+/// Adds capability `X` to a capability set.
+///
+/// Used by [`PolicyGate::grant`](crate::PolicyGate::grant). Appending to
+/// [`Dynamic`] starts a typed set `(X,)`; appending to a tuple extends it.
+///
+/// This trait is sealed and cannot be implemented outside policy-core.
+#[diagnostic::on_unimplemented(
+    message = "cannot add `{X}` to capability set `{Self}`",
+    note = "typed capability sets hold at most eight capabilities"
+)]
+pub trait Append<X: Capability>: sealed::SealedAppend<X> {
+    /// The resulting capability set.
+    type Output;
+}
+
+impl<X: Capability> sealed::SealedAppend<X> for Dynamic {}
+
+impl<X: Capability> Append<X> for Dynamic {
+    type Output = (X,);
+}
+
+macro_rules! tuple_append {
+    ($($T:ident),+) => {
+        impl<X: Capability, $($T: Capability),+> sealed::SealedAppend<X> for ($($T,)+) {}
+
+        impl<X: Capability, $($T: Capability),+> Append<X> for ($($T,)+) {
+            type Output = ($($T,)+ X);
+        }
+    };
+}
+
+tuple_append!(A);
+tuple_append!(A, B);
+tuple_append!(A, B, C);
+tuple_append!(A, B, C, D);
+tuple_append!(A, B, C, D, E);
+tuple_append!(A, B, C, D, E, F);
+tuple_append!(A, B, C, D, E, F, G);
@@ -0,0 +1,107 @@
+//! Pluggable time source for deadline checks.
+//!
+//! [`ExpiringSecret`](crate::ExpiringSecret) and
+//! [`RotatingSecret`](crate::RotatingSecret) read the current time from a
+//! [`Clock`] instead of calling `SystemTime::now()` directly, so tests can
+//! move time forward with a [`ManualClock`] instead of sleeping.
+
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, SystemTime};
+
+/// Source of the current wall-clock time.
+///
+/// Implementations must be `Send + Sync` so a clock can be shared by
+/// secrets used from several threads.
+pub trait Clock: Send + Sync + fmt::Debug {
+    /// Returns the current time.
+    fn now(&self) -> SystemTime;
+}
+
+impl<T: Clock + ?Sized> Clock for Arc<T> {
+    fn now(&self) -> SystemTime {
+        (**self).now()
+    }
+}
+
+/// The operating system's clock, [`SystemTime::now()`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Clock that only moves when told to, for deterministic tests.
+///
+/// Share it through an `Arc` to advance the time seen by the secrets using it.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use policy_core::{Clock, ManualClock};
+///
+/// let clock = ManualClock::new(UNIX_EPOCH);
+/// clock.advance(Duration::from_secs(90));
+/// assert_eq!(clock.now(), UNIX_EPOCH + Duration::from_secs(90));
+/// ```
+#[derive(Debug)]
+pub struct ManualClock {
+    now: Mutex<SystemTime>,
+}
+
+impl ManualClock {
+    /// Creates a clock stopped at `now`.
+    pub fn new(now: SystemTime) -> Self {
+        Self {
+            now: Mutex::new(now),
+        }
+    }
+
+    /// Sets the current time.
+    pub fn set(&self, now: SystemTime) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) = now;
+    }
+
+    /// Moves the current time forward by `by`.
+    pub fn advance(&self, by: Duration) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) += by;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> SystemTime {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::UNIX_EPOCH;
+
+    #[test]
+    fn manual_clock_moves_only_when_told() {
+        let clock = ManualClock::new(UNIX_EPOCH);
+        assert_eq!(clock.now(), UNIX_EPOCH);
+
+        clock.advance(Duration::from_secs(5));
+        assert_eq!(clock.now(), UNIX_EPOCH + Duration::from_secs(5));
+
+        clock.set(UNIX_EPOCH + Duration::from_secs(1));
+        assert_eq!(clock.now(), UNIX_EPOCH + Duration::from_secs(1));
+    }
+
+    #[test]
+    fn shared_manual_clock_is_a_clock() {
+        let clock = Arc::new(ManualClock::new(UNIX_EPOCH));
+        let shared: Arc<dyn Clock> = Arc::new(Arc::clone(&clock));
+
+        clock.advance(Duration::from_secs(3));
+
+        assert_eq!(shared.now(), UNIX_EPOCH + Duration::from_secs(3));
+    }
+}
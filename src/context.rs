@@ -1,11 +1,15 @@
+use std::collections::BTreeSet;
 use std::marker::PhantomData;
 
 use crate::audit::{AuditCap, PolicyAudit};
-use crate::capability::{HttpCap, LogCap};
+use crate::capability::{Capability, Grant, HttpCap, LogCap};
+use crate::capset::{Dynamic, Grants};
 use crate::error::{Violation, ViolationKind};
-use crate::http::PolicyHttp;
+use crate::http::{HttpConfig, PolicyHttp};
 use crate::logging::PolicyLog;
 use crate::request::Principal;
+use crate::secret::{DeclassifyCap, PolicyDeclassify};
+use crate::secrets::{PolicySecrets, SecretsCap, SecretsConfig};
 use crate::state::{Authed, Authorized, Unauthed};
 
 /// Execution context containing request metadata and capabilities.
@@ -24,6 +28,16 @@ use crate::state::{Authed, Authorized, Unauthed};
 ///
 /// Only `Ctx<Authorized>` can access privileged operations like logging and HTTP.
 ///
+/// # Capability Sets
+///
+/// The second parameter `C` records which capabilities the context holds.
+/// `Ctx<Authorized>` is `Ctx<Authorized, Dynamic>`: capabilities are checked at
+/// runtime and `ctx.log()` returns a `Result`. A context built with
+/// [`PolicyGate::grant`](crate::PolicyGate::grant) carries a tuple such as
+/// `Ctx<Authorized, (LogCap, HttpCap)>`; its accessors return the capability
+/// directly and asking for one outside the tuple is a compile error.
+/// See [`capset`](crate::capset) for details.
+///
 /// # Construction
 ///
 /// `Ctx` cannot be constructed by user code. Use `PolicyGate` to obtain a
@@ -33,18 +47,16 @@ use crate::state::{Authed, Authorized, Unauthed};
 /// # Examples
 ///
 /// ```
-/// use policy_core::{PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
+/// use policy_core::{AllowAuthenticated, PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
 ///
 /// // Using PolicyGate (returns Ctx<Authorized> directly):
 /// let meta = RequestMeta {
 ///     request_id: "req-123".to_string(),
-///     principal: Some(Principal {
-///         id: "user-1".to_string(),
-///         name: "Alice".to_string(),
-///     }),
+///     principal: Some(Principal::new("user-1", "Alice")),
 /// };
 ///
 /// let ctx = PolicyGate::new(meta)
+///     .with_authorizer(AllowAuthenticated)
 ///     .require(Authenticated)
 ///     .require(Authorized::for_action("log"))
 ///     .build()
@@ -54,20 +66,24 @@ use crate::state::{Authed, Authorized, Unauthed};
 /// let logger = ctx.log().expect("LogCap granted");
 /// ```
 #[derive(Debug, Clone)]
-pub struct Ctx<S = Authorized> {
+pub struct Ctx<S = Authorized, C = Dynamic> {
     request_id: String,
     principal: Option<Principal>,
-    log_cap: Option<LogCap>,
-    http_cap: Option<HttpCap>,
-    audit_cap: Option<AuditCap>,
+    /// Actions authorized by the gate; capabilities are minted from these on demand.
+    granted: BTreeSet<&'static str>,
+    /// Transport and egress policy used by `PolicyHttp`.
+    http: HttpConfig,
+    /// Store and authorizer used by `PolicySecrets`.
+    secrets: SecretsConfig,
     _state: PhantomData<S>,
+    _caps: PhantomData<C>,
 }
 
 // ============================================================================
 // Shared methods (available on all states)
 // ============================================================================
 
-impl<S> Ctx<S> {
+impl<S, C> Ctx<S, C> {
     /// Returns the request ID for this context.
     pub fn request_id(&self) -> &str {
         &self.request_id
@@ -95,10 +111,11 @@ impl Ctx<Unauthed> {
         Self {
             request_id,
             principal: None,
-            log_cap: None,
-            http_cap: None,
-            audit_cap: None,
+            granted: BTreeSet::new(),
+            http: HttpConfig::default(),
+            secrets: SecretsConfig::default(),
             _state: PhantomData,
+            _caps: PhantomData,
         }
     }
 
@@ -119,10 +136,7 @@ impl Ctx<Unauthed> {
     /// use policy_core::{Ctx, Principal};
     ///
     /// let ctx = Ctx::new_unauthed("req-1".to_string());
-    /// let principal = Principal {
-    ///     id: "user-1".to_string(),
-    ///     name: "Alice".to_string(),
-    /// };
+    /// let principal = Principal::new("user-1", "Alice");
     ///
     /// let authed_ctx = ctx.authenticate(Some(principal)).expect("valid principal");
     /// ```
@@ -131,10 +145,11 @@ impl Ctx<Unauthed> {
             Ok(Ctx {
                 request_id: self.request_id,
                 principal: Some(p),
-                log_cap: None,
-                http_cap: None,
-                audit_cap: None,
+                granted: BTreeSet::new(),
+                http: HttpConfig::default(),
+                secrets: SecretsConfig::default(),
                 _state: PhantomData,
+                _caps: PhantomData,
             })
         } else {
             Err(Violation::new(
@@ -174,14 +189,16 @@ impl Ctx<Authed> {
         http_cap: Option<HttpCap>,
         audit_cap: Option<AuditCap>,
     ) -> Ctx<Authorized> {
-        Ctx {
-            request_id: self.request_id,
-            principal: self.principal,
-            log_cap,
-            http_cap,
-            audit_cap,
-            _state: PhantomData,
-        }
+        let granted = [
+            log_cap.map(|_| LogCap::ACTION),
+            http_cap.map(|_| HttpCap::ACTION),
+            audit_cap.map(|_| AuditCap::ACTION),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        Ctx::new_authorized(self.request_id, self.principal, granted)
     }
 }
 
@@ -224,43 +241,150 @@ impl Ctx<Authorized> {
         log_cap: Option<LogCap>,
         http_cap: Option<HttpCap>,
     ) -> Self {
-        Self {
-            request_id,
-            principal: None,
-            log_cap,
-            http_cap,
-            audit_cap: None,
-            _state: PhantomData,
-        }
+        let granted = [
+            log_cap.map(|_| LogCap::ACTION),
+            http_cap.map(|_| HttpCap::ACTION),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+
+        Self::new_authorized(request_id, None, granted)
     }
+}
 
+impl<C> Ctx<Authorized, C> {
     /// Creates a new authorized context with full state.
     ///
     /// This is `pub(crate)` and used internally for state transitions
     /// and PolicyGate integration.
+    ///
+    /// BREAKING CHANGE WARNING: `granted` MUST only contain actions that passed
+    /// policy validation. Every capability bound to a granted action can be
+    /// minted from this context, and every capability in a typed set `C` MUST
+    /// have its action in `granted`.
     pub(crate) fn new_authorized(
         request_id: String,
         principal: Option<Principal>,
-        log_cap: Option<LogCap>,
-        http_cap: Option<HttpCap>,
-        audit_cap: Option<AuditCap>,
+        granted: BTreeSet<&'static str>,
     ) -> Self {
         Self {
             request_id,
             principal,
-            log_cap,
-            http_cap,
-            audit_cap,
+            granted,
+            http: HttpConfig::default(),
+            secrets: SecretsConfig::default(),
             _state: PhantomData,
+            _caps: PhantomData,
         }
     }
 
+    /// Sets the transport and egress policy used by [`http`](Self::http).
+    ///
+    /// This is `pub(crate)` and used by `PolicyGate::build`.
+    pub(crate) fn with_http_config(mut self, config: HttpConfig) -> Self {
+        self.http = config;
+        self
+    }
+
+    /// Sets the store and authorizer used by [`secrets`](Self::secrets).
+    ///
+    /// This is `pub(crate)` and used by `PolicyGate::build`.
+    pub(crate) fn with_secrets_config(mut self, config: SecretsConfig) -> Self {
+        self.secrets = config;
+        self
+    }
+
+    /// Returns the capability `C` if its action was authorized.
+    ///
+    /// Works for the built-in capabilities as well as those declared with
+    /// [`capability!`](crate::capability!).
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(Violation)` with [`ViolationKind::MissingCapability`] if
+    /// `C::ACTION` was not authorized by the gate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{capability, AllowAuthenticated, Authorized, Capability, PolicyGate, Principal, RequestMeta};
+    ///
+    /// capability! {
+    ///     /// Permission to charge a card.
+    ///     pub struct PaymentCap => "payment";
+    /// }
+    ///
+    /// let meta = RequestMeta {
+    ///     request_id: "req-1".to_string(),
+    ///     principal: Some(Principal::new("u1", "Alice")),
+    /// };
+    /// let ctx = PolicyGate::new(meta)
+    ///     .with_authorizer(AllowAuthenticated)
+    ///     .require(Authorized::for_action("log"))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(ctx.capability::<PaymentCap>().is_err());
+    /// ```
+    pub fn capability<X: Capability>(&self) -> Result<X, Violation> {
+        if self.granted.contains(X::ACTION) {
+            Ok(X::from_grant(Grant::new()))
+        } else {
+            Err(Violation::new(
+                ViolationKind::MissingCapability { action: X::ACTION },
+                format!("Capability for '{}' not granted", X::ACTION),
+            ))
+        }
+    }
+
+    /// Returns capability `X`, checked against the context's capability set.
+    ///
+    /// For a typed set containing `X` this returns `X` directly; for
+    /// [`Dynamic`] it returns `Result<X, Violation>` like
+    /// [`capability`](Self::capability). The index parameter is inferred, so
+    /// call it as `ctx.cap::<X, _>()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{capability, AllowAuthenticated, PolicyGate, Principal, RequestMeta};
+    ///
+    /// capability! {
+    ///     /// Permission to write to the database.
+    ///     pub struct DbWriteCap => "db:write";
+    /// }
+    ///
+    /// let meta = RequestMeta {
+    ///     request_id: "req-1".to_string(),
+    ///     principal: Some(Principal::new("u1", "Alice")),
+    /// };
+    /// let ctx = PolicyGate::new(meta).with_authorizer(AllowAuthenticated).grant::<DbWriteCap>().build().unwrap();
+    ///
+    /// let cap: DbWriteCap = ctx.cap::<DbWriteCap, _>();
+    /// ```
+    pub fn cap<X: Capability, I>(&self) -> <C as Grants<X, I>>::Access<X>
+    where
+        C: Grants<X, I>,
+    {
+        C::access(
+            self.granted.contains(X::ACTION),
+            || X::from_grant(Grant::new()),
+            || {
+                Violation::new(
+                    ViolationKind::MissingCapability { action: X::ACTION },
+                    format!("Capability for '{}' not granted", X::ACTION),
+                )
+            },
+        )
+    }
+
     /// Returns the logging capability if present.
     ///
     /// Returns `Some(LogCap)` if logging policies were satisfied,
     /// `None` otherwise.
     pub fn log_cap(&self) -> Option<LogCap> {
-        self.log_cap
+        self.capability().ok()
     }
 
     /// Returns the HTTP capability if present.
@@ -268,7 +392,7 @@ impl Ctx<Authorized> {
     /// Returns `Some(HttpCap)` if HTTP policies were satisfied,
     /// `None` otherwise.
     pub fn http_cap(&self) -> Option<HttpCap> {
-        self.http_cap
+        self.capability().ok()
     }
 
     /// Returns the audit capability if present.
@@ -276,14 +400,31 @@ impl Ctx<Authorized> {
     /// Returns `Some(AuditCap)` if audit policies were satisfied,
     /// `None` otherwise.
     pub fn audit_cap(&self) -> Option<AuditCap> {
-        self.audit_cap
+        self.capability().ok()
+    }
+
+    /// Returns the secrets capability if present.
+    ///
+    /// Returns `Some(SecretsCap)` if the `secrets:read` action was authorized,
+    /// `None` otherwise.
+    pub fn secrets_cap(&self) -> Option<SecretsCap> {
+        self.capability().ok()
+    }
+
+    /// Returns the declassification capability if present.
+    ///
+    /// Returns `Some(DeclassifyCap)` if the `secrets:declassify` action was
+    /// authorized, `None` otherwise.
+    pub fn declassify_cap(&self) -> Option<DeclassifyCap> {
+        self.capability().ok()
     }
 
-    // Note on code duplication: The log(), http(), and audit() methods below follow
-    // a similar pattern (check capability → return wrapper or error). This duplication
-    // is intentional rather than using a macro because:
-    // 1. Only 3 methods (minimal duplication)
+    // Note on code duplication: The log(), http(), audit(), secrets() and declassify()
+    // methods below follow a similar pattern (check capability → return wrapper or
+    // error). This duplication is intentional rather than using a macro because:
+    // 1. Only 5 methods (minimal duplication)
     // 2. Each has slightly different signatures (audit() doesn't use request_id)
+    //    and its own ViolationKind for the Dynamic case
     // 3. Explicit code is clearer in security-critical contexts
     // 4. Each method's error and return types are immediately visible
 
@@ -291,93 +432,114 @@ impl Ctx<Authorized> {
     ///
     /// # Errors
     ///
-    /// Returns `Err(Violation)` if `LogCap` was not granted.
+    /// For a [`Dynamic`] capability set, returns `Err(Violation)` if `LogCap` was
+    /// not granted. For a typed set the wrapper is returned directly, and a set
+    /// without `LogCap` does not compile.
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use policy_core::{PolicyGate, RequestMeta, Principal, Authenticated, Authorized, Secret};
+    /// # use policy_core::{AllowAuthenticated, PolicyGate, RequestMeta, Principal, Authenticated, Authorized, Secret};
     /// # let meta = RequestMeta {
     /// #     request_id: "req-1".to_string(),
-    /// #     principal: Some(Principal { id: "u1".to_string(), name: "Alice".to_string() }),
+    /// #     principal: Some(Principal::new("u1", "Alice")),
     /// # };
     /// # let ctx = PolicyGate::new(meta)
+    /// #     .with_authorizer(AllowAuthenticated)
     /// #     .require(Authenticated)
     /// #     .require(Authorized::for_action("log"))
     /// #     .build()
     /// #     .unwrap();
     /// let logger = ctx.log().expect("LogCap required");
     ///
-    /// let secret = Secret::new("password123");
+    /// let secret = Secret::new("password123".to_string());
     /// logger.info(format_args!("User logged in: {:?}", secret));
     /// // Logs: "User logged in: [REDACTED]" (with request_id included)
     /// ```
-    pub fn log(&self) -> Result<PolicyLog<'_>, Violation> {
-        if self.log_cap.is_some() {
-            Ok(PolicyLog::new(&self.request_id))
-        } else {
-            Err(Violation::new(
-                ViolationKind::MissingLogCapability,
-                "Logging capability not granted",
-            ))
-        }
+    pub fn log<I>(&self) -> <C as Grants<LogCap, I>>::Access<PolicyLog<'_>>
+    where
+        C: Grants<LogCap, I>,
+    {
+        C::access(
+            self.log_cap().is_some(),
+            || PolicyLog::new(&self.request_id),
+            || {
+                Violation::new(
+                    ViolationKind::MissingLogCapability,
+                    "Logging capability not granted",
+                )
+            },
+        )
     }
 
     /// Returns a capability-gated HTTP client.
     ///
     /// # Errors
     ///
-    /// Returns `Err(Violation)` if `HttpCap` was not granted.
+    /// For a [`Dynamic`] capability set, returns `Err(Violation)` if `HttpCap` was
+    /// not granted. For a typed set the wrapper is returned directly, and a set
+    /// without `HttpCap` does not compile.
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use policy_core::{PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
-    /// # use policy_core::{Tainted, Sanitizer, StringSanitizer};
+    /// # use policy_core::{AllowAuthenticated, PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
+    /// # use policy_core::{Tainted, Sanitizer, UrlSanitizer};
     /// # let meta = RequestMeta {
     /// #     request_id: "req-1".to_string(),
-    /// #     principal: Some(Principal { id: "u1".to_string(), name: "Alice".to_string() }),
+    /// #     principal: Some(Principal::new("u1", "Alice")),
     /// # };
     /// # let ctx = PolicyGate::new(meta)
+    /// #     .with_authorizer(AllowAuthenticated)
     /// #     .require(Authenticated)
     /// #     .require(Authorized::for_action("http"))
     /// #     .build()
     /// #     .unwrap();
     /// let http = ctx.http().expect("HttpCap required");
     ///
-    /// let sanitizer = StringSanitizer::new(256).unwrap();
     /// let url = Tainted::new("https://api.example.com".to_string());
-    /// let verified_url = sanitizer.sanitize(url).unwrap();
+    /// let verified_url = UrlSanitizer::new().sanitize(url).unwrap();
     ///
-    /// http.get(&verified_url);
+    /// let response = http.get(&verified_url).expect("request sent");
     /// ```
-    pub fn http(&self) -> Result<PolicyHttp<'_>, Violation> {
-        if self.http_cap.is_some() {
-            Ok(PolicyHttp::new(&self.request_id))
-        } else {
-            Err(Violation::new(
-                ViolationKind::MissingHttpCapability,
-                "HTTP capability not granted",
-            ))
-        }
+    pub fn http<I>(&self) -> <C as Grants<HttpCap, I>>::Access<PolicyHttp<'_>>
+    where
+        C: Grants<HttpCap, I>,
+    {
+        C::access(
+            self.http_cap().is_some(),
+            || {
+                let principal = self.principal.as_ref().map(|p| p.id.as_str());
+                PolicyHttp::new(&self.request_id, principal, &self.http)
+            },
+            || {
+                Violation::new(
+                    ViolationKind::MissingHttpCapability,
+                    "HTTP capability not granted",
+                )
+            },
+        )
     }
 
     /// Returns a capability-gated audit event emitter.
     ///
     /// # Errors
     ///
-    /// Returns `Err(Violation)` if `AuditCap` was not granted.
+    /// For a [`Dynamic`] capability set, returns `Err(Violation)` if `AuditCap` was
+    /// not granted. For a typed set the wrapper is returned directly, and a set
+    /// without `AuditCap` does not compile.
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use policy_core::{PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
+    /// # use policy_core::{AllowAuthenticated, PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
     /// # use policy_core::audit::{AuditEvent, AuditEventKind, AuditOutcome};
     /// # let meta = RequestMeta {
     /// #     request_id: "req-1".to_string(),
-    /// #     principal: Some(Principal { id: "u1".to_string(), name: "Admin".to_string() }),
+    /// #     principal: Some(Principal::new("u1", "Admin")),
     /// # };
     /// # let ctx = PolicyGate::new(meta)
+    /// #     .with_authorizer(AllowAuthenticated)
     /// #     .require(Authenticated)
     /// #     .require(Authorized::for_action("audit"))
     /// #     .build()
@@ -393,15 +555,122 @@ impl Ctx<Authorized> {
     ///
     /// audit.emit(&event);
     /// ```
-    pub fn audit(&self) -> Result<PolicyAudit<'_>, Violation> {
-        if self.audit_cap.is_some() {
-            Ok(PolicyAudit::new())
-        } else {
-            Err(Violation::new(
+    pub fn audit<I>(&self) -> <C as Grants<AuditCap, I>>::Access<PolicyAudit<'_>>
+    where
+        C: Grants<AuditCap, I>,
+    {
+        C::access(self.audit_cap().is_some(), PolicyAudit::new, || {
+            Violation::new(
                 ViolationKind::MissingAuditCapability,
                 "Audit capability not granted",
-            ))
-        }
+            )
+        })
+    }
+
+    /// Returns a capability-gated secret reader.
+    ///
+    /// Secrets are loaded from the store configured with
+    /// [`PolicyGate::with_secret_store`](crate::PolicyGate::with_secret_store).
+    /// `SecretsCap` only opens the reader: each fetch is also checked against
+    /// the gate's authorizer for the action `secrets:read:<name>`.
+    ///
+    /// # Errors
+    ///
+    /// For a [`Dynamic`] capability set, returns `Err(Violation)` with
+    /// [`ViolationKind::MissingCapability`] if `SecretsCap` was not granted.
+    /// For a typed set the wrapper is returned directly, and a set without
+    /// `SecretsCap` does not compile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use policy_core::{actions, AllowAuthenticated, Authorized, DirSecretStore, PolicyGate, Principal, RequestMeta};
+    /// # let meta = RequestMeta {
+    /// #     request_id: "req-1".to_string(),
+    /// #     principal: Some(Principal::new("svc-1", "Billing")),
+    /// # };
+    /// let ctx = PolicyGate::new(meta)
+    ///     .with_authorizer(AllowAuthenticated)
+    ///     .with_secret_store(DirSecretStore::new("/run/secrets"))
+    ///     .require(Authorized::for_action(actions::SECRETS_READ))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let secrets = ctx.secrets().expect("SecretsCap required");
+    /// if let Ok(key) = secrets.fetch("stripe_api_key") {
+    ///     // `key` is a Secret<String>; the fetch was audited by name
+    /// }
+    /// ```
+    pub fn secrets<I>(&self) -> <C as Grants<SecretsCap, I>>::Access<PolicySecrets<'_>>
+    where
+        C: Grants<SecretsCap, I>,
+    {
+        C::access(
+            self.secrets_cap().is_some(),
+            || PolicySecrets::new(&self.request_id, self.principal.as_ref(), &self.secrets),
+            || {
+                Violation::new(
+                    ViolationKind::MissingCapability {
+                        action: SecretsCap::ACTION,
+                    },
+                    "Secrets capability not granted",
+                )
+            },
+        )
+    }
+
+    /// Returns a capability-gated declassifier for [`Secret`](crate::Secret) values.
+    ///
+    /// Unlike `expose_secret()`, every exposure through it requires a reason
+    /// and is audited with the caller's location and principal.
+    ///
+    /// # Errors
+    ///
+    /// For a [`Dynamic`] capability set, returns `Err(Violation)` with
+    /// [`ViolationKind::MissingCapability`] if `DeclassifyCap` was not
+    /// granted. For a typed set the wrapper is returned directly, and a set
+    /// without `DeclassifyCap` does not compile.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use policy_core::{actions, AllowAuthenticated, Authorized, PolicyGate, Principal, RequestMeta, Secret};
+    /// # let meta = RequestMeta {
+    /// #     request_id: "req-1".to_string(),
+    /// #     principal: Some(Principal::new("svc-1", "Billing")),
+    /// # };
+    /// let ctx = PolicyGate::new(meta)
+    ///     .with_authorizer(AllowAuthenticated)
+    ///     .require(Authorized::for_action(actions::DECLASSIFY))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let password = Secret::new("hunter2".to_string());
+    /// let plain = ctx
+    ///     .declassify()
+    ///     .expect("DeclassifyCap required")
+    ///     .expose(&password, "connect to billing database")
+    ///     .unwrap();
+    /// ```
+    pub fn declassify<I>(&self) -> <C as Grants<DeclassifyCap, I>>::Access<PolicyDeclassify<'_>>
+    where
+        C: Grants<DeclassifyCap, I>,
+    {
+        C::access(
+            self.declassify_cap().is_some(),
+            || {
+                let principal = self.principal.as_ref().map(|p| p.id.as_str());
+                PolicyDeclassify::new(&self.request_id, principal)
+            },
+            || {
+                Violation::new(
+                    ViolationKind::MissingCapability {
+                        action: DeclassifyCap::ACTION,
+                    },
+                    "Declassification capability not granted",
+                )
+            },
+        )
     }
 }
 
@@ -479,10 +748,7 @@ mod tests {
     #[test]
     fn authenticate_with_principal_succeeds() {
         let ctx = Ctx::new_unauthed("req-auth".to_string());
-        let principal = Principal {
-            id: "user-1".to_string(),
-            name: "Alice".to_string(),
-        };
+        let principal = Principal::new("user-1", "Alice");
 
         let authed_ctx = ctx
             .authenticate(Some(principal))
@@ -505,10 +771,7 @@ mod tests {
     #[test]
     fn authorize_grants_capabilities() {
         let ctx = Ctx::new_unauthed("req-authz".to_string());
-        let principal = Principal {
-            id: "user-2".to_string(),
-            name: "Bob".to_string(),
-        };
+        let principal = Principal::new("user-2", "Bob");
 
         let authed_ctx = ctx.authenticate(Some(principal)).unwrap();
         let authorized_ctx = authed_ctx.authorize(Some(LogCap::new()), Some(HttpCap::new()), None);
@@ -526,10 +789,7 @@ mod tests {
         let unauthed = Ctx::new_unauthed("req-progression".to_string());
         assert!(unauthed.principal().is_none());
 
-        let principal = Principal {
-            id: "user-3".to_string(),
-            name: "Charlie".to_string(),
-        };
+        let principal = Principal::new("user-3", "Charlie");
         let authed = unauthed.authenticate(Some(principal)).unwrap();
         assert!(authed.principal().is_some());
 
@@ -542,15 +802,10 @@ mod tests {
 
     #[test]
     fn authorized_ctx_can_access_log() {
-        let ctx = Ctx::new_authorized(
+        let ctx: Ctx = Ctx::new_authorized(
             "req-log".to_string(),
-            Some(Principal {
-                id: "user-4".to_string(),
-                name: "Dana".to_string(),
-            }),
-            Some(LogCap::new()),
-            None,
-            None,
+            Some(Principal::new("user-4", "Dana")),
+            BTreeSet::from([LogCap::ACTION]),
         );
 
         assert!(ctx.log().is_ok());
@@ -558,15 +813,10 @@ mod tests {
 
     #[test]
     fn authorized_ctx_can_access_http() {
-        let ctx = Ctx::new_authorized(
+        let ctx: Ctx = Ctx::new_authorized(
             "req-http".to_string(),
-            Some(Principal {
-                id: "user-5".to_string(),
-                name: "Eve".to_string(),
-            }),
-            None,
-            Some(HttpCap::new()),
-            None,
+            Some(Principal::new("user-5", "Eve")),
+            BTreeSet::from([HttpCap::ACTION]),
         );
 
         assert!(ctx.http().is_ok());
@@ -574,15 +824,10 @@ mod tests {
 
     #[test]
     fn authorized_ctx_can_access_audit() {
-        let ctx = Ctx::new_authorized(
+        let ctx: Ctx = Ctx::new_authorized(
             "req-audit".to_string(),
-            Some(Principal {
-                id: "user-6".to_string(),
-                name: "Frank".to_string(),
-            }),
-            None,
-            None,
-            Some(AuditCap::new()),
+            Some(Principal::new("user-6", "Frank")),
+            BTreeSet::from([AuditCap::ACTION]),
         );
 
         assert!(ctx.audit().is_ok());
@@ -590,8 +835,11 @@ mod tests {
 
     #[test]
     fn ctx_audit_requires_capability() {
-        let ctx_with_cap =
-            Ctx::new_authorized("req-1".to_string(), None, None, None, Some(AuditCap::new()));
+        let ctx_with_cap: Ctx = Ctx::new_authorized(
+            "req-1".to_string(),
+            None,
+            BTreeSet::from([AuditCap::ACTION]),
+        );
         assert!(ctx_with_cap.audit().is_ok());
 
         let ctx_without_cap = Ctx::new_unchecked("req-2".to_string(), None, None);
@@ -602,4 +850,100 @@ mod tests {
             ViolationKind::MissingAuditCapability
         );
     }
+
+    #[test]
+    fn ctx_secrets_requires_capability() {
+        let ctx_with_cap: Ctx = Ctx::new_authorized(
+            "req-1".to_string(),
+            None,
+            BTreeSet::from([SecretsCap::ACTION]),
+        );
+        assert!(ctx_with_cap.secrets().is_ok());
+
+        let ctx_without_cap = Ctx::new_unchecked("req-2".to_string(), Some(LogCap::new()), None);
+        assert_eq!(
+            ctx_without_cap.secrets().unwrap_err().kind,
+            ViolationKind::MissingCapability {
+                action: "secrets:read"
+            }
+        );
+    }
+
+    #[test]
+    fn ctx_declassify_requires_capability() {
+        let ctx_with_cap: Ctx = Ctx::new_authorized(
+            "req-1".to_string(),
+            None,
+            BTreeSet::from([DeclassifyCap::ACTION]),
+        );
+        assert!(ctx_with_cap.declassify().is_ok());
+
+        let ctx_without_cap: Ctx = Ctx::new_authorized(
+            "req-2".to_string(),
+            None,
+            BTreeSet::from([SecretsCap::ACTION]),
+        );
+        assert_eq!(
+            ctx_without_cap.declassify().unwrap_err().kind,
+            ViolationKind::MissingCapability {
+                action: "secrets:declassify"
+            }
+        );
+    }
+
+    crate::capability! {
+        /// Test capability.
+        struct DbWriteCap => "db:write";
+    }
+
+    #[test]
+    fn ctx_mints_declared_capabilities_for_granted_actions() {
+        let ctx: Ctx = Ctx::new_authorized(
+            "req-db".to_string(),
+            Some(Principal::new("user-7", "Gina")),
+            BTreeSet::from(["db:write"]),
+        );
+
+        assert!(ctx.capability::<DbWriteCap>().is_ok());
+        assert!(ctx.log_cap().is_none());
+    }
+
+    #[test]
+    fn ctx_capability_reports_missing_action() {
+        let ctx = Ctx::new_unchecked("req-db".to_string(), Some(LogCap::new()), None);
+
+        let result = ctx.capability::<DbWriteCap>();
+        assert_eq!(
+            result.unwrap_err().kind,
+            ViolationKind::MissingCapability { action: "db:write" }
+        );
+        assert!(ctx.capability::<LogCap>().is_ok());
+    }
+
+    #[test]
+    fn typed_ctx_accessors_return_capabilities_directly() {
+        let ctx: Ctx<Authorized, (LogCap, DbWriteCap)> = Ctx::new_authorized(
+            "req-typed".to_string(),
+            Some(Principal::new("user-8", "Hank")),
+            BTreeSet::from([LogCap::ACTION, DbWriteCap::ACTION]),
+        );
+
+        let _log: PolicyLog<'_> = ctx.log();
+        let _cap: DbWriteCap = ctx.cap::<DbWriteCap, _>();
+
+        // Runtime accessors still answer for capabilities outside the type
+        assert!(ctx.http_cap().is_none());
+        assert!(ctx.capability::<LogCap>().is_ok());
+    }
+
+    #[test]
+    fn dynamic_ctx_cap_is_checked_at_runtime() {
+        let ctx = Ctx::new_unchecked("req-dyn".to_string(), Some(LogCap::new()), None);
+
+        assert!(ctx.cap::<LogCap, _>().is_ok());
+        assert_eq!(
+            ctx.cap::<DbWriteCap, _>().unwrap_err().kind,
+            ViolationKind::MissingCapability { action: "db:write" }
+        );
+    }
 }
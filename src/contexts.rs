@@ -0,0 +1,110 @@
+//! Output contexts that tie a [`Verified`](crate::Verified) value to the sinks
+//! it is safe for.
+//!
+//! A string that is safe in a log line is not necessarily safe in HTML or a
+//! shell command. The second type parameter of `Verified<T, C>` records which
+//! context the value was verified for:
+//!
+//! - Every [`Sanitizer`](crate::Sanitizer) declares the context it produces
+//!   through its `Context` associated type.
+//! - Every [`Sink`](crate::Sink) declares the context it accepts the same way.
+//!
+//! Passing a value verified for one context to a sink for another is a
+//! compile error:
+//!
+//! ```compile_fail
+//! use policy_core::{HtmlEncoder, Sanitizer, Sink, Tainted, VecSink};
+//!
+//! let sink = VecSink::new(); // accepts `LogLine` values
+//! let html = HtmlEncoder::new()
+//!     .sanitize(Tainted::new("<b>hi</b>".to_string()))
+//!     .unwrap(); // `Verified<String, HtmlText>`
+//!
+//! sink.sink(&html); // Error: expected `Verified<String, LogLine>`
+//! ```
+//!
+//! To move a value into another context, chain the sanitizer for that context
+//! with [`Sanitizer::and_then`](crate::Sanitizer::and_then); the value is
+//! re-checked from scratch against the new context's rules.
+
+use std::fmt::Debug;
+
+mod sealed {
+    // BREAKING CHANGE WARNING: This module MUST remain private.
+    // Sinks trust the context parameter of Verified<T, C>. A user-implementable
+    // context would not by itself mint Verified values, but it would let
+    // external code define contexts no sanitizer in this crate checks for.
+    pub trait Sealed {}
+}
+
+/// Marker trait for the contexts a [`Verified`](crate::Verified) value can be
+/// verified for.
+///
+/// This trait is sealed and cannot be implemented outside policy-core.
+pub trait VerificationContext: sealed::Sealed + Debug + 'static {
+    /// Short name of the context, used in `Debug` output.
+    const NAME: &'static str;
+}
+
+macro_rules! contexts {
+    ($($(#[$doc:meta])* $name:ident => $label:literal),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug)]
+            pub enum $name {}
+
+            impl sealed::Sealed for $name {}
+
+            impl VerificationContext for $name {
+                const NAME: &'static str = $label;
+            }
+        )*
+    };
+}
+
+contexts! {
+    /// Validated by its sanitizer but not certified for any particular sink.
+    ///
+    /// This is the default context of `Verified<T>`. Structured values such as
+    /// integers, UUIDs and timestamps are verified in this context.
+    Plain => "plain",
+    /// Single-line text free of control characters, safe to write to a log.
+    ///
+    /// Produced by [`StringSanitizer`](crate::StringSanitizer) and accepted by
+    /// [`VecSink`](crate::VecSink).
+    LogLine => "log_line",
+    /// A URL that outbound requests may be sent to.
+    ///
+    /// Produced by [`UrlSanitizer`](crate::UrlSanitizer) and accepted by
+    /// [`PolicyHttp`](crate::PolicyHttp).
+    HttpTarget => "http_target",
+    /// Text that may be sent as an outbound HTTP request body.
+    ///
+    /// Produced by [`HttpBodySanitizer`](crate::HttpBodySanitizer) and
+    /// accepted by [`PolicyHttp`](crate::PolicyHttp).
+    HttpBody => "http_body",
+    /// Text safe to embed in HTML element content or a quoted attribute value.
+    ///
+    /// Produced by [`HtmlEncoder`](crate::HtmlEncoder).
+    Html => "html",
+    /// Text safe to embed in any HTML attribute value, quoted or unquoted.
+    ///
+    /// Produced by [`HtmlAttributeEncoder`](crate::HtmlAttributeEncoder).
+    HtmlAttribute => "html_attribute",
+    /// A single percent-encoded URL path segment.
+    ///
+    /// Produced by [`PathSegmentEncoder`](crate::PathSegmentEncoder).
+    UrlPathSegment => "url_path_segment",
+    /// A percent-encoded URL query parameter name or value.
+    ///
+    /// Produced by [`QueryValueEncoder`](crate::QueryValueEncoder).
+    UrlQueryValue => "url_query_value",
+    /// A complete, quoted JSON string literal.
+    ///
+    /// Produced by [`JsonStringEncoder`](crate::JsonStringEncoder).
+    JsonString => "json_string",
+    /// A value safe to pass as a single shell argument.
+    ///
+    /// Produced by [`ShellQuoter`](crate::ShellQuoter).
+    ShellArg => "shell_arg",
+}
@@ -44,8 +44,8 @@
 //! ```
 
 use crate::{
-    Authenticated, Authorized, PolicyGate, Principal, RequestMeta, Sanitizer, Sink,
-    StringSanitizer, Tainted, VecSink,
+    AllowAuthenticated, Authenticated, Authorized, HttpBodySanitizer, PolicyGate, Principal,
+    RequestMeta, Sanitizer, Sink, StringSanitizer, Tainted, UrlSanitizer, VecSink,
 };
 
 /// Processes untrusted user input through the taint tracking pipeline.
@@ -218,7 +218,7 @@ fn attempt_bypass(raw_input: &str) -> Result<Vec<String>, String> {
 /// 1. **Policy Gate**: Validates authentication and authorization
 /// 2. **Capability**: Obtains HttpCap from validated context
 /// 3. **Taint Origin**: Wraps untrusted URL and body as `Tainted<String>`
-/// 4. **Sanitization**: Validates and promotes to `Verified<String>`
+/// 4. **Sanitization**: Promotes the URL to `Verified<Url>` and the body to `Verified<String>`
 /// 5. **HTTP Sink**: PolicyHttp accepts only verified data
 ///
 /// # Arguments
@@ -259,14 +259,15 @@ fn process_http_request(user_id: &str, raw_url: &str, raw_body: &str) -> Result<
     // Create request metadata with authenticated principal
     let meta = RequestMeta {
         request_id: format!("req-{}", user_id),
-        principal: Some(Principal {
-            id: user_id.to_string(),
-            name: format!("User {}", user_id),
-        }),
+        principal: Some(Principal::new(
+            user_id.to_string(),
+            format!("User {}", user_id),
+        )),
     };
 
     // Build context with authentication and HTTP authorization
     let ctx = PolicyGate::new(meta)
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action("http"))
         .build()
@@ -286,18 +287,19 @@ fn process_http_request(user_id: &str, raw_url: &str, raw_body: &str) -> Result<
 
     // Step 4: SANITIZATION
     // Use a real sanitizer to validate and promote to Verified<T>
-    let sanitizer = StringSanitizer::new(1024).unwrap();
-    let verified_url = sanitizer
+    let verified_url = UrlSanitizer::new()
         .sanitize(tainted_url)
         .map_err(|e| format!("URL sanitization failed: {}", e))?;
+    let sanitizer = HttpBodySanitizer::new(1024).unwrap();
     let verified_body = sanitizer
         .sanitize(tainted_body)
         .map_err(|e| format!("Body sanitization failed: {}", e))?;
 
     // Step 5: HTTP SINK
-    // PolicyHttp accepts ONLY Verified<String> for URLs and bodies
+    // PolicyHttp accepts ONLY verified URLs and bodies
     // This is enforced at compile time
-    http.post(&verified_url, &verified_body);
+    http.post(&verified_url, &verified_body)
+        .map_err(|e| format!("HTTP request failed: {}", e))?;
 
     Ok(())
 }
@@ -499,10 +501,17 @@ mod tests {
         assert!(err.contains("URL sanitization failed"));
     }
 
+    #[test]
+    fn http_end_to_end_rejects_non_http_url() {
+        let result = process_http_request("user-789", "file:///etc/passwd", "{}");
+
+        assert!(result.unwrap_err().contains("URL sanitization failed"));
+    }
+
     #[test]
     fn http_end_to_end_rejects_invalid_body() {
         // Body containing control characters should be rejected
-        let result = process_http_request("user-999", "https://api.example.com", "bad\nbody\ndata");
+        let result = process_http_request("user-999", "https://api.example.com", "bad\0body\0data");
 
         assert!(result.is_err());
         let err = result.unwrap_err();
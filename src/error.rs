@@ -1,16 +1,25 @@
 use std::fmt;
 
+use crate::http::{EgressDenial, HttpError};
+use crate::secrets::SecretError;
+use crate::Provenance;
+
 /// Errors that can occur in the policy enforcement crate.
 #[derive(Debug)]
 pub enum Error {
     /// A policy violation occurred
     Violation(Violation),
+    /// An outbound HTTP request failed
+    Http(HttpError),
+    /// A secret could not be fetched
+    Secret(SecretError),
 }
 
 impl fmt::Display for Error {
     /// Formats the error as a human-readable policy message.
     ///
     /// Violations are displayed with the prefix "Policy violation: " followed by the violation's formatted representation.
+    /// HTTP and secret errors are displayed as-is.
     ///
     /// # Examples
     ///
@@ -24,11 +33,21 @@ impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Error::Violation(v) => write!(f, "Policy violation: {}", v),
+            Error::Http(e) => write!(f, "{}", e),
+            Error::Secret(e) => write!(f, "{}", e),
         }
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Violation(_) => None,
+            Error::Http(e) => Some(e),
+            Error::Secret(e) => Some(e),
+        }
+    }
+}
 
 impl From<Violation> for Error {
     /// Converts a `Violation` into the crate's top-level `Error`.
@@ -50,6 +69,23 @@ impl From<Violation> for Error {
     }
 }
 
+impl From<HttpError> for Error {
+    /// Converts an `HttpError` into the crate's top-level `Error`.
+    ///
+    /// This lets handlers returning `Result<T, Error>` use `?` on both
+    /// capability checks and HTTP requests.
+    fn from(e: HttpError) -> Self {
+        Error::Http(e)
+    }
+}
+
+impl From<SecretError> for Error {
+    /// Converts a `SecretError` into the crate's top-level `Error`.
+    fn from(e: SecretError) -> Self {
+        Error::Secret(e)
+    }
+}
+
 /// A policy violation with details about what failed.
 #[derive(Debug)]
 pub struct Violation {
@@ -57,6 +93,9 @@ pub struct Violation {
     pub kind: ViolationKind,
     /// Human-readable message explaining the violation
     pub message: String,
+    /// Input channel of the value that caused the violation, for
+    /// [`ViolationKind::InvalidInput`] raised from a labeled input
+    pub provenance: Option<Provenance>,
 }
 
 impl Violation {
@@ -77,8 +116,25 @@ impl Violation {
         Self {
             kind,
             message: message.into(),
+            provenance: None,
         }
     }
+
+    /// Records which input channel the offending value came from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{Provenance, Violation, ViolationKind};
+    ///
+    /// let v = Violation::new(ViolationKind::InvalidInput, "bad address")
+    ///     .with_provenance(Provenance::header("x-forwarded-for"));
+    /// assert_eq!(v.provenance, Some(Provenance::header("x-forwarded-for")));
+    /// ```
+    pub fn with_provenance(mut self, provenance: Provenance) -> Self {
+        self.provenance = Some(provenance);
+        self
+    }
 }
 
 impl fmt::Display for Violation {
@@ -100,11 +156,30 @@ impl fmt::Display for Violation {
 impl std::error::Error for Violation {}
 
 impl From<crate::SanitizationError> for Violation {
+    /// Converts a sanitization failure into an `InvalidInput` violation,
+    /// keeping the provenance of the rejected value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{Provenance, Sanitizer, StringSanitizer, Tainted, Violation};
+    ///
+    /// let input = Tainted::new("a\u{0}b".to_string()).with_provenance(Provenance::query("q"));
+    /// let err = StringSanitizer::new(16).unwrap().sanitize(input).unwrap_err();
+    ///
+    /// let v = Violation::from(err);
+    /// assert_eq!(v.provenance, Some(Provenance::query("q")));
+    /// assert!(v.message.ends_with("(from query 'q')"));
+    /// ```
     fn from(err: crate::SanitizationError) -> Self {
-        Violation::new(
+        let violation = Violation::new(
             ViolationKind::InvalidInput,
             format!("Sanitization failed: {}", err),
-        )
+        );
+        match err.provenance() {
+            Some(provenance) => violation.with_provenance(provenance.clone()),
+            None => violation,
+        }
     }
 }
 
@@ -118,14 +193,34 @@ pub enum ViolationKind {
         /// The action that was not authorized
         action: &'static str,
     },
+    /// The principal does not hold a required role
+    MissingRole {
+        /// The role that was required
+        role: &'static str,
+    },
+    /// The principal was not granted a required scope
+    MissingScope {
+        /// The scope that was required
+        scope: &'static str,
+    },
     /// Logging capability was not granted
     MissingLogCapability,
     /// HTTP capability was not granted
     MissingHttpCapability,
     /// Audit capability was not granted
     MissingAuditCapability,
+    /// A user-defined capability was not granted
+    MissingCapability {
+        /// The action the capability is bound to
+        action: &'static str,
+    },
     /// Input validation failed (malformed, forbidden characters, etc.)
     InvalidInput,
+    /// An outbound request was outside the configured egress policy
+    EgressDenied {
+        /// The egress rule the request failed
+        reason: EgressDenial,
+    },
 }
 
 impl fmt::Display for ViolationKind {
@@ -142,6 +237,10 @@ impl fmt::Display for ViolationKind {
     ///     "Unauthorized for 'delete'"
     /// );
     /// assert_eq!(
+    ///     format!("{}", ViolationKind::MissingRole { role: "admin" }),
+    ///     "Missing role 'admin'"
+    /// );
+    /// assert_eq!(
     ///     format!("{}", ViolationKind::MissingLogCapability),
     ///     "Missing logging capability"
     /// );
@@ -154,10 +253,16 @@ impl fmt::Display for ViolationKind {
         match self {
             ViolationKind::Unauthenticated => write!(f, "Unauthenticated"),
             ViolationKind::Unauthorized { action } => write!(f, "Unauthorized for '{}'", action),
+            ViolationKind::MissingRole { role } => write!(f, "Missing role '{}'", role),
+            ViolationKind::MissingScope { scope } => write!(f, "Missing scope '{}'", scope),
             ViolationKind::MissingLogCapability => write!(f, "Missing logging capability"),
             ViolationKind::MissingHttpCapability => write!(f, "Missing HTTP capability"),
             ViolationKind::MissingAuditCapability => write!(f, "Missing audit capability"),
+            ViolationKind::MissingCapability { action } => {
+                write!(f, "Missing capability for '{}'", action)
+            }
             ViolationKind::InvalidInput => write!(f, "Invalid input"),
+            ViolationKind::EgressDenied { reason } => write!(f, "Egress denied ({})", reason),
         }
     }
 }
@@ -180,10 +285,22 @@ mod proptests {
                 Just("write"),
             ]
             .prop_map(|action| ViolationKind::Unauthorized { action }),
+            prop_oneof![Just("admin"), Just("auditor")]
+                .prop_map(|role| ViolationKind::MissingRole { role }),
+            prop_oneof![Just("items:read"), Just("items:write")]
+                .prop_map(|scope| ViolationKind::MissingScope { scope }),
             Just(ViolationKind::MissingLogCapability),
             Just(ViolationKind::MissingHttpCapability),
             Just(ViolationKind::MissingAuditCapability),
+            prop_oneof![Just("db:write"), Just("email:send")]
+                .prop_map(|action| ViolationKind::MissingCapability { action }),
             Just(ViolationKind::InvalidInput),
+            prop_oneof![
+                Just(EgressDenial::Host),
+                Just(EgressDenial::PrivateAddress),
+                Just(EgressDenial::Path),
+            ]
+            .prop_map(|reason| ViolationKind::EgressDenied { reason }),
         ]
     }
 
@@ -237,6 +354,12 @@ mod proptests {
                     prop_assert!(display_output.ends_with('\''));
                     prop_assert!(display_output.contains(action));
                 }
+                ViolationKind::MissingRole { role } => {
+                    prop_assert_eq!(display_output, format!("Missing role '{}'", role));
+                }
+                ViolationKind::MissingScope { scope } => {
+                    prop_assert_eq!(display_output, format!("Missing scope '{}'", scope));
+                }
                 ViolationKind::MissingLogCapability => {
                     prop_assert_eq!(display_output, "Missing logging capability");
                 }
@@ -246,9 +369,15 @@ mod proptests {
                 ViolationKind::MissingAuditCapability => {
                     prop_assert_eq!(display_output, "Missing audit capability");
                 }
+                ViolationKind::MissingCapability { action } => {
+                    prop_assert_eq!(display_output, format!("Missing capability for '{}'", action));
+                }
                 ViolationKind::InvalidInput => {
                     prop_assert_eq!(display_output, "Invalid input");
                 }
+                ViolationKind::EgressDenied { reason } => {
+                    prop_assert_eq!(display_output, format!("Egress denied ({})", reason.reason()));
+                }
             }
         }
     }
@@ -1,33 +1,40 @@
 use crate::{
-    audit::AuditCap,
-    capability::{HttpCap, LogCap},
+    authorizer::{Authorizer, DenyAll},
+    capability::Capability,
+    capset::{Append, Dynamic},
     context::Ctx,
     error::{Violation, ViolationKind},
-    policy::{actions, PolicyReq},
+    http::{AsyncHttpTransport, EgressPolicy, HttpConfig, HttpTransport},
+    policy::{Authorized as AuthorizedPolicy, PolicyReq},
     request::RequestMeta,
+    secrets::{SecretStore, SecretsConfig},
     state::Authorized,
 };
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
+use std::marker::PhantomData;
+use std::sync::Arc;
 
 /// The policy enforcement gate.
 ///
 /// `PolicyGate` is the only way to construct a valid `Ctx`.
 /// It validates policy requirements before granting capabilities.
 ///
+/// `Authorized` requirements are decided by an [`Authorizer`]. Unless one is
+/// configured with [`with_authorizer`](Self::with_authorizer), the gate uses
+/// [`DenyAll`] and every `Authorized` requirement fails.
+///
 /// # Examples
 ///
 /// ```
-/// use policy_core::{PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
+/// use policy_core::{AllowAuthenticated, PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
 ///
 /// let meta = RequestMeta {
 ///     request_id: "req-123".to_string(),
-///     principal: Some(Principal {
-///         id: "user-1".to_string(),
-///         name: "Alice".to_string(),
-///     }),
+///     principal: Some(Principal::new("user-1", "Alice")),
 /// };
 ///
 /// let ctx = PolicyGate::new(meta)
+///     .with_authorizer(AllowAuthenticated)
 ///     .require(Authenticated)
 ///     .require(Authorized::for_action("log"))
 ///     .build()
@@ -35,24 +42,226 @@ use std::collections::HashSet;
 ///
 /// assert!(ctx.log_cap().is_some());
 /// ```
-pub struct PolicyGate {
+///
+/// # Typed Capability Sets
+///
+/// The parameter `C` tracks capabilities requested with
+/// [`grant`](Self::grant). It starts as [`Dynamic`]; each `grant::<X>()`
+/// appends `X`, and `build()` returns a `Ctx<Authorized, C>` whose accessors
+/// for those capabilities cannot fail.
+pub struct PolicyGate<C = Dynamic> {
     // BREAKING CHANGE WARNING: These fields MUST remain private.
     // Making them public allows external code to modify policy requirements after construction,
     // bypassing validation and enabling privilege escalation.
     meta: RequestMeta,
     requirements: Vec<PolicyReq>, // Preserve order for deterministic validation
     requirement_set: HashSet<PolicyReq>, // O(1) deduplication
+    authorizer: Arc<dyn Authorizer>,
+    http: HttpConfig,
+    secrets: Option<Arc<dyn SecretStore>>,
+    _caps: PhantomData<C>,
 }
 
 impl PolicyGate {
     /// Creates a new policy gate with the given request metadata.
+    ///
+    /// The gate starts with the [`DenyAll`] authorizer, so `Authorized`
+    /// requirements fail until [`with_authorizer`](PolicyGate::with_authorizer)
+    /// is called.
     pub fn new(meta: RequestMeta) -> Self {
         Self {
             meta,
             requirements: Vec::new(),
             requirement_set: HashSet::new(),
+            authorizer: Arc::new(DenyAll),
+            http: HttpConfig::default(),
+            secrets: None,
+            _caps: PhantomData,
         }
     }
+}
+
+impl<C> PolicyGate<C> {
+    /// Sets the authorizer consulted for `Authorized` requirements.
+    ///
+    /// Replaces any previously configured authorizer. Share one authorizer
+    /// across requests by passing an `Arc<A>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{PolicyGate, RequestMeta, Principal, Authorized, ViolationKind};
+    ///
+    /// let meta = RequestMeta {
+    ///     request_id: "req-123".to_string(),
+    ///     principal: Some(Principal::new("user-1", "Alice")),
+    /// };
+    ///
+    /// let result = PolicyGate::new(meta)
+    ///     .with_authorizer(|_principal: &Principal, action: &str| action == "log")
+    ///     .require(Authorized::for_action("audit"))
+    ///     .build();
+    ///
+    /// let violation = result.unwrap_err();
+    /// assert_eq!(violation.kind, ViolationKind::Unauthorized { action: "audit" });
+    /// ```
+    pub fn with_authorizer(mut self, authorizer: impl Authorizer + 'static) -> Self {
+        self.authorizer = Arc::new(authorizer);
+        self
+    }
+
+    /// Sets the transport used by `PolicyHttp` in the built context.
+    ///
+    /// Without a transport, `ctx.http()` records requests and answers them
+    /// with an empty `200` instead of touching the network. Share one
+    /// transport (and its connection pool) across requests by passing an
+    /// `Arc<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use policy_core::{AllowAuthenticated, Authorized, HttpResponse, PolicyGate, Principal, RecordingTransport, RequestMeta};
+    /// # use policy_core::{Sanitizer, Tainted, UrlSanitizer};
+    ///
+    /// let transport = Arc::new(RecordingTransport::with_response(HttpResponse::new(204, "")));
+    ///
+    /// let meta = RequestMeta {
+    ///     request_id: "req-123".to_string(),
+    ///     principal: Some(Principal::new("user-1", "Alice")),
+    /// };
+    /// let ctx = PolicyGate::new(meta)
+    ///     .with_authorizer(AllowAuthenticated)
+    ///     .with_http_transport(Arc::clone(&transport))
+    ///     .require(Authorized::for_action("http"))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// # let url = UrlSanitizer::new()
+    /// #     .sanitize(Tainted::new("https://api.example.com".to_string())).unwrap();
+    /// let response = ctx.http().unwrap().delete(&url).unwrap();
+    /// assert_eq!(response.status(), 204);
+    /// assert_eq!(transport.request_count(), 1);
+    /// ```
+    pub fn with_http_transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.http.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Restricts the destinations `PolicyHttp` may contact.
+    ///
+    /// Requests outside the policy fail with `ViolationKind::EgressDenied`
+    /// and are reported as an `AuditEventKind::SecurityEvent`. Without an
+    /// egress policy, any verified URL may be requested.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{AllowAuthenticated, Authorized, EgressPolicy, Error, PolicyGate, Principal, RequestMeta, ViolationKind};
+    /// # use policy_core::{Sanitizer, Tainted, UrlSanitizer};
+    ///
+    /// let meta = RequestMeta {
+    ///     request_id: "req-123".to_string(),
+    ///     principal: Some(Principal::new("user-1", "Alice")),
+    /// };
+    /// let ctx = PolicyGate::new(meta)
+    ///     .with_authorizer(AllowAuthenticated)
+    ///     .with_egress_policy(EgressPolicy::new().allow_host("api.example.com"))
+    ///     .require(Authorized::for_action("http"))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// # let metadata_url = UrlSanitizer::new()
+    /// #     .sanitize(Tainted::new("http://169.254.169.254/latest".to_string())).unwrap();
+    /// let err = ctx.http().unwrap().get(&metadata_url).unwrap_err();
+    /// assert!(matches!(err, Error::Violation(v) if matches!(v.kind, ViolationKind::EgressDenied { .. })));
+    /// ```
+    pub fn with_egress_policy(mut self, policy: EgressPolicy) -> Self {
+        self.http.egress = Some(Arc::new(policy));
+        self
+    }
+
+    /// Sets the transport used by `PolicyHttp`'s `*_async` methods.
+    ///
+    /// Without one, the async methods record requests and answer them with an
+    /// empty `200`, like the blocking methods without a transport. Both
+    /// transports may be configured on the same gate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use policy_core::{AllowAuthenticated, Authorized, PolicyGate, Principal, RecordingTransport, RequestMeta};
+    /// # use std::future::Future;
+    /// # use policy_core::{Sanitizer, Tainted, UrlSanitizer};
+    /// # fn block_on<F: Future>(future: F) -> F::Output {
+    /// #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+    /// #     match std::pin::pin!(future).poll(&mut cx) {
+    /// #         std::task::Poll::Ready(output) => output,
+    /// #         std::task::Poll::Pending => unreachable!(),
+    /// #     }
+    /// # }
+    ///
+    /// let transport = Arc::new(RecordingTransport::new());
+    ///
+    /// let meta = RequestMeta {
+    ///     request_id: "req-123".to_string(),
+    ///     principal: Some(Principal::new("user-1", "Alice")),
+    /// };
+    /// let ctx = PolicyGate::new(meta)
+    ///     .with_authorizer(AllowAuthenticated)
+    ///     .with_async_http_transport(Arc::clone(&transport))
+    ///     .require(Authorized::for_action("http"))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// # let url = UrlSanitizer::new()
+    /// #     .sanitize(Tainted::new("https://api.example.com".to_string())).unwrap();
+    /// # block_on(async {
+    /// let response = ctx.http().unwrap().get_async(&url).await.unwrap();
+    /// assert!(response.is_success());
+    /// # });
+    /// assert_eq!(transport.request_count(), 1);
+    /// ```
+    pub fn with_async_http_transport(
+        mut self,
+        transport: impl AsyncHttpTransport + 'static,
+    ) -> Self {
+        self.http.async_transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Sets the store `PolicySecrets` loads secrets from.
+    ///
+    /// Without a store, every `fetch()` fails with
+    /// `SecretErrorKind::NotConfigured`. Reading requires the `secrets:read`
+    /// action, and each fetch also asks the gate's authorizer for
+    /// `secrets:read:<name>`. Every fetch is audited by name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{actions, AllowAuthenticated, Authorized, EnvSecretStore, PolicyGate, Principal, RequestMeta};
+    ///
+    /// # std::env::set_var("GATE_DOCS_API_KEY", "sk-test");
+    /// let meta = RequestMeta {
+    ///     request_id: "req-123".to_string(),
+    ///     principal: Some(Principal::new("svc-1", "Billing")),
+    /// };
+    /// let ctx = PolicyGate::new(meta)
+    ///     .with_authorizer(AllowAuthenticated)
+    ///     .with_secret_store(EnvSecretStore::new().with_prefix("GATE_DOCS_"))
+    ///     .require(Authorized::for_action(actions::SECRETS_READ))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let key = ctx.secrets().unwrap().fetch("API_KEY").unwrap();
+    /// assert_eq!(key.expose_secret(), "sk-test");
+    /// ```
+    pub fn with_secret_store(mut self, store: impl SecretStore + 'static) -> Self {
+        self.secrets = Some(Arc::new(store));
+        self
+    }
 
     /// Adds a policy requirement to the gate, deduplicating identical requirements.
     ///
@@ -66,10 +275,7 @@ impl PolicyGate {
     ///
     /// let meta = RequestMeta {
     ///     request_id: "req-123".to_string(),
-    ///     principal: Some(Principal {
-    ///         id: "u1".to_string(),
-    ///         name: "Alice".to_string(),
-    ///     }),
+    ///     principal: Some(Principal::new("u1", "Alice")),
     /// };
     /// let gate = PolicyGate::new(meta)
     ///     .require(Authenticated)
@@ -86,6 +292,54 @@ impl PolicyGate {
         self
     }
 
+    /// Requires capability `X` and records it in the context type.
+    ///
+    /// Equivalent to `.require(Authorized::for_action(X::ACTION))`, except the
+    /// built context is `Ctx<Authorized, (.., X)>` and `X`'s accessor returns
+    /// the capability directly instead of a `Result`. Up to eight capabilities
+    /// can be granted this way; granting the same capability twice makes its
+    /// accessor ambiguous.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::audit::AuditCap;
+    /// use policy_core::{AllowAuthenticated, Authenticated, AuthorizedState, Ctx, LogCap, PolicyGate, Principal, RequestMeta};
+    ///
+    /// fn handler(ctx: &Ctx<AuthorizedState, (LogCap, AuditCap)>) {
+    ///     ctx.log().info(format_args!("no runtime capability check"));
+    /// }
+    ///
+    /// let meta = RequestMeta {
+    ///     request_id: "req-123".to_string(),
+    ///     principal: Some(Principal::new("u1", "Alice")),
+    /// };
+    /// let ctx = PolicyGate::new(meta)
+    ///     .with_authorizer(AllowAuthenticated)
+    ///     .require(Authenticated)
+    ///     .grant::<LogCap>()
+    ///     .grant::<AuditCap>()
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// handler(&ctx);
+    /// ```
+    pub fn grant<X: Capability>(self) -> PolicyGate<<C as Append<X>>::Output>
+    where
+        C: Append<X>,
+    {
+        let gate = self.require(AuthorizedPolicy::for_action(X::ACTION));
+        PolicyGate {
+            meta: gate.meta,
+            requirements: gate.requirements,
+            requirement_set: gate.requirement_set,
+            authorizer: gate.authorizer,
+            http: gate.http,
+            secrets: gate.secrets,
+            _caps: PhantomData,
+        }
+    }
+
     /// Builds a `Ctx<Authorized>` from the gate after validating accumulated policy requirements.
     ///
     /// This method performs the full type-state progression internally:
@@ -102,16 +356,14 @@ impl PolicyGate {
     /// # Examples
     ///
     /// ```
-    /// use policy_core::{PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
+    /// use policy_core::{AllowAuthenticated, PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
     ///
     /// let meta = RequestMeta {
     ///     request_id: "req-123".to_string(),
-    ///     principal: Some(Principal {
-    ///         id: "u1".to_string(),
-    ///         name: "Alice".to_string(),
-    ///     }),
+    ///     principal: Some(Principal::new("u1", "Alice")),
     /// };
     /// let ctx = PolicyGate::new(meta)
+    ///     .with_authorizer(AllowAuthenticated)
     ///     .require(Authenticated)
     ///     .require(Authorized::for_action("log"))
     ///     .build()
@@ -120,40 +372,26 @@ impl PolicyGate {
     /// // ctx is Ctx<Authorized>
     /// assert!(ctx.log_cap().is_some());
     /// ```
-    pub fn build(self) -> Result<Ctx<Authorized>, Violation> {
+    pub fn build(self) -> Result<Ctx<Authorized, C>, Violation> {
         // BREAKING CHANGE WARNING: This validate_all() call MUST happen FIRST, before creating
         // any capabilities. Removing or reordering this creates a CRITICAL SECURITY BYPASS
         // allowing unauthenticated/unauthorized users to obtain capabilities (CWE-306, CWE-863).
         // 1. Validate all policies FIRST
         self.validate_all()?;
 
-        // 2. Grant capabilities based on satisfied requirements
-        let log_cap = if self.requires_authorization(actions::LOG) {
-            Some(LogCap::new())
-        } else {
-            None
-        };
-
-        let http_cap = if self.requires_authorization(actions::HTTP) {
-            Some(HttpCap::new())
-        } else {
-            None
-        };
-
-        let audit_cap = if self.requires_authorization(actions::AUDIT) {
-            Some(AuditCap::new())
-        } else {
-            None
-        };
+        // 2. Grant every action whose Authorized requirement was satisfied.
+        // Capabilities (built-in and user-defined) are minted from this set by Ctx.
+        let granted = self.authorized_actions();
 
         // 3. Build Ctx<Authorized> with the principal from metadata
-        Ok(Ctx::new_authorized(
-            self.meta.request_id,
-            self.meta.principal,
-            log_cap,
-            http_cap,
-            audit_cap,
-        ))
+        Ok(
+            Ctx::new_authorized(self.meta.request_id, self.meta.principal, granted)
+                .with_http_config(self.http)
+                .with_secrets_config(SecretsConfig {
+                    store: self.secrets,
+                    authorizer: self.authorizer,
+                }),
+        )
     }
 
     /// Check that all configured policy requirements are satisfied.
@@ -185,58 +423,266 @@ impl PolicyGate {
                     ));
                 }
             }
-            PolicyReq::Authorized { action: _ } => {
+            PolicyReq::Authorized { action } => {
                 // BREAKING CHANGE WARNING: This check MUST ensure a principal exists before authorizing.
                 // Removing this allows capabilities to be granted to no one (None becomes authorized).
-                if self.meta.principal.is_none() {
+                let Some(principal) = &self.meta.principal else {
                     return Err(Violation::new(
                         ViolationKind::Unauthenticated,
                         "Cannot authorize unauthenticated principal",
                     ));
+                };
+
+                // BREAKING CHANGE WARNING: The authorizer decision MUST be enforced here.
+                // Skipping it grants every capability to every authenticated principal (CWE-863).
+                if !self.authorizer.is_authorized(principal, action) {
+                    return Err(Violation::new(
+                        ViolationKind::Unauthorized { action },
+                        format!("Principal is not authorized for action '{}'", action),
+                    ));
+                }
+            }
+            PolicyReq::HasRole { role } => {
+                let Some(principal) = &self.meta.principal else {
+                    return Err(Violation::new(
+                        ViolationKind::Unauthenticated,
+                        "Cannot check role of unauthenticated principal",
+                    ));
+                };
+                if !principal.has_role(role) {
+                    return Err(Violation::new(
+                        ViolationKind::MissingRole { role },
+                        format!("Principal does not hold role '{}'", role),
+                    ));
+                }
+            }
+            PolicyReq::HasScope { scope } => {
+                let Some(principal) = &self.meta.principal else {
+                    return Err(Violation::new(
+                        ViolationKind::Unauthenticated,
+                        "Cannot check scope of unauthenticated principal",
+                    ));
+                };
+                if !principal.has_scope(scope) {
+                    return Err(Violation::new(
+                        ViolationKind::MissingScope { scope },
+                        format!("Principal was not granted scope '{}'", scope),
+                    ));
                 }
-                // Authorization validation (basic implementation):
-                //
-                // Currently, authorization is simplified: any authenticated principal
-                // is authorized for any action. This model is suitable for:
-                // - Early-stage systems with coarse-grained access control
-                // - Prototypes where all authenticated users have equal privileges
-                // - Internal tools with implicit trust assumptions
-                //
-                // Future enhancement: Role-Based Access Control (RBAC)
-                //
-                // A production authorization system would check whether the principal
-                // has specific permissions for the requested action. This typically involves:
-                // - Checking principal.roles against action requirements
-                // - Querying a policy decision point (PDP) or authorization service
-                // - Evaluating attribute-based policies (ABAC) for fine-grained control
-                //
-                // Example future logic:
-                //   match action {
-                //       "admin" => require_role(&principal, "admin"),
-                //       "log" => require_any_role(&principal, &["user", "admin"]),
-                //       _ => Ok(()),
-                //   }
-                //
-                // The simplified implementation here is intentional and will be enhanced
-                // in future iterations as access control requirements evolve.
             }
         }
         Ok(())
     }
 
-    /// Determines whether any requirement authorizes the specified action.
+    /// Collects the actions named by `PolicyReq::Authorized` requirements.
     ///
-    /// Returns `true` if a `PolicyReq::Authorized` with the given action exists among the gate's requirements, `false` otherwise.
-    fn requires_authorization(&self, action: &str) -> bool {
+    /// Only meaningful after `validate_all()` has succeeded.
+    fn authorized_actions(&self) -> BTreeSet<&'static str> {
         self.requirements
             .iter()
-            .any(|req| matches!(req, PolicyReq::Authorized { action: a } if *a == action))
+            .filter_map(|req| match req {
+                PolicyReq::Authorized { action } => Some(*action),
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authorizer::AllowAuthenticated;
+    use crate::policy::{
+        actions, Authenticated, Authorized as AuthorizedPolicy, HasRole, HasScope,
+    };
+    use crate::request::Principal;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn meta_for(id: &str) -> RequestMeta {
+        RequestMeta {
+            request_id: "req-authz".to_string(),
+            principal: Some(Principal::new(id.to_string(), "Test")),
+        }
+    }
+
+    #[test]
+    fn default_authorizer_denies_every_action() {
+        let violation = PolicyGate::new(meta_for("user-1"))
+            .require(Authenticated)
+            .require(AuthorizedPolicy::for_action(actions::LOG))
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            violation.kind,
+            ViolationKind::Unauthorized {
+                action: actions::LOG
+            }
+        );
+
+        let violation = PolicyGate::new(meta_for("user-1"))
+            .grant::<crate::capability::LogCap>()
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            violation.kind,
+            ViolationKind::Unauthorized {
+                action: actions::LOG
+            }
+        );
+    }
+
+    #[test]
+    fn allow_authenticated_must_be_opted_into() {
+        let ctx = PolicyGate::new(meta_for("user-1"))
+            .with_authorizer(AllowAuthenticated)
+            .require(Authenticated)
+            .require(AuthorizedPolicy::for_action(actions::LOG))
+            .build()
+            .expect("AllowAuthenticated allows");
+
+        assert!(ctx.log_cap().is_some());
+    }
+
+    #[test]
+    fn authorizer_denial_returns_unauthorized() {
+        let result = PolicyGate::new(meta_for("user-1"))
+            .with_authorizer(|_: &Principal, action: &str| action == actions::LOG)
+            .require(Authenticated)
+            .require(AuthorizedPolicy::for_action(actions::LOG))
+            .require(AuthorizedPolicy::for_action(actions::HTTP))
+            .build();
+
+        let violation = result.unwrap_err();
+        assert_eq!(
+            violation.kind,
+            ViolationKind::Unauthorized {
+                action: actions::HTTP
+            }
+        );
+        assert!(violation.message.contains("http"));
+    }
+
+    #[test]
+    fn authorizer_sees_principal_and_action() {
+        let authz = |p: &Principal, action: &str| p.id == "admin-1" && action == actions::AUDIT;
+
+        let admin = PolicyGate::new(meta_for("admin-1"))
+            .with_authorizer(authz)
+            .require(AuthorizedPolicy::for_action(actions::AUDIT))
+            .build();
+        assert!(admin.unwrap().audit_cap().is_some());
+
+        let user = PolicyGate::new(meta_for("user-1"))
+            .with_authorizer(authz)
+            .require(AuthorizedPolicy::for_action(actions::AUDIT))
+            .build();
+        assert!(user.is_err());
+    }
+
+    #[test]
+    fn authorizer_not_consulted_without_principal() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let meta = RequestMeta {
+            request_id: "req-anon".to_string(),
+            principal: None,
+        };
+
+        let result = PolicyGate::new(meta)
+            .with_authorizer(move |_: &Principal, _: &str| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                true
+            })
+            .require(AuthorizedPolicy::for_action(actions::LOG))
+            .build();
+
+        assert_eq!(result.unwrap_err().kind, ViolationKind::Unauthenticated);
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn has_role_requirement_checks_principal_roles() {
+        let mut meta = meta_for("user-1");
+        meta.principal = meta.principal.map(|p| p.with_role("admin"));
+
+        let ctx = PolicyGate::new(meta.clone())
+            .require(HasRole::named("admin"))
+            .build();
+        assert!(ctx.is_ok());
+
+        let result = PolicyGate::new(meta)
+            .require(HasRole::named("auditor"))
+            .build();
+        assert_eq!(
+            result.unwrap_err().kind,
+            ViolationKind::MissingRole { role: "auditor" }
+        );
+    }
+
+    #[test]
+    fn has_scope_requirement_checks_principal_scopes() {
+        let mut meta = meta_for("user-1");
+        meta.principal = meta.principal.map(|p| p.with_scope("items:read"));
+
+        let ctx = PolicyGate::new(meta.clone())
+            .require(HasScope::named("items:read"))
+            .build();
+        assert!(ctx.is_ok());
+
+        let result = PolicyGate::new(meta)
+            .require(HasScope::named("items:write"))
+            .build();
+        assert_eq!(
+            result.unwrap_err().kind,
+            ViolationKind::MissingScope {
+                scope: "items:write"
+            }
+        );
+    }
+
+    #[test]
+    fn role_and_scope_requirements_need_a_principal() {
+        let meta = RequestMeta {
+            request_id: "req-anon".to_string(),
+            principal: None,
+        };
+
+        let role = PolicyGate::new(meta.clone())
+            .require(HasRole::named("admin"))
+            .build();
+        assert_eq!(role.unwrap_err().kind, ViolationKind::Unauthenticated);
+
+        let scope = PolicyGate::new(meta)
+            .require(HasScope::named("items:read"))
+            .build();
+        assert_eq!(scope.unwrap_err().kind, ViolationKind::Unauthenticated);
+    }
+
+    #[test]
+    fn authorizer_consulted_once_per_deduplicated_action() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+
+        PolicyGate::new(meta_for("user-1"))
+            .with_authorizer(move |_: &Principal, _: &str| {
+                counter.fetch_add(1, Ordering::SeqCst);
+                true
+            })
+            .require(AuthorizedPolicy::for_action(actions::LOG))
+            .require(AuthorizedPolicy::for_action(actions::LOG))
+            .require(AuthorizedPolicy::for_action(actions::HTTP))
+            .build()
+            .unwrap();
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
     }
 }
 
 #[cfg(test)]
 mod proptests {
     use super::*;
+    use crate::policy::actions;
     use crate::request::Principal;
     use proptest::prelude::*;
 
@@ -258,7 +704,7 @@ mod proptests {
             prop::string::string_regex("[a-z0-9-]{3,10}").unwrap(),
             prop::string::string_regex("[A-Za-z ]{3,15}").unwrap(),
         )
-            .prop_map(|(id, name)| Principal { id, name })
+            .prop_map(|(id, name)| Principal::new(id, name))
     }
 
     // Strategy: Generate arbitrary action names
@@ -322,6 +768,7 @@ mod proptests {
             };
 
             let ctx = PolicyGate::new(meta)
+                .with_authorizer(crate::authorizer::AllowAuthenticated)
                 .require(crate::policy::Authenticated)
                 .require(crate::policy::Authorized::for_action(action))
                 .build()
@@ -355,12 +802,14 @@ mod proptests {
             }
 
             // Build context twice with same requirements
-            let mut gate1 = PolicyGate::new(meta.clone());
+            let mut gate1 = PolicyGate::new(meta.clone())
+                .with_authorizer(crate::authorizer::AllowAuthenticated);
             for req in &requirements {
                 gate1 = gate1.require(req.clone());
             }
 
-            let mut gate2 = PolicyGate::new(meta);
+            let mut gate2 = PolicyGate::new(meta)
+                .with_authorizer(crate::authorizer::AllowAuthenticated);
             for req in &requirements {
                 gate2 = gate2.require(req.clone());
             }
@@ -399,7 +848,8 @@ mod proptests {
             }
 
             // Build with original order
-            let mut gate1 = PolicyGate::new(meta.clone());
+            let mut gate1 = PolicyGate::new(meta.clone())
+                .with_authorizer(crate::authorizer::AllowAuthenticated);
             for req in &requirements {
                 gate1 = gate1.require(req.clone());
             }
@@ -407,7 +857,8 @@ mod proptests {
 
             // Build with reversed order
             requirements.reverse();
-            let mut gate2 = PolicyGate::new(meta);
+            let mut gate2 = PolicyGate::new(meta)
+                .with_authorizer(crate::authorizer::AllowAuthenticated);
             for req in &requirements {
                 gate2 = gate2.require(req.clone());
             }
@@ -1,8 +1,77 @@
-use std::cell::RefCell;
+use std::borrow::Cow;
 use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
+use crate::audit::{emit_event, AuditEvent, AuditEventKind, AuditOutcome};
+use crate::capability::{Capability, HttpCap};
+use crate::contexts::{HttpBody, HttpTarget};
+use crate::error::{Error, Violation, ViolationKind};
 use crate::Verified;
 
+#[cfg(feature = "http-client")]
+mod blocking;
+mod egress;
+#[cfg(feature = "http-client-async")]
+mod nonblocking;
+#[cfg(any(feature = "http-client", feature = "http-client-async"))]
+mod resolve;
+mod transport;
+pub(crate) mod url;
+
+#[cfg(feature = "http-client")]
+pub use blocking::BlockingTransport;
+pub use egress::{EgressDenial, EgressPolicy};
+#[cfg(feature = "http-client-async")]
+pub use nonblocking::AsyncTransport;
+pub use transport::{
+    AsyncHttpTransport, HttpError, HttpErrorKind, HttpResponse, HttpTransport, OutboundRequest,
+    RecordingTransport, TransportFuture, REQUEST_ID_HEADER,
+};
+pub use url::Url;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// A verified URL that [`PolicyHttp`] can send requests to.
+///
+/// Implemented only for `Verified<Url, HttpTarget>`, the output of
+/// [`UrlSanitizer`](crate::UrlSanitizer), so every URL `PolicyHttp` requests
+/// has been parsed and normalized. Verified strings are not accepted, whatever
+/// their context, including the [`LogLine`](crate::contexts::LogLine) output
+/// of [`StringSanitizer`](crate::StringSanitizer):
+///
+/// ```compile_fail
+/// use policy_core::{HttpUrl, Sanitizer, StringSanitizer, Tainted};
+///
+/// fn request(_url: &impl HttpUrl) {}
+///
+/// let url = StringSanitizer::new(256)
+///     .unwrap()
+///     .sanitize(Tainted::new("https://example.com/".to_string()))
+///     .unwrap();
+/// request(&url); // Error: `Verified<String, LogLine>` is not an `HttpUrl`
+/// ```
+///
+/// This trait is sealed and cannot be implemented outside this crate.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not a verified HTTP target",
+    note = "sanitize request URLs with `UrlSanitizer`, which verifies them for `HttpTarget`"
+)]
+pub trait HttpUrl: sealed::Sealed {
+    /// Returns the URL to request.
+    #[doc(hidden)]
+    fn request_url(&self) -> &str;
+}
+
+impl sealed::Sealed for Verified<Url, HttpTarget> {}
+
+impl HttpUrl for Verified<Url, HttpTarget> {
+    fn request_url(&self) -> &str {
+        self.as_ref().as_str()
+    }
+}
+
 /// HTTP method for a request.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HttpMethod {
@@ -48,65 +117,127 @@ pub struct HttpRequest {
     pub body_len: usize,
 }
 
+impl HttpRequest {
+    /// Captures the metadata of an outbound request, dropping its body.
+    pub(crate) fn from_outbound(request: &OutboundRequest<'_>) -> Self {
+        Self {
+            request_id: request.request_id().to_string(),
+            method: request.method(),
+            url: request.url().to_string(),
+            body_len: request.body().len(),
+        }
+    }
+}
+
+/// HTTP settings configured on `PolicyGate` and shared by every `PolicyHttp`
+/// handed out by the resulting context.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct HttpConfig {
+    /// Transport for outbound requests; `None` records requests without sending them
+    pub(crate) transport: Option<Arc<dyn HttpTransport>>,
+    /// Transport for the `*_async` methods; `None` records requests without sending them
+    pub(crate) async_transport: Option<Arc<dyn AsyncHttpTransport>>,
+    /// Destinations requests may reach; `None` allows any verified URL
+    pub(crate) egress: Option<Arc<EgressPolicy>>,
+}
+
 /// A capability-gated HTTP client interface.
 ///
 /// `PolicyHttp` is obtained from `Ctx::http()` and requires `HttpCap`.
 /// It is lifetime-bound to the context to prevent misuse.
 ///
-/// This implementation records HTTP request metadata for testing without
-/// performing actual network I/O, making tests deterministic and offline.
+/// Requests are handed to the [`HttpTransport`] configured with
+/// [`PolicyGate::with_http_transport`](crate::PolicyGate::with_http_transport).
+/// Without a transport, requests are only recorded and answered with an empty
+/// `200`, which keeps tests deterministic and offline. Either way, every
+/// request's metadata is recorded on this `PolicyHttp`.
+///
+/// The `*_async` methods ([`get_async`](Self::get_async) and friends) apply
+/// the same checks and hand requests to the [`AsyncHttpTransport`]
+/// configured with
+/// [`PolicyGate::with_async_http_transport`](crate::PolicyGate::with_async_http_transport),
+/// recording them in the same way when none is configured.
+///
+/// If an [`EgressPolicy`] was configured with
+/// [`PolicyGate::with_egress_policy`](crate::PolicyGate::with_egress_policy),
+/// requests to destinations outside it fail with
+/// [`ViolationKind::EgressDenied`] and are reported as an
+/// [`AuditEventKind::SecurityEvent`] on the `policy_audit` tracing target.
+///
+/// `PolicyHttp` is `Send + Sync`, so it can be shared with worker threads or
+/// moved into a blocking task from async code.
 ///
 /// # Security Properties
 ///
 /// - Requires `HttpCap` to construct (capability-gated)
-/// - Accepts only `Verified<String>` for URLs and request bodies
+/// - Accepts only `Verified<Url, HttpTarget>` URLs ([`HttpUrl`]) and `Verified<String, HttpBody>`
+///   bodies ([`HttpBodySanitizer`](crate::HttpBodySanitizer))
 /// - Rejects tainted data at compile time
+/// - Enforces the egress policy before anything is sent
+/// - Propagates the request ID to the transport
+/// - Returns response bodies only as `Tainted` data
 /// - Does not leak full request bodies in recorded metadata
 ///
 /// # Examples
 ///
 /// ```no_run
-/// # use policy_core::{PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
-/// # use policy_core::{Tainted, Sanitizer, StringSanitizer};
+/// # use policy_core::{AllowAuthenticated, PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
+/// # use policy_core::{HttpBodySanitizer, Tainted, Sanitizer, UrlSanitizer};
 /// # let meta = RequestMeta {
 /// #     request_id: "req-1".to_string(),
-/// #     principal: Some(Principal { id: "u1".to_string(), name: "Alice".to_string() }),
+/// #     principal: Some(Principal::new("u1", "Alice")),
 /// # };
 /// # let ctx = PolicyGate::new(meta)
+/// #     .with_authorizer(AllowAuthenticated)
 /// #     .require(Authenticated)
 /// #     .require(Authorized::for_action("http"))
 /// #     .build()
 /// #     .unwrap();
 /// let http = ctx.http().expect("HttpCap required");
 ///
-/// // URLs and bodies must be verified
-/// let sanitizer = StringSanitizer::new(256).unwrap();
+/// // URLs must be verified for the `HttpTarget` context, bodies for `HttpBody`
 /// let url = Tainted::new("https://api.example.com/users".to_string());
-/// let verified_url = sanitizer.sanitize(url).unwrap();
+/// let verified_url = UrlSanitizer::new().sanitize(url).unwrap();
 ///
 /// let body = Tainted::new(r#"{"name": "Alice"}"#.to_string());
-/// let verified_body = sanitizer.sanitize(body).unwrap();
+/// let verified_body = HttpBodySanitizer::new(4096).unwrap().sanitize(body).unwrap();
 ///
-/// http.post(&verified_url, &verified_body);
+/// let response = http.post(&verified_url, &verified_body).expect("request sent");
+/// assert!(response.is_success());
 /// ```
 #[derive(Debug)]
 pub struct PolicyHttp<'a> {
     // Lifetime ensures this can't outlive the Ctx
     _ctx_lifetime: std::marker::PhantomData<&'a ()>,
     request_id: &'a str,
+    principal: Option<&'a str>,
+    // None means record-only
+    transport: Option<&'a dyn HttpTransport>,
+    // None means record-only for the *_async methods
+    async_transport: Option<&'a dyn AsyncHttpTransport>,
+    // None means any verified URL
+    egress: Option<&'a EgressPolicy>,
     // Recorded requests for testing/verification
-    requests: RefCell<Vec<HttpRequest>>,
+    requests: Mutex<Vec<HttpRequest>>,
 }
 
 impl<'a> PolicyHttp<'a> {
-    /// Creates a new PolicyHttp with a request ID.
+    /// Creates a new PolicyHttp for a request using the gate's configuration.
     ///
     /// This is `pub(crate)` - only `Ctx` can create it.
-    pub(crate) fn new(request_id: &'a str) -> Self {
+    pub(crate) fn new(
+        request_id: &'a str,
+        principal: Option<&'a str>,
+        config: &'a HttpConfig,
+    ) -> Self {
         Self {
             _ctx_lifetime: std::marker::PhantomData,
             request_id,
-            requests: RefCell::new(Vec::new()),
+            principal,
+            transport: config.transport.as_deref(),
+            async_transport: config.async_transport.as_deref(),
+            egress: config.egress.as_deref(),
+            requests: Mutex::new(Vec::new()),
         }
     }
 
@@ -115,90 +246,299 @@ impl<'a> PolicyHttp<'a> {
         self.request_id
     }
 
-    /// Records an HTTP GET request.
+    /// Sends an HTTP GET request.
     ///
     /// # Arguments
     ///
     /// * `url` - The verified target URL
     ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Violation`] with [`ViolationKind::EgressDenied`] if the
+    /// URL is outside the egress policy, or [`Error::Http`] if the transport
+    /// could not complete the request.
+    ///
     /// # Examples
     ///
     /// ```ignore
-    /// # use policy_core::{PolicyHttp, Verified};
-    /// # let http = PolicyHttp::new();
-    /// # let verified_url = Verified::new_unchecked("https://api.example.com".to_string());
-    /// http.get(&verified_url);
+    /// # use policy_core::{PolicyHttp, Sanitizer, Tainted, UrlSanitizer, Verified};
+    /// # let http = PolicyHttp::new("req-1", None);
+    /// # let verified_url = UrlSanitizer::new().sanitize(Tainted::new("https://api.example.com".to_string()))?;
+    /// let response = http.get(&verified_url)?;
     /// ```
-    pub fn get(&self, url: &Verified<String>) {
-        self.record_request(
-            HttpMethod::Get,
-            url,
-            &Verified::new_unchecked(String::new()),
-        );
+    pub fn get(&self, url: &impl HttpUrl) -> Result<HttpResponse, Error> {
+        self.send(HttpMethod::Get, url, "")
     }
 
-    /// Records an HTTP POST request with a body.
+    /// Sends an HTTP POST request with a body.
     ///
     /// # Arguments
     ///
     /// * `url` - The verified target URL
-    /// * `body` - The verified request body
+    /// * `body` - The request body, verified for the [`HttpBody`] context and sent as-is
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Violation`] with [`ViolationKind::EgressDenied`] if the
+    /// URL is outside the egress policy, or [`Error::Http`] if the transport
+    /// could not complete the request.
     ///
     /// # Examples
     ///
     /// ```ignore
-    /// # use policy_core::{PolicyHttp, Verified};
-    /// # let http = PolicyHttp::new();
-    /// # let url = Verified::new_unchecked("https://api.example.com".to_string());
-    /// # let body = Verified::new_unchecked(r#"{"key": "value"}"#.to_string());
-    /// http.post(&url, &body);
+    /// # use policy_core::{PolicyHttp, Sanitizer, Tainted, UrlSanitizer, Verified};
+    /// # let http = PolicyHttp::new("req-1", None);
+    /// # let url = UrlSanitizer::new().sanitize(Tainted::new("https://api.example.com".to_string()))?;
+    /// # let body: Verified<String, HttpBody> = Verified::new_unchecked(r#"{"key": "value"}"#.to_string());
+    /// let response = http.post(&url, &body)?;
     /// ```
-    pub fn post(&self, url: &Verified<String>, body: &Verified<String>) {
-        self.record_request(HttpMethod::Post, url, body);
+    pub fn post(
+        &self,
+        url: &impl HttpUrl,
+        body: &Verified<String, HttpBody>,
+    ) -> Result<HttpResponse, Error> {
+        self.send(HttpMethod::Post, url, body.as_ref())
     }
 
-    /// Records an HTTP PUT request with a body.
+    /// Sends an HTTP PUT request with a body.
     ///
     /// # Arguments
     ///
     /// * `url` - The verified target URL
-    /// * `body` - The verified request body
-    pub fn put(&self, url: &Verified<String>, body: &Verified<String>) {
-        self.record_request(HttpMethod::Put, url, body);
+    /// * `body` - The request body, verified for the [`HttpBody`] context and sent as-is
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Violation`] with [`ViolationKind::EgressDenied`] if the
+    /// URL is outside the egress policy, or [`Error::Http`] if the transport
+    /// could not complete the request.
+    pub fn put(
+        &self,
+        url: &impl HttpUrl,
+        body: &Verified<String, HttpBody>,
+    ) -> Result<HttpResponse, Error> {
+        self.send(HttpMethod::Put, url, body.as_ref())
     }
 
-    /// Records an HTTP DELETE request.
+    /// Sends an HTTP DELETE request.
     ///
     /// # Arguments
     ///
     /// * `url` - The verified target URL
-    pub fn delete(&self, url: &Verified<String>) {
-        self.record_request(
-            HttpMethod::Delete,
-            url,
-            &Verified::new_unchecked(String::new()),
-        );
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Violation`] with [`ViolationKind::EgressDenied`] if the
+    /// URL is outside the egress policy, or [`Error::Http`] if the transport
+    /// could not complete the request.
+    pub fn delete(&self, url: &impl HttpUrl) -> Result<HttpResponse, Error> {
+        self.send(HttpMethod::Delete, url, "")
     }
 
-    /// Records an HTTP PATCH request with a body.
+    /// Sends an HTTP PATCH request with a body.
     ///
     /// # Arguments
     ///
     /// * `url` - The verified target URL
-    /// * `body` - The verified request body
-    pub fn patch(&self, url: &Verified<String>, body: &Verified<String>) {
-        self.record_request(HttpMethod::Patch, url, body);
+    /// * `body` - The request body, verified for the [`HttpBody`] context and sent as-is
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Violation`] with [`ViolationKind::EgressDenied`] if the
+    /// URL is outside the egress policy, or [`Error::Http`] if the transport
+    /// could not complete the request.
+    pub fn patch(
+        &self,
+        url: &impl HttpUrl,
+        body: &Verified<String, HttpBody>,
+    ) -> Result<HttpResponse, Error> {
+        self.send(HttpMethod::Patch, url, body.as_ref())
+    }
+
+    /// Sends an HTTP GET request through the async transport.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`get`](Self::get).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use policy_core::{contexts::HttpTarget, Error, PolicyHttp, Url, Verified};
+    /// # async fn example(
+    /// #     http: &PolicyHttp<'_>,
+    /// #     verified_url: &Verified<Url, HttpTarget>,
+    /// # ) -> Result<(), Error> {
+    /// let response = http.get_async(verified_url).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_async(&self, url: &impl HttpUrl) -> Result<HttpResponse, Error> {
+        self.send_async(HttpMethod::Get, url.request_url(), "")
+            .await
+    }
+
+    /// Sends an HTTP POST request with a body through the async transport.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`post`](Self::post).
+    pub async fn post_async(
+        &self,
+        url: &impl HttpUrl,
+        body: &Verified<String, HttpBody>,
+    ) -> Result<HttpResponse, Error> {
+        self.send_async(HttpMethod::Post, url.request_url(), body.as_ref())
+            .await
     }
 
-    /// Internal method to record a request.
-    fn record_request(&self, method: HttpMethod, url: &Verified<String>, body: &Verified<String>) {
-        let request = HttpRequest {
+    /// Sends an HTTP PUT request with a body through the async transport.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`put`](Self::put).
+    pub async fn put_async(
+        &self,
+        url: &impl HttpUrl,
+        body: &Verified<String, HttpBody>,
+    ) -> Result<HttpResponse, Error> {
+        self.send_async(HttpMethod::Put, url.request_url(), body.as_ref())
+            .await
+    }
+
+    /// Sends an HTTP DELETE request through the async transport.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`delete`](Self::delete).
+    pub async fn delete_async(&self, url: &impl HttpUrl) -> Result<HttpResponse, Error> {
+        self.send_async(HttpMethod::Delete, url.request_url(), "")
+            .await
+    }
+
+    /// Sends an HTTP PATCH request with a body through the async transport.
+    ///
+    /// # Errors
+    ///
+    /// Same as [`patch`](Self::patch).
+    pub async fn patch_async(
+        &self,
+        url: &impl HttpUrl,
+        body: &Verified<String, HttpBody>,
+    ) -> Result<HttpResponse, Error> {
+        self.send_async(HttpMethod::Patch, url.request_url(), body.as_ref())
+            .await
+    }
+
+    /// Internal method to check, record and hand a request to the transport.
+    fn send(
+        &self,
+        method: HttpMethod,
+        url: &impl HttpUrl,
+        body: &str,
+    ) -> Result<HttpResponse, Error> {
+        let url = url.request_url();
+        let request_id = self.admit(method, url, body)?;
+        let request = OutboundRequest::new(&request_id, method, url, body);
+
+        match self.transport {
+            Some(transport) => match transport.send(&request) {
+                Ok(response) => Ok(response.with_source(url)),
+                Err(error) => Err(self.transport_error(method, url, error)),
+            },
+            None => Ok(HttpResponse::new(200, Vec::new())),
+        }
+    }
+
+    /// Async counterpart of [`send`](Self::send), using the async transport.
+    async fn send_async(
+        &self,
+        method: HttpMethod,
+        url: &str,
+        body: &str,
+    ) -> Result<HttpResponse, Error> {
+        let request_id = self.admit(method, url, body)?;
+        let request = OutboundRequest::new(&request_id, method, url, body);
+
+        match self.async_transport {
+            Some(transport) => match transport.send_async(&request).await {
+                Ok(response) => Ok(response.with_source(url)),
+                Err(error) => Err(self.transport_error(method, url, error)),
+            },
+            None => Ok(HttpResponse::new(200, Vec::new())),
+        }
+    }
+
+    /// Checks the egress policy and records the request, returning the
+    /// header-safe request ID to send with it.
+    fn admit(&self, method: HttpMethod, url: &str, body: &str) -> Result<Cow<'a, str>, Violation> {
+        // BREAKING CHANGE WARNING: The egress check MUST run before the request is
+        // recorded or handed to the transport. Skipping it re-opens SSRF (CWE-918).
+        if let Some(policy) = self.egress {
+            self.check_egress(policy, method, url)?;
+        }
+
+        self.lock().push(HttpRequest {
             request_id: self.request_id.to_string(),
             method,
-            url: url.as_ref().clone(),
-            body_len: body.as_ref().len(),
+            url: url.to_string(),
+            body_len: body.len(),
+        });
+
+        Ok(header_safe(self.request_id))
+    }
+
+    /// Rejects URLs outside the egress policy and audits the attempt.
+    fn check_egress(
+        &self,
+        policy: &EgressPolicy,
+        method: HttpMethod,
+        url: &str,
+    ) -> Result<(), Violation> {
+        let result = match url::ParsedUrl::parse(url) {
+            Some(parsed) => policy.check_parsed(&parsed),
+            None => Err(EgressDenial::MalformedUrl),
         };
-        self.requests.borrow_mut().push(request);
+        match result {
+            Ok(()) => Ok(()),
+            Err(reason) => Err(self.deny(method, url, reason)),
+        }
+    }
+
+    /// Reports a transport refusing a private address as an egress denial,
+    /// since it is one found after resolving the host.
+    fn transport_error(&self, method: HttpMethod, url: &str, error: HttpError) -> Error {
+        match error.kind() {
+            HttpErrorKind::PrivateAddress => {
+                self.deny(method, url, EgressDenial::PrivateAddress).into()
+            }
+            _ => error.into(),
+        }
+    }
+
+    /// Audits a denied request and returns the violation for it.
+    fn deny(&self, method: HttpMethod, url: &str, reason: EgressDenial) -> Violation {
+        // The URL is redacted: it may carry credentials or tokens in the query
+        let event = AuditEvent::new(
+            self.request_id,
+            self.principal,
+            AuditEventKind::SecurityEvent,
+            AuditOutcome::Denied,
+        )
+        .with_action(HttpCap::ACTION)
+        .with_method(method.to_string())
+        .with_redacted_url(url);
+        emit_event(&event);
+
+        Violation::new(
+            ViolationKind::EgressDenied { reason },
+            format!("Outbound {} request denied: {}", method, reason),
+        )
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<HttpRequest>> {
+        // Recorded metadata stays consistent even if a holder panicked.
+        self.requests.lock().unwrap_or_else(PoisonError::into_inner)
     }
 
     /// Returns the number of recorded requests.
@@ -208,16 +548,16 @@ impl<'a> PolicyHttp<'a> {
     /// # Examples
     ///
     /// ```ignore
-    /// # use policy_core::{PolicyHttp, Verified};
-    /// # let http = PolicyHttp::new();
+    /// # use policy_core::{PolicyHttp, Sanitizer, Tainted, UrlSanitizer, Verified};
+    /// # let http = PolicyHttp::new("req-1", None);
     /// assert_eq!(http.request_count(), 0);
     ///
-    /// # let url = Verified::new_unchecked("https://example.com".to_string());
-    /// http.get(&url);
+    /// # let url = UrlSanitizer::new().sanitize(Tainted::new("https://example.com".to_string()))?;
+    /// http.get(&url)?;
     /// assert_eq!(http.request_count(), 1);
     /// ```
     pub fn request_count(&self) -> usize {
-        self.requests.borrow().len()
+        self.lock().len()
     }
 
     /// Provides borrowed access to request history via callback (zero-copy).
@@ -234,10 +574,10 @@ impl<'a> PolicyHttp<'a> {
     /// # Examples
     ///
     /// ```ignore
-    /// # use policy_core::{PolicyHttp, Verified};
-    /// # let http = PolicyHttp::new();
-    /// # let url = Verified::new_unchecked("https://example.com".to_string());
-    /// http.get(&url);
+    /// # use policy_core::{PolicyHttp, Sanitizer, Tainted, UrlSanitizer, Verified};
+    /// # let http = PolicyHttp::new("req-1", None);
+    /// # let url = UrlSanitizer::new().sanitize(Tainted::new("https://example.com".to_string()))?;
+    /// http.get(&url)?;
     ///
     /// // Zero-copy access via callback
     /// http.with_requests(|requests| {
@@ -251,14 +591,14 @@ impl<'a> PolicyHttp<'a> {
     where
         F: FnOnce(&[HttpRequest]) -> R,
     {
-        f(&self.requests.borrow())
+        f(&self.lock())
     }
 
     /// Returns an iterator over request history (lazy cloning).
     ///
     /// # Performance Note
     ///
-    /// Due to `RefCell` interior mutability, this method clones the vector
+    /// Because the history sits behind a `Mutex`, this method clones the vector
     /// before returning an iterator. However, iteration happens lazily, so if
     /// you only need a few requests, this can be more efficient than processing
     /// the entire cloned vector.
@@ -268,10 +608,10 @@ impl<'a> PolicyHttp<'a> {
     /// # Examples
     ///
     /// ```ignore
-    /// # use policy_core::{PolicyHttp, Verified};
-    /// # let http = PolicyHttp::new();
-    /// # let url = Verified::new_unchecked("https://example.com".to_string());
-    /// http.get(&url);
+    /// # use policy_core::{PolicyHttp, Sanitizer, Tainted, UrlSanitizer, Verified};
+    /// # let http = PolicyHttp::new("req-1", None);
+    /// # let url = UrlSanitizer::new().sanitize(Tainted::new("https://example.com".to_string()))?;
+    /// http.get(&url)?;
     ///
     /// // Iterator-based access
     /// for request in http.iter_requests() {
@@ -279,7 +619,7 @@ impl<'a> PolicyHttp<'a> {
     /// }
     /// ```
     pub fn iter_requests(&self) -> impl Iterator<Item = HttpRequest> {
-        self.requests.borrow().clone().into_iter()
+        self.lock().clone().into_iter()
     }
 
     /// Returns a snapshot of all recorded requests.
@@ -296,11 +636,11 @@ impl<'a> PolicyHttp<'a> {
     /// # Examples
     ///
     /// ```ignore
-    /// # use policy_core::{PolicyHttp, Verified, HttpMethod};
-    /// # let http = PolicyHttp::new();
-    /// # let url = Verified::new_unchecked("https://example.com".to_string());
-    /// # let body = Verified::new_unchecked("data".to_string());
-    /// http.post(&url, &body);
+    /// # use policy_core::{PolicyHttp, Sanitizer, Tainted, UrlSanitizer, Verified, HttpMethod};
+    /// # let http = PolicyHttp::new("req-1", None);
+    /// # let url = UrlSanitizer::new().sanitize(Tainted::new("https://example.com".to_string()))?;
+    /// # let body: Verified<String, HttpBody> = Verified::new_unchecked("data".to_string());
+    /// http.post(&url, &body)?;
     ///
     /// let requests = http.requests();
     /// assert_eq!(requests.len(), 1);
@@ -313,7 +653,24 @@ impl<'a> PolicyHttp<'a> {
         note = "Use `iter_requests()` or `with_requests()` for better performance"
     )]
     pub fn requests(&self) -> Vec<HttpRequest> {
-        self.requests.borrow().clone()
+        self.lock().clone()
+    }
+}
+
+/// Replaces control characters so the request ID is a valid header value.
+///
+/// BREAKING CHANGE WARNING: The request ID comes from the incoming request.
+/// Forwarding CR/LF unchanged would allow header injection (CWE-113).
+fn header_safe(request_id: &str) -> Cow<'_, str> {
+    if request_id.chars().any(char::is_control) {
+        Cow::Owned(
+            request_id
+                .chars()
+                .map(|c| if c.is_control() { ' ' } else { c })
+                .collect(),
+        )
+    } else {
+        Cow::Borrowed(request_id)
     }
 }
 
@@ -321,7 +678,35 @@ impl<'a> PolicyHttp<'a> {
 #[allow(deprecated)]
 mod tests {
     use super::*;
-    use crate::{Sanitizer, StringSanitizer, Tainted};
+    use crate::{HttpBodySanitizer, Sanitizer, Tainted, UrlSanitizer};
+
+    /// Parses a URL the way `UrlSanitizer` does, without its scheme checks.
+    fn target(input: &str) -> Verified<Url, HttpTarget> {
+        Verified::new_unchecked(Url::parse_normalized(input).unwrap())
+    }
+
+    static UNCONFIGURED: HttpConfig = HttpConfig {
+        transport: None,
+        async_transport: None,
+        egress: None,
+    };
+
+    fn with_transport(transport: &Arc<RecordingTransport>) -> HttpConfig {
+        HttpConfig {
+            transport: Some(Arc::clone(transport) as Arc<dyn HttpTransport>),
+            async_transport: None,
+            egress: None,
+        }
+    }
+
+    /// Polls a future that never waits, as every `RecordingTransport` send is.
+    fn poll_ready<F: std::future::Future>(future: F) -> F::Output {
+        let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+        match std::pin::pin!(future).poll(&mut cx) {
+            std::task::Poll::Ready(output) => output,
+            std::task::Poll::Pending => panic!("future should complete immediately"),
+        }
+    }
 
     #[test]
     fn http_method_display() {
@@ -334,10 +719,10 @@ mod tests {
 
     #[test]
     fn policy_http_records_get_request() {
-        let http = PolicyHttp::new("req-test-1");
-        let url = Verified::new_unchecked("https://api.example.com/users".to_string());
+        let http = PolicyHttp::new("req-test-1", None, &UNCONFIGURED);
+        let url = target("https://api.example.com/users");
 
-        http.get(&url);
+        http.get(&url).unwrap();
 
         assert_eq!(http.request_count(), 1);
         let requests = http.requests();
@@ -347,13 +732,33 @@ mod tests {
         assert_eq!(requests[0].body_len, 0);
     }
 
+    #[test]
+    fn policy_http_accepts_verified_url() {
+        use crate::Sanitizer;
+
+        let http = PolicyHttp::new("req-test-13", None, &UNCONFIGURED);
+        let url = crate::UrlSanitizer::new()
+            .sanitize(crate::Tainted::new(
+                "HTTPS://API.example.com/users/../items?page=2".to_string(),
+            ))
+            .unwrap();
+
+        http.get(&url).unwrap();
+
+        assert_eq!(
+            http.requests()[0].url,
+            "https://api.example.com/items?page=2"
+        );
+    }
+
     #[test]
     fn policy_http_records_post_request() {
-        let http = PolicyHttp::new("req-test-2");
-        let url = Verified::new_unchecked("https://api.example.com/users".to_string());
-        let body = Verified::new_unchecked(r#"{"name": "Alice"}"#.to_string());
+        let http = PolicyHttp::new("req-test-2", None, &UNCONFIGURED);
+        let url = target("https://api.example.com/users");
+        let body: Verified<String, HttpBody> =
+            Verified::new_unchecked(r#"{"name": "Alice"}"#.to_string());
 
-        http.post(&url, &body);
+        http.post(&url, &body).unwrap();
 
         assert_eq!(http.request_count(), 1);
         let requests = http.requests();
@@ -365,14 +770,14 @@ mod tests {
 
     #[test]
     fn policy_http_records_multiple_requests() {
-        let http = PolicyHttp::new("req-test-3");
-        let url1 = Verified::new_unchecked("https://example.com/1".to_string());
-        let url2 = Verified::new_unchecked("https://example.com/2".to_string());
-        let body = Verified::new_unchecked("data".to_string());
+        let http = PolicyHttp::new("req-test-3", None, &UNCONFIGURED);
+        let url1 = target("https://example.com/1");
+        let url2 = target("https://example.com/2");
+        let body: Verified<String, HttpBody> = Verified::new_unchecked("data".to_string());
 
-        http.get(&url1);
-        http.post(&url2, &body);
-        http.delete(&url1);
+        http.get(&url1).unwrap();
+        http.post(&url2, &body).unwrap();
+        http.delete(&url1).unwrap();
 
         assert_eq!(http.request_count(), 3);
         let requests = http.requests();
@@ -384,11 +789,11 @@ mod tests {
 
     #[test]
     fn policy_http_enforces_verified_urls() {
-        let http = PolicyHttp::new("req-test-4");
+        let http = PolicyHttp::new("req-test-4", None, &UNCONFIGURED);
 
         // This works - verified URL:
-        let verified_url = Verified::new_unchecked("https://example.com".to_string());
-        http.get(&verified_url);
+        let verified_url = target("https://example.com");
+        http.get(&verified_url).unwrap();
 
         // These would NOT compile if uncommented (good!):
         // let raw_url = "https://example.com".to_string();
@@ -400,18 +805,20 @@ mod tests {
 
     #[test]
     fn policy_http_with_sanitizer_integration() {
-        let http = PolicyHttp::new("req-test-5");
-        let sanitizer = StringSanitizer::new(256).unwrap();
+        let http = PolicyHttp::new("req-test-5", None, &UNCONFIGURED);
+        let sanitizer = HttpBodySanitizer::new(256).unwrap();
 
         // Sanitize tainted URL
         let tainted_url = Tainted::new("  https://api.example.com/data  ".to_string());
-        let verified_url = sanitizer.sanitize(tainted_url).expect("valid URL");
+        let verified_url = UrlSanitizer::new()
+            .sanitize(tainted_url)
+            .expect("valid URL");
 
         // Sanitize tainted body
         let tainted_body = Tainted::new(r#"{"key": "value"}"#.to_string());
         let verified_body = sanitizer.sanitize(tainted_body).expect("valid body");
 
-        http.post(&verified_url, &verified_body);
+        http.post(&verified_url, &verified_body).unwrap();
 
         let requests = http.requests();
         assert_eq!(requests.len(), 1);
@@ -422,11 +829,12 @@ mod tests {
 
     #[test]
     fn policy_http_records_put_request() {
-        let http = PolicyHttp::new("req-test-6");
-        let url = Verified::new_unchecked("https://api.example.com/users/1".to_string());
-        let body = Verified::new_unchecked(r#"{"name": "Bob"}"#.to_string());
+        let http = PolicyHttp::new("req-test-6", None, &UNCONFIGURED);
+        let url = target("https://api.example.com/users/1");
+        let body: Verified<String, HttpBody> =
+            Verified::new_unchecked(r#"{"name": "Bob"}"#.to_string());
 
-        http.put(&url, &body);
+        http.put(&url, &body).unwrap();
 
         let requests = http.requests();
         assert_eq!(requests[0].request_id, "req-test-6");
@@ -436,11 +844,12 @@ mod tests {
 
     #[test]
     fn policy_http_records_patch_request() {
-        let http = PolicyHttp::new("req-test-7");
-        let url = Verified::new_unchecked("https://api.example.com/users/1".to_string());
-        let body = Verified::new_unchecked(r#"{"status": "active"}"#.to_string());
+        let http = PolicyHttp::new("req-test-7", None, &UNCONFIGURED);
+        let url = target("https://api.example.com/users/1");
+        let body: Verified<String, HttpBody> =
+            Verified::new_unchecked(r#"{"status": "active"}"#.to_string());
 
-        http.patch(&url, &body);
+        http.patch(&url, &body).unwrap();
 
         let requests = http.requests();
         assert_eq!(requests[0].request_id, "req-test-7");
@@ -449,11 +858,12 @@ mod tests {
 
     #[test]
     fn policy_http_does_not_leak_body_in_metadata() {
-        let http = PolicyHttp::new("req-test-8");
-        let url = Verified::new_unchecked("https://api.example.com".to_string());
-        let secret_body = Verified::new_unchecked("SECRET_PASSWORD_12345".to_string());
+        let http = PolicyHttp::new("req-test-8", None, &UNCONFIGURED);
+        let url = target("https://api.example.com");
+        let secret_body: Verified<String, HttpBody> =
+            Verified::new_unchecked("SECRET_PASSWORD_12345".to_string());
 
-        http.post(&url, &secret_body);
+        http.post(&url, &secret_body).unwrap();
 
         let requests = http.requests();
         // Metadata should only contain length, not the actual body
@@ -464,4 +874,235 @@ mod tests {
         let debug_output = format!("{:?}", requests[0]);
         assert!(!debug_output.contains("SECRET_PASSWORD"));
     }
+
+    #[test]
+    fn policy_http_without_transport_returns_empty_ok() {
+        let http = PolicyHttp::new("req-test-9", None, &UNCONFIGURED);
+        let url = target("https://api.example.com");
+
+        let response = http.get(&url).unwrap();
+
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.body_len(), 0);
+    }
+
+    #[test]
+    fn policy_http_forwards_to_transport() {
+        let transport = Arc::new(RecordingTransport::with_response(HttpResponse::new(
+            201, "created",
+        )));
+        let config = with_transport(&transport);
+        let http = PolicyHttp::new("req-test-10", None, &config);
+        let url = target("https://api.example.com/items");
+        let body: Verified<String, HttpBody> = Verified::new_unchecked("data".to_string());
+
+        let response = http.post(&url, &body).unwrap();
+
+        assert_eq!(response.status(), 201);
+        assert_eq!(response.text().into_value().into_inner(), "created");
+        assert_eq!(http.request_count(), 1);
+        let sent = transport.requests();
+        assert_eq!(sent[0].request_id, "req-test-10");
+        assert_eq!(sent[0].method, HttpMethod::Post);
+        assert_eq!(sent[0].body_len, 4);
+    }
+
+    #[test]
+    fn policy_http_labels_response_bodies_with_redacted_url() {
+        let transport = Arc::new(RecordingTransport::with_response(HttpResponse::new(
+            200, "ok",
+        )));
+        let config = with_transport(&transport);
+        let http = PolicyHttp::new("req-test-12", None, &config);
+        let url = target("https://user:pw@api.example.com/v1?token=abc");
+
+        let response = http.get(&url).unwrap();
+
+        let expected = crate::Provenance::upstream("https://api.example.com/v1");
+        assert_eq!(response.text().provenance(), Some(&expected));
+        assert_eq!(response.into_body().provenance(), Some(&expected));
+    }
+
+    #[test]
+    fn policy_http_strips_control_chars_from_forwarded_request_id() {
+        let transport = Arc::new(RecordingTransport::new());
+        let config = with_transport(&transport);
+        let http = PolicyHttp::new("req-1\r\nx-injected: 1", None, &config);
+        let url = target("https://api.example.com");
+
+        http.get(&url).unwrap();
+
+        assert_eq!(transport.requests()[0].request_id, "req-1  x-injected: 1");
+    }
+
+    #[test]
+    fn policy_http_propagates_transport_errors() {
+        #[derive(Debug)]
+        struct Unreachable;
+
+        impl HttpTransport for Unreachable {
+            fn send(&self, _: &OutboundRequest<'_>) -> Result<HttpResponse, HttpError> {
+                Err(HttpError::new(HttpErrorKind::Connect))
+            }
+        }
+
+        let config = HttpConfig {
+            transport: Some(Arc::new(Unreachable)),
+            async_transport: None,
+            egress: None,
+        };
+        let http = PolicyHttp::new("req-test-11", None, &config);
+        let url = target("https://api.example.com");
+
+        let err = http.get(&url).unwrap_err();
+
+        assert!(matches!(err, Error::Http(e) if e.kind() == HttpErrorKind::Connect));
+        assert_eq!(http.request_count(), 1);
+    }
+
+    #[test]
+    fn policy_http_reports_private_resolution_as_egress_denial() {
+        #[derive(Debug)]
+        struct Rebound;
+
+        impl HttpTransport for Rebound {
+            fn send(&self, _: &OutboundRequest<'_>) -> Result<HttpResponse, HttpError> {
+                Err(HttpError::new(HttpErrorKind::PrivateAddress))
+            }
+        }
+
+        let config = HttpConfig {
+            transport: Some(Arc::new(Rebound)),
+            async_transport: None,
+            egress: Some(Arc::new(EgressPolicy::new().allow_host("*.example.com"))),
+        };
+        let http = PolicyHttp::new("req-rebind", None, &config);
+        let url = target("https://internal.example.com/");
+
+        let err = http.get(&url).unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::Violation(v) if v.kind == ViolationKind::EgressDenied {
+                reason: EgressDenial::PrivateAddress
+            }
+        ));
+    }
+
+    #[test]
+    fn policy_http_enforces_egress_policy_before_sending() {
+        let transport = Arc::new(RecordingTransport::new());
+        let config = HttpConfig {
+            egress: Some(Arc::new(
+                EgressPolicy::new()
+                    .allow_host("api.example.com")
+                    .allow_path_prefix("/v1"),
+            )),
+            ..with_transport(&transport)
+        };
+        let http = PolicyHttp::new("req-test-12", Some("user-1"), &config);
+
+        let allowed = target("https://api.example.com/v1/items");
+        http.get(&allowed).unwrap();
+
+        for (url, reason) in [
+            ("https://evil.test/v1/items", EgressDenial::Host),
+            ("https://api.example.com/admin", EgressDenial::Path),
+            ("https://10.0.0.1/v1", EgressDenial::PrivateAddress),
+        ] {
+            let denied = target(url);
+            let err = http.delete(&denied).unwrap_err();
+            assert!(
+                matches!(&err, Error::Violation(v) if v.kind == ViolationKind::EgressDenied { reason }),
+                "{url}: {err}"
+            );
+        }
+
+        // Denied requests are neither recorded nor sent
+        assert_eq!(http.request_count(), 1);
+        assert_eq!(transport.request_count(), 1);
+    }
+
+    #[test]
+    fn policy_http_async_methods_use_async_transport() {
+        let blocking = Arc::new(RecordingTransport::new());
+        let async_transport = Arc::new(RecordingTransport::with_response(HttpResponse::new(
+            202, "queued",
+        )));
+        let config = HttpConfig {
+            async_transport: Some(Arc::clone(&async_transport) as Arc<dyn AsyncHttpTransport>),
+            ..with_transport(&blocking)
+        };
+        let http = PolicyHttp::new("req-test-13", None, &config);
+        let url = target("https://api.example.com/jobs?t=1");
+        let body = HttpBodySanitizer::new(64)
+            .unwrap()
+            .sanitize(Tainted::new("{}".to_string()))
+            .unwrap();
+
+        let response = poll_ready(http.post_async(&url, &body)).unwrap();
+        poll_ready(http.get_async(&url)).unwrap();
+        poll_ready(http.put_async(&url, &body)).unwrap();
+        poll_ready(http.patch_async(&url, &body)).unwrap();
+        poll_ready(http.delete_async(&url)).unwrap();
+
+        assert_eq!(response.status(), 202);
+        assert_eq!(
+            response.text().provenance(),
+            Some(&crate::Provenance::upstream("https://api.example.com/jobs"))
+        );
+        assert_eq!(async_transport.request_count(), 5);
+        assert_eq!(async_transport.requests()[0].body_len, 2);
+        assert_eq!(blocking.request_count(), 0);
+        assert_eq!(http.request_count(), 5);
+    }
+
+    #[test]
+    fn policy_http_async_methods_enforce_egress_policy() {
+        let transport = Arc::new(RecordingTransport::new());
+        let config = HttpConfig {
+            transport: None,
+            async_transport: Some(Arc::clone(&transport) as Arc<dyn AsyncHttpTransport>),
+            egress: Some(Arc::new(EgressPolicy::new().allow_host("api.example.com"))),
+        };
+        let http = PolicyHttp::new("req-test-14", None, &config);
+        let url = target("https://evil.test/latest");
+
+        let err = poll_ready(http.get_async(&url)).unwrap_err();
+
+        assert!(matches!(
+            err,
+            Error::Violation(v) if v.kind == ViolationKind::EgressDenied {
+                reason: EgressDenial::Host
+            }
+        ));
+        assert_eq!(http.request_count(), 0);
+        assert_eq!(transport.request_count(), 0);
+    }
+
+    #[test]
+    fn policy_http_async_methods_record_without_transport() {
+        let http = PolicyHttp::new("req-test-15", None, &UNCONFIGURED);
+        let url = target("https://api.example.com");
+
+        let response = poll_ready(http.get_async(&url)).unwrap();
+
+        assert_eq!(response.status(), 200);
+        assert_eq!(http.request_count(), 1);
+    }
+
+    #[test]
+    fn policy_http_async_futures_are_send() {
+        fn assert_send<T: Send>(_: &T) {}
+        let http = PolicyHttp::new("req-test-16", None, &UNCONFIGURED);
+        let url = target("https://api.example.com");
+
+        assert_send(&http.get_async(&url));
+    }
+
+    #[test]
+    fn policy_http_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<PolicyHttp<'static>>();
+    }
 }
@@ -0,0 +1,366 @@
+//! Real network transport backed by `ureq` (feature `http-client`).
+
+use std::io::{self, Read};
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::time::Duration;
+
+use super::resolve::{reject_private, PrivateAddressError};
+use super::transport::{
+    HttpError, HttpErrorKind, HttpResponse, HttpTransport, OutboundRequest, REQUEST_ID_HEADER,
+};
+use super::HttpMethod;
+
+/// Default limit on response body size (10 MiB).
+const DEFAULT_MAX_BODY_LEN: usize = 10 * 1024 * 1024;
+
+/// Transport that performs real, blocking HTTP(S) requests.
+///
+/// Available with the `http-client` feature. Connections are pooled, so build
+/// one transport at startup and share it through an `Arc`. Async handlers
+/// should use `PolicyHttp`'s `*_async` methods with an
+/// [`AsyncHttpTransport`](crate::AsyncHttpTransport) instead, such as
+/// `AsyncTransport` from the `http-client-async` feature.
+///
+/// # Security Properties
+///
+/// - **No redirects**: a redirect would send the request to a URL that was
+///   never verified, so 3xx responses are returned as-is
+/// - **No private addresses**: a host that resolves to a private, loopback or
+///   link-local address fails with [`HttpErrorKind::PrivateAddress`], and the
+///   connection only uses the addresses that were checked. Call
+///   [`allow_private_networks`](Self::allow_private_networks) for internal
+///   services
+/// - **Bounded bodies**: responses larger than the configured limit fail
+///   with [`HttpErrorKind::Io`] instead of exhausting memory
+/// - **Request-id propagation**: every request carries the
+///   [`REQUEST_ID_HEADER`] header
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use policy_core::{AllowAuthenticated, Authorized, BlockingTransport, PolicyGate, Principal, RequestMeta};
+///
+/// let transport = Arc::new(BlockingTransport::new().with_timeout(Duration::from_secs(5)));
+///
+/// let meta = RequestMeta {
+///     request_id: "req-1".to_string(),
+///     principal: Some(Principal::new("u1", "Alice")),
+/// };
+/// let ctx = PolicyGate::new(meta)
+///     .with_authorizer(AllowAuthenticated)
+///     .with_http_transport(Arc::clone(&transport))
+///     .require(Authorized::for_action("http"))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug)]
+pub struct BlockingTransport {
+    agent: ureq::Agent,
+    timeout: Duration,
+    allow_private: bool,
+    max_body_len: usize,
+}
+
+impl BlockingTransport {
+    /// Creates a transport with a 30 second timeout and a 10 MiB body limit.
+    pub fn new() -> Self {
+        let timeout = Duration::from_secs(30);
+        Self {
+            agent: Self::agent(timeout, false),
+            timeout,
+            allow_private: false,
+            max_body_len: DEFAULT_MAX_BODY_LEN,
+        }
+    }
+
+    /// Sets the overall timeout for each request.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self.agent = Self::agent(timeout, self.allow_private);
+        self
+    }
+
+    /// Allows connections to private, loopback and link-local addresses.
+    ///
+    /// Only use this for trusted internal services or local test servers;
+    /// the gate's [`EgressPolicy`](crate::EgressPolicy) still applies.
+    pub fn allow_private_networks(mut self) -> Self {
+        self.allow_private = true;
+        self.agent = Self::agent(self.timeout, true);
+        self
+    }
+
+    /// Sets the maximum accepted response body size in bytes.
+    pub fn with_max_body_len(mut self, max_body_len: usize) -> Self {
+        self.max_body_len = max_body_len;
+        self
+    }
+
+    fn agent(timeout: Duration, allow_private: bool) -> ureq::Agent {
+        // BREAKING CHANGE WARNING: redirects MUST stay disabled. Following a redirect
+        // sends the request (and its body) to a URL that never passed verification.
+        let builder = ureq::AgentBuilder::new().timeout(timeout).redirects(0);
+        if allow_private {
+            return builder.build();
+        }
+        // BREAKING CHANGE WARNING: the resolver MUST check the addresses that are
+        // connected to. Checking a separate lookup lets DNS rebinding reach
+        // internal addresses (SSRF, CWE-918).
+        builder.resolver(resolve_public).build()
+    }
+
+    fn read_body(&self, response: ureq::Response) -> Result<HttpResponse, HttpError> {
+        let status = response.status();
+        let limit = u64::try_from(self.max_body_len).unwrap_or(u64::MAX);
+
+        let mut body = Vec::new();
+        response
+            .into_reader()
+            .take(limit.saturating_add(1))
+            .read_to_end(&mut body)
+            .map_err(|e| io_error(&e))?;
+
+        if body.len() > self.max_body_len {
+            return Err(HttpError::with_message(
+                HttpErrorKind::Io,
+                format!("response body exceeds {} bytes", self.max_body_len),
+            ));
+        }
+
+        Ok(HttpResponse::new(status, body))
+    }
+}
+
+impl Default for BlockingTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HttpTransport for BlockingTransport {
+    fn send(&self, request: &OutboundRequest<'_>) -> Result<HttpResponse, HttpError> {
+        let method = request.method().to_string();
+        let call = self
+            .agent
+            .request(&method, request.url())
+            .set(REQUEST_ID_HEADER, request.request_id());
+
+        let result = match request.method() {
+            HttpMethod::Get | HttpMethod::Delete => call.call(),
+            HttpMethod::Post | HttpMethod::Put | HttpMethod::Patch => {
+                call.send_string(request.body())
+            }
+        };
+
+        match result {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => self.read_body(response),
+            Err(ureq::Error::Transport(transport)) => Err(transport_error(&transport)),
+        }
+    }
+}
+
+/// Resolves `netloc`, refusing it if any of its addresses is private.
+fn resolve_public(netloc: &str) -> io::Result<Vec<SocketAddr>> {
+    let addrs = netloc.to_socket_addrs()?.collect();
+    Ok(reject_private(addrs)?)
+}
+
+/// Maps a ureq transport failure to an `HttpError` without echoing the URL.
+fn transport_error(error: &ureq::Transport) -> HttpError {
+    use ureq::ErrorKind;
+
+    if PrivateAddressError::is_cause_of(error) {
+        return HttpError::new(HttpErrorKind::PrivateAddress);
+    }
+
+    let kind = match error.kind() {
+        ErrorKind::InvalidUrl | ErrorKind::UnknownScheme | ErrorKind::InsecureRequestHttpsOnly => {
+            HttpErrorKind::InvalidUrl
+        }
+        ErrorKind::Dns
+        | ErrorKind::ConnectionFailed
+        | ErrorKind::InvalidProxyUrl
+        | ErrorKind::ProxyConnect
+        | ErrorKind::ProxyUnauthorized => HttpErrorKind::Connect,
+        ErrorKind::BadStatus | ErrorKind::BadHeader | ErrorKind::TooManyRedirects => {
+            HttpErrorKind::Protocol
+        }
+        ErrorKind::Io | ErrorKind::HTTP => {
+            let timed_out = std::error::Error::source(error)
+                .and_then(|source| source.downcast_ref::<io::Error>())
+                .is_some_and(is_timeout);
+            if timed_out {
+                HttpErrorKind::Timeout
+            } else {
+                HttpErrorKind::Io
+            }
+        }
+    };
+
+    HttpError::new(kind)
+}
+
+fn io_error(error: &io::Error) -> HttpError {
+    if is_timeout(error) {
+        HttpError::new(HttpErrorKind::Timeout)
+    } else {
+        HttpError::new(HttpErrorKind::Io)
+    }
+}
+
+fn is_timeout(error: &io::Error) -> bool {
+    matches!(
+        error.kind(),
+        io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves one request on a loopback port and returns its head and body.
+    fn serve_once(response: &'static str) -> (String, thread::JoinHandle<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/items", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut head = String::new();
+            let mut content_len = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+                if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_len = value.trim().parse().unwrap();
+                }
+                head.push_str(&line);
+            }
+
+            let mut body = vec![0; content_len];
+            reader.read_exact(&mut body).unwrap();
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+
+            (head, String::from_utf8(body).unwrap())
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn sends_request_id_and_returns_tainted_body() {
+        let (url, server) =
+            serve_once("HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello");
+        let transport = BlockingTransport::new().allow_private_networks();
+
+        let request = OutboundRequest::new("req-loopback", HttpMethod::Post, &url, "payload");
+        let response = transport.send(&request).unwrap();
+
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.text().into_value().into_inner(), "hello");
+
+        let (head, body) = server.join().unwrap();
+        assert!(head.starts_with("POST /items HTTP/1.1"));
+        assert!(head
+            .to_ascii_lowercase()
+            .contains("x-request-id: req-loopback"));
+        assert_eq!(body, "payload");
+    }
+
+    #[test]
+    fn error_statuses_are_responses() {
+        let (url, server) = serve_once(
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 4\r\nConnection: close\r\n\r\nnope",
+        );
+        let transport = BlockingTransport::new().allow_private_networks();
+
+        let request = OutboundRequest::new("req-404", HttpMethod::Get, &url, "");
+        let response = transport.send(&request).unwrap();
+
+        assert_eq!(response.status(), 404);
+        assert!(!response.is_success());
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn private_addresses_are_refused_by_default() {
+        let transport = BlockingTransport::new();
+
+        for url in [
+            "http://localhost:1/",
+            "http://127.0.0.1:1/",
+            "http://[::1]:1/",
+        ] {
+            let request = OutboundRequest::new("req-private", HttpMethod::Get, url, "");
+            let err = transport.send(&request).unwrap_err();
+            assert_eq!(err.kind(), HttpErrorKind::PrivateAddress, "{}", url);
+        }
+    }
+
+    #[test]
+    fn redirects_are_not_followed() {
+        let (url, server) = serve_once(
+            "HTTP/1.1 302 Found\r\nLocation: http://127.0.0.1:1/\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        );
+        let transport = BlockingTransport::new().allow_private_networks();
+
+        let request = OutboundRequest::new("req-302", HttpMethod::Get, &url, "");
+        let response = transport.send(&request).unwrap();
+
+        assert_eq!(response.status(), 302);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn oversized_bodies_are_rejected() {
+        let (url, server) =
+            serve_once("HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello");
+        let transport = BlockingTransport::new()
+            .allow_private_networks()
+            .with_max_body_len(4);
+
+        let request = OutboundRequest::new("req-big", HttpMethod::Get, &url, "");
+        let err = transport.send(&request).unwrap_err();
+
+        assert_eq!(err.kind(), HttpErrorKind::Io);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn connection_failures_map_to_connect() {
+        // Bind then drop to get a port with no listener
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let url = format!("http://{}/", addr);
+        let transport = BlockingTransport::new()
+            .allow_private_networks()
+            .with_timeout(Duration::from_secs(2));
+
+        let request = OutboundRequest::new("req-down", HttpMethod::Get, &url, "");
+        let err = transport.send(&request).unwrap_err();
+
+        assert_eq!(err.kind(), HttpErrorKind::Connect);
+    }
+
+    #[test]
+    fn invalid_urls_map_to_invalid_url() {
+        let transport = BlockingTransport::new().allow_private_networks();
+
+        let request = OutboundRequest::new("req-bad", HttpMethod::Get, "not a url", "");
+        let err = transport.send(&request).unwrap_err();
+
+        assert_eq!(err.kind(), HttpErrorKind::InvalidUrl);
+    }
+}
@@ -0,0 +1,545 @@
+//! Outbound request allowlists enforced by `PolicyHttp`.
+
+use std::collections::BTreeSet;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use super::url::{default_port, host_matches, host_pattern, Host, ParsedUrl};
+
+/// Allowlist of destinations `PolicyHttp` may contact.
+///
+/// A verified URL only proves the string is well-formed; it says nothing
+/// about where the request goes. An `EgressPolicy` restricts that, closing
+/// the server-side request forgery (SSRF) hole of sending requests to any
+/// host an attacker can name. Configure it on the gate with
+/// [`PolicyGate::with_egress_policy`](crate::PolicyGate::with_egress_policy).
+///
+/// # Rules
+///
+/// A request is allowed only if every rule passes:
+///
+/// - **Scheme**: one of [`allow_scheme`](Self::allow_scheme); `https` only if none are set
+/// - **Host**: matches [`allow_host`](Self::allow_host); no hosts are allowed by default.
+///   `*.example.com` matches any subdomain but not `example.com` itself
+/// - **Port**: one of [`allow_port`](Self::allow_port); only the scheme's default
+///   port (443/80) if none are set
+/// - **Path**: starts with one of [`allow_path_prefix`](Self::allow_path_prefix) at a
+///   segment boundary; any path if none are set. Paths with dot segments or
+///   encoded separators are always rejected when prefixes are set
+/// - **Private addresses**: IP literals in loopback, private, link-local,
+///   carrier-grade NAT, unspecified, multicast or unique-local ranges, and
+///   `localhost`, are denied unless [`allow_private_networks`](Self::allow_private_networks)
+///   is called. IPv6 addresses that embed an IPv4 address (mapped,
+///   IPv4-compatible, NAT64 `64:ff9b::/96` and 6to4 `2002::/16`) are judged
+///   by that IPv4 address
+///
+/// URLs carrying credentials (`user:pass@host`) are always denied: they are
+/// a common way to disguise the real host.
+///
+/// # Security Considerations
+///
+/// The policy matches hostnames as written and does not resolve them. An
+/// allowed name, or a subdomain covered by a wildcard, can still resolve to an
+/// internal address. The built-in transports (`BlockingTransport` and
+/// `AsyncTransport`) close that gap. They check every address a host resolves
+/// to and refuse to connect to private ones, and the connection uses the
+/// addresses that were checked. Custom [`HttpTransport`](crate::HttpTransport)s
+/// must do the same.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::EgressPolicy;
+///
+/// let policy = EgressPolicy::new()
+///     .allow_host("api.example.com")
+///     .allow_host("*.cdn.example.com")
+///     .allow_path_prefix("/v1/");
+///
+/// assert!(policy.check("https://api.example.com/v1/users").is_ok());
+/// assert!(policy.check("https://img.cdn.example.com/v1/logo.png").is_ok());
+/// assert!(policy.check("https://api.example.com/admin").is_err());
+/// assert!(policy.check("http://api.example.com/v1/users").is_err());
+/// assert!(policy.check("https://169.254.169.254/v1/").is_err());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EgressPolicy {
+    schemes: BTreeSet<String>,
+    hosts: BTreeSet<String>,
+    ports: BTreeSet<u16>,
+    path_prefixes: Vec<String>,
+    allow_private: bool,
+}
+
+impl EgressPolicy {
+    /// Creates a policy that denies every destination until hosts are allowed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Allows a URL scheme (case-insensitive), replacing the `https`-only default.
+    pub fn allow_scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.schemes.insert(scheme.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Allows a host (case-insensitive).
+    ///
+    /// A leading `*.` allows every subdomain of the remaining name. IP
+    /// literals are written without brackets (`10.0.0.5`, `::1`).
+    pub fn allow_host(mut self, host: impl Into<String>) -> Self {
+        self.hosts.insert(host_pattern(host.into()));
+        self
+    }
+
+    /// Allows a port, replacing the scheme-default-only default.
+    pub fn allow_port(mut self, port: u16) -> Self {
+        self.ports.insert(port);
+        self
+    }
+
+    /// Allows paths starting with `prefix`.
+    ///
+    /// Prefixes match at segment boundaries: `/v1` matches `/v1` and `/v1/users`
+    /// but not `/v1admin`.
+    pub fn allow_path_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.path_prefixes.push(prefix.into());
+        self
+    }
+
+    /// Allows IP literals in private, loopback and link-local ranges.
+    ///
+    /// Only use this for trusted internal services or local test servers;
+    /// the allowed hosts still apply.
+    pub fn allow_private_networks(mut self) -> Self {
+        self.allow_private = true;
+        self
+    }
+
+    /// Checks a URL against the policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`EgressDenial`] for the first rule the URL fails.
+    pub fn check(&self, url: &str) -> Result<(), EgressDenial> {
+        let parsed = ParsedUrl::parse(url).ok_or(EgressDenial::MalformedUrl)?;
+        self.check_parsed(&parsed)
+    }
+
+    pub(crate) fn check_parsed(&self, url: &ParsedUrl) -> Result<(), EgressDenial> {
+        if url.has_userinfo {
+            return Err(EgressDenial::Credentials);
+        }
+
+        let scheme_allowed = if self.schemes.is_empty() {
+            url.scheme == "https"
+        } else {
+            self.schemes.contains(&url.scheme)
+        };
+        if !scheme_allowed {
+            return Err(EgressDenial::Scheme);
+        }
+
+        // BREAKING CHANGE WARNING: The private-range check MUST run before the host
+        // allowlist. A wildcard or misconfigured allowlist must never open access to
+        // internal addresses such as cloud metadata endpoints (SSRF, CWE-918).
+        if !self.allow_private && is_private(&url.host) {
+            return Err(EgressDenial::PrivateAddress);
+        }
+
+        if !self.host_allowed(&url.host) {
+            return Err(EgressDenial::Host);
+        }
+
+        let port_allowed = match url.effective_port() {
+            Some(port) if self.ports.is_empty() => default_port(&url.scheme) == Some(port),
+            Some(port) => self.ports.contains(&port),
+            None => false,
+        };
+        if !port_allowed {
+            return Err(EgressDenial::Port);
+        }
+
+        if !self.path_prefixes.is_empty()
+            && (url.has_ambiguous_path()
+                || !self
+                    .path_prefixes
+                    .iter()
+                    .any(|prefix| path_matches(&url.path, prefix)))
+        {
+            return Err(EgressDenial::Path);
+        }
+
+        Ok(())
+    }
+
+    fn host_allowed(&self, host: &Host) -> bool {
+        self.hosts.iter().any(|pattern| host_matches(pattern, host))
+    }
+}
+
+/// Why an [`EgressPolicy`] denied a URL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EgressDenial {
+    /// The URL could not be parsed unambiguously
+    MalformedUrl,
+    /// The URL carries `user:password@` credentials
+    Credentials,
+    /// The scheme is not allowed
+    Scheme,
+    /// The host is not allowed
+    Host,
+    /// The port is not allowed
+    Port,
+    /// The path is not under an allowed prefix
+    Path,
+    /// The host is an address in a private, loopback or link-local range
+    PrivateAddress,
+}
+
+impl EgressDenial {
+    /// Returns a short, stable description of the failed rule.
+    pub fn reason(&self) -> &'static str {
+        match self {
+            Self::MalformedUrl => "malformed URL",
+            Self::Credentials => "credentials in URL",
+            Self::Scheme => "scheme not allowed",
+            Self::Host => "host not allowed",
+            Self::Port => "port not allowed",
+            Self::Path => "path not allowed",
+            Self::PrivateAddress => "private address",
+        }
+    }
+}
+
+impl std::fmt::Display for EgressDenial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.reason())
+    }
+}
+
+fn path_matches(path: &str, prefix: &str) -> bool {
+    match path.strip_prefix(prefix) {
+        Some(rest) => prefix.ends_with('/') || rest.is_empty() || rest.starts_with('/'),
+        None => false,
+    }
+}
+
+fn is_private(host: &Host) -> bool {
+    match host {
+        Host::Domain(name) => name == "localhost" || name.ends_with(".localhost"),
+        Host::Ip(ip) => is_private_ip(ip),
+    }
+}
+
+/// Returns `true` for addresses in the ranges [`EgressPolicy`] denies unless
+/// private networks are allowed.
+pub(crate) fn is_private_ip(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_private_v4(ip),
+        IpAddr::V6(ip) => is_private_v6(ip),
+    }
+}
+
+fn is_private_v4(ip: &Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    ip.is_private()
+        || ip.is_loopback()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || a == 0
+        // Carrier-grade NAT, 100.64.0.0/10
+        || (a == 100 && (64..128).contains(&b))
+}
+
+fn is_private_v6(ip: &Ipv6Addr) -> bool {
+    if let Some(v4) = embedded_ipv4(ip) {
+        return is_private_v4(&v4);
+    }
+    let first = ip.segments()[0];
+    ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        // Unique local, fc00::/7
+        || (first & 0xfe00) == 0xfc00
+        // Link-local, fe80::/10
+        || (first & 0xffc0) == 0xfe80
+}
+
+/// Returns the IPv4 address carried by IPv6 forms that reach it: mapped
+/// (`::ffff:a.b.c.d`), IPv4-compatible (`::a.b.c.d`), NAT64 (`64:ff9b::/96`)
+/// and 6to4 (`2002::/16`).
+fn embedded_ipv4(ip: &Ipv6Addr) -> Option<Ipv4Addr> {
+    let octets = ip.octets();
+    let tail = |start: usize| {
+        Ipv4Addr::new(
+            octets[start],
+            octets[start + 1],
+            octets[start + 2],
+            octets[start + 3],
+        )
+    };
+    match ip.segments() {
+        // Mapped ::ffff:0:0/96 and IPv4-compatible ::/96; `::` and `::1` are
+        // included and map to 0.0.0.0/8, which is private as well
+        [0, 0, 0, 0, 0, 0xffff | 0, _, _] => Some(tail(12)),
+        // NAT64 well-known prefix, 64:ff9b::/96
+        [0x64, 0xff9b, 0, 0, 0, 0, _, _] => Some(tail(12)),
+        // 6to4, 2002::/16, with the IPv4 address in bytes 2..6
+        [0x2002, ..] => Some(tail(2)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn api_policy() -> EgressPolicy {
+        EgressPolicy::new().allow_host("api.example.com")
+    }
+
+    #[test]
+    fn empty_policy_denies_everything() {
+        assert_eq!(
+            EgressPolicy::new().check("https://api.example.com/"),
+            Err(EgressDenial::Host)
+        );
+    }
+
+    #[test]
+    fn scheme_defaults_to_https() {
+        assert!(api_policy().check("https://api.example.com/").is_ok());
+        assert_eq!(
+            api_policy().check("http://api.example.com/"),
+            Err(EgressDenial::Scheme)
+        );
+        assert_eq!(
+            api_policy().check("file:///etc/passwd"),
+            Err(EgressDenial::MalformedUrl)
+        );
+
+        let http = api_policy().allow_scheme("HTTP");
+        assert!(http.check("http://api.example.com/").is_ok());
+        assert_eq!(
+            http.check("https://api.example.com/"),
+            Err(EgressDenial::Scheme)
+        );
+    }
+
+    #[test]
+    fn hosts_match_exactly_or_by_wildcard() {
+        let policy = EgressPolicy::new()
+            .allow_host("API.example.com.")
+            .allow_host("*.cdn.test");
+
+        assert!(policy.check("https://api.example.com/").is_ok());
+        assert!(policy.check("https://a.b.cdn.test/").is_ok());
+        assert_eq!(policy.check("https://cdn.test/"), Err(EgressDenial::Host));
+        assert_eq!(
+            policy.check("https://evilcdn.test/"),
+            Err(EgressDenial::Host)
+        );
+        assert_eq!(
+            policy.check("https://api.example.com.evil.test/"),
+            Err(EgressDenial::Host)
+        );
+    }
+
+    #[test]
+    fn ports_default_to_scheme_port() {
+        assert!(api_policy().check("https://api.example.com:443/").is_ok());
+        assert_eq!(
+            api_policy().check("https://api.example.com:8443/"),
+            Err(EgressDenial::Port)
+        );
+
+        let policy = api_policy().allow_port(8443);
+        assert!(policy.check("https://api.example.com:8443/").is_ok());
+        assert_eq!(
+            policy.check("https://api.example.com/"),
+            Err(EgressDenial::Port)
+        );
+    }
+
+    #[test]
+    fn path_prefixes_match_segments() {
+        let policy = api_policy().allow_path_prefix("/v1");
+
+        assert!(policy.check("https://api.example.com/v1").is_ok());
+        assert!(policy.check("https://api.example.com/v1/users?x=1").is_ok());
+        for path in ["/v1admin", "/v2/users", "/v1/../admin", "/v1/%2e%2e/admin"] {
+            assert_eq!(
+                policy.check(&format!("https://api.example.com{path}")),
+                Err(EgressDenial::Path),
+                "{path}"
+            );
+        }
+    }
+
+    #[test]
+    fn private_addresses_are_denied_even_when_allowed() {
+        let policy = EgressPolicy::new()
+            .allow_scheme("http")
+            .allow_host("127.0.0.1")
+            .allow_host("169.254.169.254")
+            .allow_host("::1")
+            .allow_host("localhost");
+
+        for url in [
+            "http://127.0.0.1/",
+            "http://169.254.169.254/latest/meta-data",
+            "http://[::1]/",
+            "http://[::ffff:10.0.0.1]/",
+            "http://localhost/",
+            "http://10.1.2.3/",
+            "http://192.168.0.1/",
+            "http://172.16.0.1/",
+            "http://100.64.0.1/",
+            "http://0.0.0.0/",
+            "http://[fd00::1]/",
+            "http://[fe80::1]/",
+        ] {
+            assert_eq!(
+                policy.check(url),
+                Err(EgressDenial::PrivateAddress),
+                "{url}"
+            );
+        }
+    }
+
+    #[test]
+    fn ipv6_forms_embedding_private_ipv4_are_denied() {
+        let policy = EgressPolicy::new().allow_scheme("http");
+
+        for url in [
+            // IPv4-compatible
+            "http://[::127.0.0.1]/",
+            "http://[::a9fe:a9fe]/",
+            // NAT64
+            "http://[64:ff9b::10.0.0.1]/",
+            "http://[64:ff9b::a9fe:a9fe]/",
+            // 6to4
+            "http://[2002:7f00:1::]/",
+            "http://[2002:c0a8:101::1]/",
+            "http://[2002:a9fe:a9fe::]/",
+        ] {
+            assert_eq!(
+                policy.check(url),
+                Err(EgressDenial::PrivateAddress),
+                "{url}"
+            );
+        }
+    }
+
+    #[test]
+    fn ipv6_forms_embedding_public_ipv4_follow_the_allowlist() {
+        for host in ["::5db8:d822", "64:ff9b::5db8:d822", "2002:5db8:d822::1"] {
+            let url = format!("https://[{host}]/");
+            assert_eq!(api_policy().check(&url), Err(EgressDenial::Host), "{url}");
+            assert!(
+                EgressPolicy::new().allow_host(host).check(&url).is_ok(),
+                "{url}"
+            );
+        }
+    }
+
+    #[test]
+    fn embedded_ipv4_is_extracted_from_each_form() {
+        let v4 = Ipv4Addr::new(10, 1, 2, 3);
+        for v6 in [
+            "::ffff:10.1.2.3",
+            "::10.1.2.3",
+            "64:ff9b::10.1.2.3",
+            "2002:a01:203::",
+        ] {
+            let ip: Ipv6Addr = v6.parse().unwrap();
+            assert_eq!(embedded_ipv4(&ip), Some(v4), "{v6}");
+        }
+        let native: Ipv6Addr = "2001:db8::10.1.2.3".parse().unwrap();
+        assert_eq!(embedded_ipv4(&native), None);
+    }
+
+    #[test]
+    fn private_networks_can_be_opted_into() {
+        let policy = EgressPolicy::new()
+            .allow_scheme("http")
+            .allow_host("127.0.0.1")
+            .allow_port(8080)
+            .allow_private_networks();
+
+        assert!(policy.check("http://127.0.0.1:8080/").is_ok());
+        assert_eq!(
+            policy.check("http://127.0.0.2:8080/"),
+            Err(EgressDenial::Host)
+        );
+    }
+
+    #[test]
+    fn public_ip_literals_need_an_allowlist_entry() {
+        let policy = EgressPolicy::new().allow_host("93.184.216.34");
+
+        assert!(policy.check("https://93.184.216.34/").is_ok());
+        assert_eq!(
+            policy.check("https://93.184.216.35/"),
+            Err(EgressDenial::Host)
+        );
+    }
+
+    #[test]
+    fn credentials_are_denied() {
+        assert_eq!(
+            api_policy().check("https://api.example.com@evil.test/"),
+            Err(EgressDenial::Credentials)
+        );
+        assert_eq!(
+            api_policy().check("https://user:pw@api.example.com/"),
+            Err(EgressDenial::Credentials)
+        );
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        /// Property: Private IPv4 literals are denied even when explicitly allowed
+        #[test]
+        fn proptest_private_ipv4_always_denied(
+            octets in prop_oneof![
+                (Just(10u8), any::<u8>(), any::<u8>(), any::<u8>()),
+                (Just(127u8), any::<u8>(), any::<u8>(), any::<u8>()),
+                (Just(172u8), 16u8..32, any::<u8>(), any::<u8>()),
+                (Just(192u8), Just(168u8), any::<u8>(), any::<u8>()),
+                (Just(169u8), Just(254u8), any::<u8>(), any::<u8>()),
+            ],
+            port in prop::option::of(any::<u16>()),
+        ) {
+            let ip = Ipv4Addr::new(octets.0, octets.1, octets.2, octets.3);
+            let policy = EgressPolicy::new().allow_host(ip.to_string()).allow_host("*.internal");
+            let url = match port {
+                Some(port) => format!("https://{}:{}/", ip, port),
+                None => format!("https://{}/", ip),
+            };
+
+            prop_assert_eq!(policy.check(&url), Err(EgressDenial::PrivateAddress));
+        }
+
+        /// Property: Only listed hosts and their wildcard subdomains are allowed
+        #[test]
+        fn proptest_unlisted_hosts_denied(label in "[a-z][a-z0-9-]{0,20}[a-z0-9]") {
+            let policy = EgressPolicy::new().allow_host("api.example.com").allow_host("*.cdn.test");
+
+            let subdomain = format!("https://{}.cdn.test/", label);
+            let lookalike = format!("https://{}cdn.test/", label);
+            let suffixed = format!("https://api.example.com.{}.test/", label);
+
+            prop_assert!(policy.check(&subdomain).is_ok());
+            prop_assert_eq!(policy.check(&lookalike), Err(EgressDenial::Host));
+            prop_assert_eq!(policy.check(&suffixed), Err(EgressDenial::Host));
+        }
+    }
+}
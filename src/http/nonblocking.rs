@@ -0,0 +1,365 @@
+//! Async network transport backed by `reqwest` (feature `http-client-async`).
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+
+use super::egress::is_private_ip;
+use super::resolve::{reject_private, PrivateAddressError};
+use super::transport::{
+    AsyncHttpTransport, HttpError, HttpErrorKind, HttpResponse, OutboundRequest, TransportFuture,
+    REQUEST_ID_HEADER,
+};
+use super::url::{Host, ParsedUrl};
+use super::HttpMethod;
+
+/// Default limit on response body size (10 MiB).
+const DEFAULT_MAX_BODY_LEN: usize = 10 * 1024 * 1024;
+
+/// Transport that performs real, non-blocking HTTP(S) requests.
+///
+/// Available with the `http-client-async` feature. It serves `PolicyHttp`'s
+/// `*_async` methods and must be driven by a Tokio runtime. Connections are
+/// pooled, so build one transport at startup and share it through an `Arc`.
+///
+/// # Security Properties
+///
+/// - **No redirects**: a redirect would send the request to a URL that was
+///   never verified, so 3xx responses are returned as-is
+/// - **No private addresses**: a host that resolves to a private, loopback or
+///   link-local address, or is one, fails with
+///   [`HttpErrorKind::PrivateAddress`], and the connection only uses the
+///   addresses that were checked. Call
+///   [`allow_private_networks`](Self::allow_private_networks) for internal
+///   services
+/// - **No proxies**: proxy settings from the environment are ignored, since a
+///   proxy would resolve the host itself
+/// - **Bounded bodies**: responses larger than the configured limit fail
+///   with [`HttpErrorKind::Io`] instead of exhausting memory
+/// - **Request-id propagation**: every request carries the
+///   [`REQUEST_ID_HEADER`] header
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::sync::Arc;
+/// use std::time::Duration;
+/// use policy_core::{AllowAuthenticated, AsyncTransport, Authorized, PolicyGate, Principal, RequestMeta};
+///
+/// let transport = Arc::new(AsyncTransport::new().with_timeout(Duration::from_secs(5)));
+///
+/// let meta = RequestMeta {
+///     request_id: "req-1".to_string(),
+///     principal: Some(Principal::new("u1", "Alice")),
+/// };
+/// let ctx = PolicyGate::new(meta)
+///     .with_authorizer(AllowAuthenticated)
+///     .with_async_http_transport(Arc::clone(&transport))
+///     .require(Authorized::for_action("http"))
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct AsyncTransport {
+    client: reqwest::Client,
+    timeout: Duration,
+    allow_private: bool,
+    max_body_len: usize,
+}
+
+impl AsyncTransport {
+    /// Creates a transport with a 30 second timeout and a 10 MiB body limit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the TLS backend cannot be initialized.
+    pub fn new() -> Self {
+        let timeout = Duration::from_secs(30);
+        Self {
+            client: Self::client(timeout, false),
+            timeout,
+            allow_private: false,
+            max_body_len: DEFAULT_MAX_BODY_LEN,
+        }
+    }
+
+    /// Sets the overall timeout for each request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the TLS backend cannot be initialized.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self.client = Self::client(timeout, self.allow_private);
+        self
+    }
+
+    /// Allows connections to private, loopback and link-local addresses.
+    ///
+    /// Only use this for trusted internal services or local test servers;
+    /// the gate's [`EgressPolicy`](crate::EgressPolicy) still applies.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the TLS backend cannot be initialized.
+    pub fn allow_private_networks(mut self) -> Self {
+        self.allow_private = true;
+        self.client = Self::client(self.timeout, true);
+        self
+    }
+
+    /// Sets the maximum accepted response body size in bytes.
+    pub fn with_max_body_len(mut self, max_body_len: usize) -> Self {
+        self.max_body_len = max_body_len;
+        self
+    }
+
+    fn client(timeout: Duration, allow_private: bool) -> reqwest::Client {
+        // BREAKING CHANGE WARNING: redirects MUST stay disabled. Following a redirect
+        // sends the request (and its body) to a URL that never passed verification.
+        let builder = reqwest::Client::builder()
+            .timeout(timeout)
+            .redirect(reqwest::redirect::Policy::none())
+            .no_proxy();
+        // BREAKING CHANGE WARNING: the resolver MUST check the addresses that are
+        // connected to. Checking a separate lookup lets DNS rebinding reach
+        // internal addresses (SSRF, CWE-918).
+        let builder = if allow_private {
+            builder
+        } else {
+            builder.dns_resolver(Arc::new(PublicResolver))
+        };
+        builder.build().expect("TLS backend should initialize")
+    }
+
+    async fn send(&self, request: &OutboundRequest<'_>) -> Result<HttpResponse, HttpError> {
+        // IP literals are connected to without consulting the resolver
+        if !self.allow_private && is_private_literal(request.url()) {
+            return Err(HttpError::new(HttpErrorKind::PrivateAddress));
+        }
+
+        let method = match request.method() {
+            HttpMethod::Get => reqwest::Method::GET,
+            HttpMethod::Post => reqwest::Method::POST,
+            HttpMethod::Put => reqwest::Method::PUT,
+            HttpMethod::Delete => reqwest::Method::DELETE,
+            HttpMethod::Patch => reqwest::Method::PATCH,
+        };
+        let mut call = self
+            .client
+            .request(method, request.url())
+            .header(REQUEST_ID_HEADER, request.request_id());
+        if let HttpMethod::Post | HttpMethod::Put | HttpMethod::Patch = request.method() {
+            call = call.body(request.body().to_string());
+        }
+
+        let mut response = call.send().await.map_err(|e| client_error(&e))?;
+        let status = response.status().as_u16();
+
+        let mut body = Vec::new();
+        while let Some(chunk) = response.chunk().await.map_err(|e| client_error(&e))? {
+            if body.len().saturating_add(chunk.len()) > self.max_body_len {
+                return Err(HttpError::with_message(
+                    HttpErrorKind::Io,
+                    format!("response body exceeds {} bytes", self.max_body_len),
+                ));
+            }
+            body.extend_from_slice(&chunk);
+        }
+
+        Ok(HttpResponse::new(status, body))
+    }
+}
+
+impl Default for AsyncTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AsyncHttpTransport for AsyncTransport {
+    fn send_async<'a>(&'a self, request: &'a OutboundRequest<'_>) -> TransportFuture<'a> {
+        Box::pin(self.send(request))
+    }
+}
+
+/// Resolver that refuses hosts resolving to private addresses.
+#[derive(Debug)]
+struct PublicResolver;
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let addrs = tokio::net::lookup_host((name.as_str(), 0)).await?.collect();
+            let addrs: Addrs = Box::new(reject_private(addrs)?.into_iter());
+            Ok(addrs)
+        })
+    }
+}
+
+/// Returns `true` if `url`'s host is a private IP literal.
+fn is_private_literal(url: &str) -> bool {
+    ParsedUrl::parse(url).is_some_and(|url| matches!(url.host, Host::Ip(ip) if is_private_ip(&ip)))
+}
+
+/// Maps a reqwest failure to an `HttpError` without echoing the URL.
+fn client_error(error: &reqwest::Error) -> HttpError {
+    let kind = if PrivateAddressError::is_cause_of(error) {
+        HttpErrorKind::PrivateAddress
+    } else if error.is_builder() {
+        HttpErrorKind::InvalidUrl
+    } else if error.is_timeout() {
+        HttpErrorKind::Timeout
+    } else if error.is_connect() {
+        HttpErrorKind::Connect
+    } else if error.is_decode() || error.is_redirect() {
+        HttpErrorKind::Protocol
+    } else {
+        HttpErrorKind::Io
+    };
+
+    HttpError::new(kind)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::future::Future;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    /// Serves one request on a loopback port and returns its head and body.
+    fn serve_once(response: &'static str) -> (String, thread::JoinHandle<(String, String)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/items", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut head = String::new();
+            let mut content_len = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+                if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                    content_len = value.trim().parse().unwrap();
+                }
+                head.push_str(&line);
+            }
+
+            let mut body = vec![0; content_len];
+            reader.read_exact(&mut body).unwrap();
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+
+            (head, String::from_utf8(body).unwrap())
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn sends_request_id_and_returns_tainted_body() {
+        let (url, server) =
+            serve_once("HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello");
+        let transport = AsyncTransport::new().allow_private_networks();
+
+        let request = OutboundRequest::new("req-loopback", HttpMethod::Post, &url, "payload");
+        let response = block_on(transport.send_async(&request)).unwrap();
+
+        assert_eq!(response.status(), 200);
+        assert_eq!(response.text().into_value().into_inner(), "hello");
+
+        let (head, body) = server.join().unwrap();
+        assert!(head.starts_with("POST /items HTTP/1.1"));
+        assert!(head
+            .to_ascii_lowercase()
+            .contains("x-request-id: req-loopback"));
+        assert_eq!(body, "payload");
+    }
+
+    #[test]
+    fn private_addresses_are_refused_by_default() {
+        let transport = AsyncTransport::new();
+
+        for url in [
+            "http://localhost:1/",
+            "http://127.0.0.1:1/",
+            "http://[::1]:1/",
+        ] {
+            let request = OutboundRequest::new("req-private", HttpMethod::Get, url, "");
+            let err = block_on(transport.send_async(&request)).unwrap_err();
+            assert_eq!(err.kind(), HttpErrorKind::PrivateAddress, "{}", url);
+        }
+    }
+
+    #[test]
+    fn redirects_are_not_followed() {
+        let (url, server) = serve_once(
+            "HTTP/1.1 302 Found\r\nLocation: http://127.0.0.1:1/\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        );
+        let transport = AsyncTransport::new().allow_private_networks();
+
+        let request = OutboundRequest::new("req-302", HttpMethod::Get, &url, "");
+        let response = block_on(transport.send_async(&request)).unwrap();
+
+        assert_eq!(response.status(), 302);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn oversized_bodies_are_rejected() {
+        let (url, server) =
+            serve_once("HTTP/1.1 200 OK\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello");
+        let transport = AsyncTransport::new()
+            .allow_private_networks()
+            .with_max_body_len(4);
+
+        let request = OutboundRequest::new("req-big", HttpMethod::Get, &url, "");
+        let err = block_on(transport.send_async(&request)).unwrap_err();
+
+        assert_eq!(err.kind(), HttpErrorKind::Io);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn connection_failures_map_to_connect() {
+        // Bind then drop to get a port with no listener
+        let addr = TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let url = format!("http://{}/", addr);
+        let transport = AsyncTransport::new()
+            .allow_private_networks()
+            .with_timeout(Duration::from_secs(2));
+
+        let request = OutboundRequest::new("req-down", HttpMethod::Get, &url, "");
+        let err = block_on(transport.send_async(&request)).unwrap_err();
+
+        assert_eq!(err.kind(), HttpErrorKind::Connect);
+    }
+
+    #[test]
+    fn invalid_urls_map_to_invalid_url() {
+        let transport = AsyncTransport::new().allow_private_networks();
+
+        let request = OutboundRequest::new("req-bad", HttpMethod::Get, "not a url", "");
+        let err = block_on(transport.send_async(&request)).unwrap_err();
+
+        assert_eq!(err.kind(), HttpErrorKind::InvalidUrl);
+    }
+}
@@ -0,0 +1,89 @@
+//! Resolution-time private-address checks shared by the built-in transports.
+//!
+//! [`EgressPolicy`](super::EgressPolicy) judges the host as written. A name
+//! it allows can still resolve to an internal address, so the transports
+//! check the resolved addresses too and connect only to those.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::net::SocketAddr;
+
+use super::egress::is_private_ip;
+
+/// Returns `addrs` if none of them is private.
+///
+/// # Errors
+///
+/// Returns [`PrivateAddressError`] if any address is private. The whole set
+/// is refused because a connection may fall back to any of them.
+pub(crate) fn reject_private(
+    addrs: Vec<SocketAddr>,
+) -> Result<Vec<SocketAddr>, PrivateAddressError> {
+    if addrs.iter().any(|addr| is_private_ip(&addr.ip())) {
+        return Err(PrivateAddressError);
+    }
+    Ok(addrs)
+}
+
+/// A host resolved to a private, loopback or link-local address.
+#[derive(Debug)]
+pub(crate) struct PrivateAddressError;
+
+impl PrivateAddressError {
+    /// Returns `true` if `error` or one of its sources is a
+    /// `PrivateAddressError`, including one wrapped in an `io::Error`.
+    pub(crate) fn is_cause_of(error: &(dyn Error + 'static)) -> bool {
+        let mut next = Some(error);
+        while let Some(error) = next {
+            let wrapped = error
+                .downcast_ref::<io::Error>()
+                .and_then(io::Error::get_ref);
+            if error.is::<Self>() || wrapped.is_some_and(|inner| inner.is::<Self>()) {
+                return true;
+            }
+            next = error.source();
+        }
+        false
+    }
+}
+
+impl fmt::Display for PrivateAddressError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("host resolves to a private address")
+    }
+}
+
+impl Error for PrivateAddressError {}
+
+impl From<PrivateAddressError> for io::Error {
+    fn from(error: PrivateAddressError) -> Self {
+        io::Error::new(io::ErrorKind::PermissionDenied, error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn private_addresses_are_refused_together() {
+        let public: SocketAddr = "93.184.216.34:443".parse().unwrap();
+        let metadata: SocketAddr = "169.254.169.254:443".parse().unwrap();
+        let mapped: SocketAddr = "[::ffff:10.0.0.1]:443".parse().unwrap();
+
+        assert_eq!(reject_private(vec![public]).unwrap(), [public]);
+        assert!(reject_private(vec![public, metadata]).is_err());
+        assert!(reject_private(vec![mapped]).is_err());
+    }
+
+    #[test]
+    fn cause_is_found_through_io_errors_and_sources() {
+        let io_error = io::Error::from(PrivateAddressError);
+        assert!(PrivateAddressError::is_cause_of(&io_error));
+        assert!(PrivateAddressError::is_cause_of(&PrivateAddressError));
+        assert!(!PrivateAddressError::is_cause_of(&io::Error::other(
+            "refused"
+        )));
+    }
+}
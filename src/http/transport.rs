@@ -0,0 +1,466 @@
+//! Pluggable transports that carry requests issued through `PolicyHttp`.
+//!
+//! `PolicyHttp` enforces the policy side (capability, verified URL and body,
+//! request-id propagation) and hands every request to an [`HttpTransport`],
+//! or to an [`AsyncHttpTransport`] for its `*_async` methods. The transport
+//! only moves bytes; it never sees tainted data.
+
+use std::fmt;
+use std::future::{self, Future};
+use std::pin::Pin;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use super::{HttpMethod, HttpRequest};
+use crate::audit::RedactedUrl;
+use crate::labels::{Labeled, ThirdPartySupplied};
+use crate::{Provenance, Tainted};
+
+/// Header used to propagate the request ID to downstream services.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// A request ready to be sent, built by `PolicyHttp` from verified values.
+///
+/// Transports receive this instead of raw strings. It cannot be constructed
+/// outside policy-core, so every `OutboundRequest` a transport sees has passed
+/// through a capability-gated `PolicyHttp`.
+#[derive(Debug)]
+pub struct OutboundRequest<'r> {
+    request_id: &'r str,
+    method: HttpMethod,
+    url: &'r str,
+    body: &'r str,
+}
+
+impl<'r> OutboundRequest<'r> {
+    /// Creates an outbound request.
+    ///
+    /// This is `pub(crate)` so transports only ever see requests built from
+    /// `Verified` values by `PolicyHttp`.
+    pub(crate) fn new(
+        request_id: &'r str,
+        method: HttpMethod,
+        url: &'r str,
+        body: &'r str,
+    ) -> Self {
+        Self {
+            request_id,
+            method,
+            url,
+            body,
+        }
+    }
+
+    /// Returns the request ID to send in the [`REQUEST_ID_HEADER`] header.
+    ///
+    /// Control characters are replaced with spaces by `PolicyHttp`, so the
+    /// value is safe to use as a header value.
+    pub fn request_id(&self) -> &str {
+        self.request_id
+    }
+
+    /// Returns the HTTP method.
+    pub fn method(&self) -> HttpMethod {
+        self.method
+    }
+
+    /// Returns the verified target URL.
+    pub fn url(&self) -> &str {
+        self.url
+    }
+
+    /// Returns the verified request body (empty for GET and DELETE).
+    pub fn body(&self) -> &str {
+        self.body
+    }
+}
+
+/// Response returned by an [`HttpTransport`].
+///
+/// The status code is trusted metadata. The body comes from an external
+/// system and is therefore only available as [`Tainted`] data carrying the
+/// [`ThirdPartySupplied`] information-flow label. Responses returned by
+/// [`PolicyHttp`](crate::PolicyHttp) also give the body an upstream
+/// [`Provenance`] naming the redacted request URL.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    status: u16,
+    body: Vec<u8>,
+    source: Option<Provenance>,
+}
+
+impl HttpResponse {
+    /// Creates a response with a status code and raw body.
+    pub fn new(status: u16, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            body: body.into(),
+            source: None,
+        }
+    }
+
+    /// Labels the body as coming from `url`, with query and credentials removed.
+    pub(crate) fn with_source(mut self, url: &str) -> Self {
+        self.source = Some(Provenance::upstream(RedactedUrl::from(url).as_str()));
+        self
+    }
+
+    /// Returns the HTTP status code.
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Returns `true` for 2xx status codes.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Returns the length of the response body in bytes.
+    pub fn body_len(&self) -> usize {
+        self.body.len()
+    }
+
+    /// Consumes the response and returns the body as tainted bytes.
+    pub fn into_body(self) -> Labeled<Tainted<Vec<u8>>, ThirdPartySupplied> {
+        Labeled::new(Tainted::from_parts(self.body, self.source))
+    }
+
+    /// Returns the body as tainted text, replacing invalid UTF-8 sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{HttpResponse, Sink, StringSanitizer, VecSink};
+    ///
+    /// let response = HttpResponse::new(200, "  ok  ");
+    ///
+    /// // The body is untrusted and must be sanitized before use
+    /// let text = response.text().sanitize(&StringSanitizer::new(16).unwrap()).unwrap();
+    /// assert_eq!(text.labels(), ["third_party"]);
+    ///
+    /// // Sinks that tolerate third-party data accept it
+    /// let sink = VecSink::new();
+    /// sink.sink(&text).unwrap();
+    /// assert_eq!(sink.into_vec(), ["ok"]);
+    /// ```
+    pub fn text(&self) -> Labeled<Tainted<String>, ThirdPartySupplied> {
+        let text = String::from_utf8_lossy(&self.body).into_owned();
+        Labeled::new(Tainted::from_parts(text, self.source.clone()))
+    }
+}
+
+/// Error returned when a transport fails to complete a request.
+///
+/// Messages describe the failure category only. They never include the
+/// request body.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{HttpError, HttpErrorKind};
+///
+/// let error = HttpError::new(HttpErrorKind::Timeout);
+/// assert_eq!(error.kind(), HttpErrorKind::Timeout);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpError {
+    kind: HttpErrorKind,
+    message: Option<String>,
+}
+
+impl HttpError {
+    /// Creates a new HTTP error with the specified kind.
+    pub fn new(kind: HttpErrorKind) -> Self {
+        Self {
+            kind,
+            message: None,
+        }
+    }
+
+    /// Creates a new HTTP error with a custom message.
+    pub fn with_message(kind: HttpErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: Some(message.into()),
+        }
+    }
+
+    /// Returns the error kind.
+    pub fn kind(&self) -> HttpErrorKind {
+        self.kind
+    }
+
+    /// Returns the error message, if any.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+impl fmt::Display for HttpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(msg) = &self.message {
+            write!(f, "http error ({}): {}", self.kind, msg)
+        } else {
+            write!(f, "http error ({})", self.kind)
+        }
+    }
+}
+
+impl std::error::Error for HttpError {}
+
+/// Kind of HTTP transport error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpErrorKind {
+    /// The URL could not be parsed or uses an unsupported scheme.
+    InvalidUrl,
+    /// The connection could not be established (DNS, refused, TLS).
+    Connect,
+    /// The request or response did not complete in time.
+    Timeout,
+    /// An I/O error occurred while sending or receiving.
+    Io,
+    /// The peer sent a malformed HTTP response.
+    Protocol,
+    /// The host resolved to a private, loopback or link-local address.
+    ///
+    /// `PolicyHttp` reports it as an egress denial.
+    PrivateAddress,
+}
+
+impl fmt::Display for HttpErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidUrl => write!(f, "invalid URL"),
+            Self::Connect => write!(f, "connection failed"),
+            Self::Timeout => write!(f, "timed out"),
+            Self::Io => write!(f, "I/O error"),
+            Self::Protocol => write!(f, "protocol error"),
+            Self::PrivateAddress => write!(f, "private address"),
+        }
+    }
+}
+
+/// Carries requests issued through `PolicyHttp`.
+///
+/// Implementations must be `Send + Sync` so one transport (and its connection
+/// pool) can be shared by every request handler. Configure it on the gate with
+/// [`PolicyGate::with_http_transport`](crate::PolicyGate::with_http_transport).
+///
+/// # Security Considerations
+///
+/// - **Send the request ID**: forward [`OutboundRequest::request_id`] in the
+///   [`REQUEST_ID_HEADER`] header so downstream logs correlate
+/// - **Do not log bodies**: the request body may contain personal data
+/// - **Return bodies untouched**: `PolicyHttp` hands them out as `Tainted`
+/// - **Check resolved addresses**: refuse hosts resolving to private
+///   addresses with [`HttpErrorKind::PrivateAddress`], connecting only to
+///   the addresses that were checked
+pub trait HttpTransport: Send + Sync + fmt::Debug {
+    /// Sends a request and returns the response.
+    ///
+    /// Non-2xx statuses are responses, not errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns `HttpError` if the request could not be completed.
+    fn send(&self, request: &OutboundRequest<'_>) -> Result<HttpResponse, HttpError>;
+}
+
+impl<T: HttpTransport + ?Sized> HttpTransport for std::sync::Arc<T> {
+    fn send(&self, request: &OutboundRequest<'_>) -> Result<HttpResponse, HttpError> {
+        (**self).send(request)
+    }
+}
+
+/// Future returned by [`AsyncHttpTransport::send_async`].
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<HttpResponse, HttpError>> + Send + 'a>>;
+
+/// Carries requests issued through `PolicyHttp`'s `*_async` methods.
+///
+/// The async counterpart of [`HttpTransport`], with the same security
+/// considerations. Configure it on the gate with
+/// [`PolicyGate::with_async_http_transport`](crate::PolicyGate::with_async_http_transport).
+/// The returned future must be `Send` so handlers can run on multi-threaded
+/// executors.
+pub trait AsyncHttpTransport: Send + Sync + fmt::Debug {
+    /// Sends a request and resolves to the response.
+    ///
+    /// Non-2xx statuses are responses, not errors.
+    ///
+    /// # Errors
+    ///
+    /// Resolves to `HttpError` if the request could not be completed.
+    fn send_async<'a>(&'a self, request: &'a OutboundRequest<'_>) -> TransportFuture<'a>;
+}
+
+impl<T: AsyncHttpTransport + ?Sized> AsyncHttpTransport for std::sync::Arc<T> {
+    fn send_async<'a>(&'a self, request: &'a OutboundRequest<'_>) -> TransportFuture<'a> {
+        (**self).send_async(request)
+    }
+}
+
+/// Transport that records requests instead of sending them.
+///
+/// Every request is stored as [`HttpRequest`] metadata (never the body) and
+/// answered with a fixed response, `200` with an empty body by default. This
+/// is what `PolicyHttp` uses when no transport is configured, which keeps
+/// tests deterministic and offline. It implements both [`HttpTransport`] and
+/// [`AsyncHttpTransport`]; async sends complete immediately.
+///
+/// Share one `RecordingTransport` through an `Arc` to inspect the requests
+/// issued by every context built from a gate.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use policy_core::{AllowAuthenticated, Authorized, PolicyGate, Principal, RecordingTransport, RequestMeta};
+/// # use policy_core::{Sanitizer, Tainted, UrlSanitizer};
+///
+/// let transport = Arc::new(RecordingTransport::new());
+///
+/// let meta = RequestMeta {
+///     request_id: "req-1".to_string(),
+///     principal: Some(Principal::new("u1", "Alice")),
+/// };
+/// let ctx = PolicyGate::new(meta)
+///     .with_authorizer(AllowAuthenticated)
+///     .with_http_transport(Arc::clone(&transport))
+///     .require(Authorized::for_action("http"))
+///     .build()
+///     .unwrap();
+///
+/// # let url = UrlSanitizer::new()
+/// #     .sanitize(Tainted::new("https://api.example.com".to_string())).unwrap();
+/// ctx.http().unwrap().get(&url).unwrap();
+///
+/// assert_eq!(transport.request_count(), 1);
+/// assert_eq!(transport.requests()[0].request_id, "req-1");
+/// ```
+#[derive(Debug)]
+pub struct RecordingTransport {
+    requests: Mutex<Vec<HttpRequest>>,
+    response: HttpResponse,
+}
+
+impl RecordingTransport {
+    /// Creates a transport that answers every request with an empty `200`.
+    pub fn new() -> Self {
+        Self::with_response(HttpResponse::new(200, Vec::new()))
+    }
+
+    /// Creates a transport that answers every request with `response`.
+    pub fn with_response(response: HttpResponse) -> Self {
+        Self {
+            requests: Mutex::new(Vec::new()),
+            response,
+        }
+    }
+
+    /// Returns the number of recorded requests.
+    pub fn request_count(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns a snapshot of the recorded requests.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.lock().clone()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<HttpRequest>> {
+        // Recorded metadata stays consistent even if a holder panicked.
+        self.requests.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Default for RecordingTransport {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HttpTransport for RecordingTransport {
+    fn send(&self, request: &OutboundRequest<'_>) -> Result<HttpResponse, HttpError> {
+        self.lock().push(HttpRequest::from_outbound(request));
+        Ok(self.response.clone())
+    }
+}
+
+impl AsyncHttpTransport for RecordingTransport {
+    fn send_async<'a>(&'a self, request: &'a OutboundRequest<'_>) -> TransportFuture<'a> {
+        Box::pin(future::ready(self.send(request)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recording_transport_records_metadata_only() {
+        let transport = RecordingTransport::new();
+        let request = OutboundRequest::new("req-1", HttpMethod::Post, "https://a.test", "secret");
+
+        let response = transport.send(&request).unwrap();
+
+        assert_eq!(response.status(), 200);
+        assert_eq!(transport.request_count(), 1);
+        let recorded = &transport.requests()[0];
+        assert_eq!(recorded.request_id, "req-1");
+        assert_eq!(recorded.body_len, 6);
+        assert!(!format!("{:?}", recorded).contains("secret"));
+    }
+
+    #[test]
+    fn recording_transport_records_async_sends() {
+        let transport = RecordingTransport::with_response(HttpResponse::new(201, ""));
+        let request = OutboundRequest::new("req-3", HttpMethod::Put, "https://a.test", "x");
+
+        let waker = std::task::Waker::noop();
+        let mut cx = std::task::Context::from_waker(waker);
+        let mut future = transport.send_async(&request);
+        let std::task::Poll::Ready(response) = future.as_mut().poll(&mut cx) else {
+            panic!("recording sends complete immediately");
+        };
+
+        assert_eq!(response.unwrap().status(), 201);
+        assert_eq!(transport.requests()[0].method, HttpMethod::Put);
+    }
+
+    #[test]
+    fn recording_transport_returns_configured_response() {
+        let transport = RecordingTransport::with_response(HttpResponse::new(404, "missing"));
+        let request = OutboundRequest::new("req-2", HttpMethod::Get, "https://a.test", "");
+
+        let response = transport.send(&request).unwrap();
+
+        assert_eq!(response.status(), 404);
+        assert!(!response.is_success());
+        assert_eq!(response.body_len(), 7);
+    }
+
+    #[test]
+    fn response_body_is_tainted() {
+        let response = HttpResponse::new(200, vec![0x68, 0x69, 0xff]);
+        assert_eq!(response.text().labels(), ["third_party"]);
+
+        let text = response.text().into_value().into_inner();
+        assert_eq!(text, "hi\u{fffd}");
+        assert_eq!(
+            response.into_body().into_value().into_inner(),
+            vec![0x68, 0x69, 0xff]
+        );
+    }
+
+    #[test]
+    fn http_error_display() {
+        assert_eq!(
+            HttpError::new(HttpErrorKind::Connect).to_string(),
+            "http error (connection failed)"
+        );
+        assert_eq!(
+            HttpError::with_message(HttpErrorKind::Timeout, "after 5s").to_string(),
+            "http error (timed out): after 5s"
+        );
+    }
+}
@@ -0,0 +1,502 @@
+//! Information-flow labels tracked at the type level.
+//!
+//! `Tainted` and `Verified` answer one question: has this value been checked?
+//! Labels answer a different one: what kind of data is this? A value can be
+//! user-supplied, supplied by a third-party API, personal data (PII) or
+//! financial data, in any combination. [`Labeled<T, L>`] carries its label
+//! set `L` in its type:
+//!
+//! - Input sources label what they hand out: values read from
+//!   [`TaintedInputs`](crate::web::TaintedInputs) are [`UserSupplied`], and
+//!   response bodies from [`HttpResponse`](crate::HttpResponse) are
+//!   [`ThirdPartySupplied`].
+//! - Combining labeled values joins their label sets, so the result of
+//!   [`Labeled::zip`] or [`Labeled::combine`] carries every label of its inputs.
+//! - Labels can be added with [`Labeled::add_labels`] but never silently
+//!   removed. The one way out is
+//!   [`PolicyDeclassify::unlabel`](crate::PolicyDeclassify::unlabel), which
+//!   requires a [`DeclassifyCap`](crate::DeclassifyCap) and audits a reason.
+//! - Every [`Sink`](crate::Sink) states the labels it tolerates in its
+//!   `Labels` type, and [`Sink::sink`](crate::Sink::sink) only accepts values
+//!   whose labels are all tolerated. Unlabeled `Verified` values count as
+//!   [`Unlabeled`].
+//!
+//! Label sets are written with the aliases [`Unlabeled`], [`UserSupplied`],
+//! [`ThirdPartySupplied`], [`PersonalData`] and [`FinancialData`], combined
+//! with [`Join`].
+//!
+//! # Examples
+//!
+//! An analytics sink that accepts user-supplied data but not PII:
+//!
+//! ```
+//! use policy_core::contexts::Plain;
+//! use policy_core::labels::UserSupplied;
+//! use policy_core::web::{ExtractTaintedInputs, RequestAdapter};
+//! use policy_core::{IdentifierSanitizer, LabelsChecked, Sink, SinkError, Verified};
+//!
+//! struct Analytics;
+//!
+//! impl Sink<String> for Analytics {
+//!     type Context = Plain;
+//!     type Labels = UserSupplied;
+//!
+//!     fn write(&self, _value: &Verified<String>, _: LabelsChecked) -> Result<(), SinkError> {
+//!         Ok(())
+//!     }
+//! }
+//!
+//! let mut request = RequestAdapter::new("req-1".to_string());
+//! request.add_query_param("page".to_string(), "pricing".to_string());
+//! let inputs = request.extract_tainted_inputs();
+//!
+//! // Query parameters are labeled `UserSupplied`
+//! let ids = IdentifierSanitizer::new(32).unwrap();
+//! let page = inputs.get_query("page").unwrap().sanitize(&ids).unwrap();
+//!
+//! Analytics.sink(&page).unwrap();
+//! ```
+//!
+//! Once a value is joined with PII, the same sink rejects it at compile time:
+//!
+//! ```compile_fail
+//! # use policy_core::contexts::Plain;
+//! # use policy_core::labels::{PersonalData, UserSupplied};
+//! # use policy_core::web::{ExtractTaintedInputs, RequestAdapter};
+//! # use policy_core::{IdentifierSanitizer, LabelsChecked, Sink, SinkError, Verified};
+//! # struct Analytics;
+//! # impl Sink<String> for Analytics {
+//! #     type Context = Plain;
+//! #     type Labels = UserSupplied;
+//! #     fn write(&self, _value: &Verified<String>, _: LabelsChecked) -> Result<(), SinkError> {
+//! #         Ok(())
+//! #     }
+//! # }
+//! # let mut request = RequestAdapter::new("req-1".to_string());
+//! # request.add_query_param("page".to_string(), "pricing".to_string());
+//! # let inputs = request.extract_tainted_inputs();
+//! # let ids = IdentifierSanitizer::new(32).unwrap();
+//! let page = inputs.get_query("page").unwrap().sanitize(&ids).unwrap();
+//!
+//! let tagged = page.add_labels::<PersonalData>();
+//! Analytics.sink(&tagged); // Error: `Pii` is not tolerated
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::{Provenance, SanitizationError, Sanitizer, Tainted, Verified};
+
+mod sealed {
+    // BREAKING CHANGE WARNING: This module MUST remain private.
+    // `Within` decides which labeled values a sink accepts. A user-implementable
+    // flag or label set could claim that PII is contained in a set without it,
+    // letting labeled data reach sinks that do not tolerate it.
+    pub trait Sealed {}
+    pub trait SealedFlag<M> {}
+}
+
+/// Marker trait for a single information-flow label.
+///
+/// This trait is sealed and cannot be implemented outside policy-core.
+pub trait Label: sealed::Sealed + 'static {
+    /// Short name of the label, used in `Debug` output.
+    const NAME: &'static str;
+}
+
+macro_rules! labels {
+    ($($(#[$doc:meta])* $name:ident => $label:literal),* $(,)?) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug)]
+            pub enum $name {}
+
+            impl sealed::Sealed for $name {}
+
+            impl Label for $name {
+                const NAME: &'static str = $label;
+            }
+
+            impl sealed::SealedFlag<$name> for $name {}
+
+            impl Flag<$name> for $name {
+                const SET: bool = true;
+                type Or<G: Flag<$name>> = $name;
+            }
+
+            impl Le<$name> for $name {}
+        )*
+    };
+}
+
+labels! {
+    /// Supplied by the user making the request.
+    User => "user",
+    /// Supplied by a third-party API.
+    ThirdParty => "third_party",
+    /// Personally identifiable information.
+    Pii => "pii",
+    /// Financial data such as account numbers or balances.
+    Financial => "financial",
+}
+
+/// Marks a label as absent from a [`Labels`] set.
+#[derive(Debug)]
+pub enum No {}
+
+/// Presence of label `M` at its position in a [`Labels`] set: either `M`
+/// itself or [`No`].
+///
+/// This trait is sealed and cannot be implemented outside policy-core.
+pub trait Flag<M: Label>: sealed::SealedFlag<M> + 'static {
+    /// Whether the label is present.
+    const SET: bool;
+
+    /// The flag of the union of two sets at this position.
+    type Or<G: Flag<M>>: Flag<M>;
+}
+
+impl<M: Label> sealed::SealedFlag<M> for No {}
+
+impl<M: Label> Flag<M> for No {
+    const SET: bool = false;
+    type Or<G: Flag<M>> = G;
+}
+
+/// Proof that a flag is contained in another: absent labels are contained
+/// in anything, present labels only in themselves.
+#[diagnostic::on_unimplemented(
+    message = "label `{Self}` is not tolerated here",
+    label = "a value labeled `{Self}` cannot flow into this sink",
+    note = "sinks only accept values whose labels are all part of their `Sink::Labels`"
+)]
+pub trait Le<G> {}
+
+impl<G> Le<G> for No {}
+
+/// A set of labels, one position per label.
+///
+/// Use the aliases [`Unlabeled`], [`UserSupplied`], [`ThirdPartySupplied`],
+/// [`PersonalData`] and [`FinancialData`] and combine them with [`Join`]
+/// rather than naming this type directly.
+pub struct Labels<U, T, P, F> {
+    _flags: PhantomData<fn(U, T, P, F)>,
+}
+
+/// A set of labels.
+///
+/// This trait is sealed and implemented only for [`Labels`].
+pub trait LabelSet: sealed::Sealed + 'static {
+    #[doc(hidden)]
+    type User: Flag<User>;
+    #[doc(hidden)]
+    type ThirdParty: Flag<ThirdParty>;
+    #[doc(hidden)]
+    type Pii: Flag<Pii>;
+    #[doc(hidden)]
+    type Financial: Flag<Financial>;
+
+    /// The union of this set and `Other`.
+    type Join<Other: LabelSet>: LabelSet;
+
+    /// Returns the names of the labels in this set.
+    fn names() -> Vec<&'static str> {
+        [
+            (<Self::User as Flag<User>>::SET, User::NAME),
+            (
+                <Self::ThirdParty as Flag<ThirdParty>>::SET,
+                ThirdParty::NAME,
+            ),
+            (<Self::Pii as Flag<Pii>>::SET, Pii::NAME),
+            (<Self::Financial as Flag<Financial>>::SET, Financial::NAME),
+        ]
+        .into_iter()
+        .filter_map(|(set, name)| set.then_some(name))
+        .collect()
+    }
+}
+
+impl<U, T, P, F> sealed::Sealed for Labels<U, T, P, F>
+where
+    U: Flag<User>,
+    T: Flag<ThirdParty>,
+    P: Flag<Pii>,
+    F: Flag<Financial>,
+{
+}
+
+impl<U, T, P, F> LabelSet for Labels<U, T, P, F>
+where
+    U: Flag<User>,
+    T: Flag<ThirdParty>,
+    P: Flag<Pii>,
+    F: Flag<Financial>,
+{
+    type User = U;
+    type ThirdParty = T;
+    type Pii = P;
+    type Financial = F;
+
+    type Join<Other: LabelSet> = Labels<
+        U::Or<Other::User>,
+        T::Or<Other::ThirdParty>,
+        P::Or<Other::Pii>,
+        F::Or<Other::Financial>,
+    >;
+}
+
+/// Proof that every label of `Self` is also in `A`.
+///
+/// Implemented for every pair of label sets where this holds; it cannot be
+/// implemented by hand.
+pub trait Within<A: LabelSet>: LabelSet {}
+
+impl<L, A> Within<A> for L
+where
+    L: LabelSet,
+    A: LabelSet,
+    L::User: Le<A::User>,
+    L::ThirdParty: Le<A::ThirdParty>,
+    L::Pii: Le<A::Pii>,
+    L::Financial: Le<A::Financial>,
+{
+}
+
+/// No labels.
+pub type Unlabeled = Labels<No, No, No, No>;
+/// Supplied by the user making the request.
+pub type UserSupplied = Labels<User, No, No, No>;
+/// Supplied by a third-party API.
+pub type ThirdPartySupplied = Labels<No, ThirdParty, No, No>;
+/// Personally identifiable information.
+pub type PersonalData = Labels<No, No, Pii, No>;
+/// Financial data.
+pub type FinancialData = Labels<No, No, No, Financial>;
+/// The union of two label sets, e.g. `Join<UserSupplied, PersonalData>`.
+pub type Join<A, B> = <A as LabelSet>::Join<B>;
+
+/// A value tagged with the label set `L`.
+///
+/// `T` is usually a [`Tainted`] value on its way to a sanitizer, or a
+/// [`Verified`] value on its way to a sink. The value is only reachable
+/// through label-preserving operations, a [`Sink`](crate::Sink) that
+/// tolerates `L`, or the audited
+/// [`PolicyDeclassify::unlabel`](crate::PolicyDeclassify::unlabel).
+///
+/// Labels only grow: a value starts with the labels of its source, or with
+/// none from [`unlabeled`](Self::unlabeled), and
+/// [`add_labels`](Self::add_labels) adds more.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::labels::{FinancialData, Join, Labeled, PersonalData, UserSupplied};
+///
+/// let name = Labeled::unlabeled("Ann".to_string()).add_labels::<Join<UserSupplied, PersonalData>>();
+/// let balance = Labeled::unlabeled(1200u32).add_labels::<FinancialData>();
+///
+/// // The combined value carries all three labels
+/// let summary = name.combine(balance, |name, balance| format!("{name}: {balance}"));
+/// assert_eq!(summary.labels(), ["user", "pii", "financial"]);
+/// ```
+// BREAKING CHANGE WARNING: Do NOT add Deref, AsRef, From<T>, Into<T>, a public
+// constructor taking a label set, or a method that returns the value with fewer
+// labels than `L`. PolicyDeclassify::unlabel is the only audited way out (CWE-200).
+pub struct Labeled<T, L = Unlabeled> {
+    // BREAKING CHANGE WARNING: This field MUST remain private.
+    value: T,
+    _labels: PhantomData<fn() -> L>,
+}
+
+impl<T> Labeled<T, Unlabeled> {
+    /// Wraps a value with no labels.
+    ///
+    /// Use [`add_labels`](Self::add_labels) to classify it, for example as
+    /// [`PersonalData`].
+    pub fn unlabeled(value: T) -> Self {
+        Self::new(value)
+    }
+
+    /// Returns the value. Nothing is lost, since it carries no labels.
+    ///
+    /// Labeled values have no such method:
+    ///
+    /// ```compile_fail
+    /// use policy_core::web::{ExtractTaintedInputs, RequestAdapter};
+    ///
+    /// let mut request = RequestAdapter::new("req-1".to_string());
+    /// request.add_query_param("q".to_string(), "rust".to_string());
+    /// let q = request.extract_tainted_inputs().get_query("q").unwrap();
+    ///
+    /// let raw = q.into_inner(); // Error: `q` is labeled `UserSupplied`
+    /// ```
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T, L: LabelSet> Labeled<T, L> {
+    /// Tags `value` with the labels `L`.
+    ///
+    /// This is `pub(crate)` so that only input sources choose the initial
+    /// labels; everyone else starts from [`unlabeled`](Self::unlabeled).
+    pub(crate) fn new(value: T) -> Self {
+        Self {
+            value,
+            _labels: PhantomData,
+        }
+    }
+
+    /// Returns the names of the labels on this value.
+    pub fn labels(&self) -> Vec<&'static str> {
+        L::names()
+    }
+
+    /// Adds the labels in `M`.
+    pub fn add_labels<M: LabelSet>(self) -> Labeled<T, Join<L, M>> {
+        Labeled::new(self.value)
+    }
+
+    /// Applies `f` to the value, keeping its labels.
+    ///
+    /// Like the transformations on [`Tainted`], `f` is a function pointer,
+    /// so it cannot capture local variables. It can still write the value
+    /// anywhere a `fn` can reach, such as a static or a file; the labels
+    /// only follow the value returned here.
+    pub fn map<U>(self, f: fn(T) -> U) -> Labeled<U, L> {
+        Labeled::new(f(self.value))
+    }
+
+    /// Pairs two labeled values; the pair carries the labels of both.
+    pub fn zip<U, M: LabelSet>(self, other: Labeled<U, M>) -> Labeled<(T, U), Join<L, M>> {
+        Labeled::new((self.value, other.value))
+    }
+
+    /// Combines two labeled values with `f`; the result carries the labels
+    /// of both.
+    pub fn combine<U, V, M: LabelSet>(
+        self,
+        other: Labeled<U, M>,
+        f: fn(T, U) -> V,
+    ) -> Labeled<V, Join<L, M>> {
+        Labeled::new(f(self.value, other.value))
+    }
+
+    /// Borrows the value for a sink or declassifier that has checked the labels.
+    pub(crate) fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the value for a declassifier that has audited the removal.
+    pub(crate) fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T, L: LabelSet> Labeled<Tainted<T>, L> {
+    /// Sanitizes the tainted value, keeping its labels.
+    ///
+    /// # Errors
+    ///
+    /// Returns the sanitizer's error if the value is rejected.
+    pub fn sanitize<U, S>(
+        self,
+        sanitizer: &S,
+    ) -> Result<Labeled<Verified<U, S::Context>, L>, SanitizationError>
+    where
+        S: Sanitizer<T, U>,
+    {
+        sanitizer.sanitize(self.value).map(Labeled::new)
+    }
+
+    /// Returns the provenance of the tainted value, if it has one.
+    pub fn provenance(&self) -> Option<&Provenance> {
+        self.value.provenance()
+    }
+}
+
+// Manual impls so the uninhabited label markers need not implement these traits.
+
+impl<T: Clone, L: LabelSet> Clone for Labeled<T, L> {
+    fn clone(&self) -> Self {
+        Self::new(self.value.clone())
+    }
+}
+
+impl<T, L: LabelSet> fmt::Debug for Labeled<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // SECURITY: labeled data is PII or financial data often enough that the
+        // value is never printed, even when its type's Debug would show it.
+        f.debug_struct("Labeled")
+            .field("labels", &L::names())
+            .field("value", &"<redacted>")
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Sink, StringSanitizer, VecSink};
+
+    fn is_within<L: Within<A>, A: LabelSet>() {}
+
+    #[test]
+    fn join_is_a_union() {
+        type Both = Join<UserSupplied, PersonalData>;
+        assert_eq!(<Both as LabelSet>::names(), ["user", "pii"]);
+        assert_eq!(
+            <Join<Both, UserSupplied> as LabelSet>::names(),
+            ["user", "pii"]
+        );
+        assert!(<Join<Unlabeled, Unlabeled> as LabelSet>::names().is_empty());
+    }
+
+    #[test]
+    fn within_is_subset() {
+        is_within::<Unlabeled, Unlabeled>();
+        is_within::<Unlabeled, PersonalData>();
+        is_within::<UserSupplied, Join<UserSupplied, ThirdPartySupplied>>();
+        is_within::<Join<PersonalData, FinancialData>, Join<FinancialData, PersonalData>>();
+    }
+
+    #[test]
+    fn combining_joins_labels() {
+        let a = Labeled::<_, UserSupplied>::new(2);
+        let b = Labeled::<_, ThirdPartySupplied>::new(3);
+
+        let sum = a.clone().combine(b.clone(), |a, b| a + b);
+        assert_eq!(sum.labels(), ["user", "third_party"]);
+        assert_eq!(sum.into_value(), 5);
+
+        let pair = a.zip(b.add_labels::<FinancialData>());
+        assert_eq!(pair.labels(), ["user", "third_party", "financial"]);
+    }
+
+    #[test]
+    fn sanitize_keeps_labels_and_sink_accepts_tolerated_values() {
+        let sink = VecSink::new();
+        let input = Labeled::<_, UserSupplied>::new(Tainted::new("  hi  ".to_string()));
+
+        let verified = input.sanitize(&StringSanitizer::new(16).unwrap()).unwrap();
+        assert_eq!(verified.labels(), ["user"]);
+
+        sink.sink(&verified).unwrap();
+        assert_eq!(sink.into_vec(), ["hi"]);
+    }
+
+    #[test]
+    fn unlabeled_values_unwrap_without_declassification() {
+        let value = Labeled::unlabeled(7);
+        assert!(value.labels().is_empty());
+        assert_eq!(value.into_inner(), 7);
+    }
+
+    #[test]
+    fn debug_redacts_the_value() {
+        let labeled = Labeled::<_, PersonalData>::new("ann@example.com".to_string());
+        let debug = format!("{:?}", labeled);
+        assert!(debug.contains("pii"));
+        assert!(!debug.contains("ann@example.com"));
+    }
+}
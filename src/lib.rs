@@ -7,19 +7,41 @@
 //!
 //! # Core Types
 //!
-//! - [`Secret<T>`]: Wrapper that redacts sensitive values in logs/output
-//! - [`Tainted<T>`]: Wrapper for untrusted data requiring sanitization
-//! - [`Verified<T>`]: Wrapper for validated/sanitized data safe to use
+//! - [`Secret<T>`]: Wrapper that redacts sensitive values in logs/output, wipes them on drop and compares them in constant time
+//! - [`ExpiringSecret<T>`] / [`RotatingSecret<T>`]: Secrets with a deadline, refreshed from a callback, timed by a pluggable [`Clock`]
+//! - [`Tainted<T>`]: Wrapper for untrusted data requiring sanitization, labeled with its [`Provenance`]
+//! - [`Verified<T>`]: Wrapper for validated/sanitized data, tagged with the output [`contexts`] it is safe for and the [`Approval`] of the sanitizer that accepted it
 //! - [`Sanitizer<T>`]: Trait for sanitizing tainted values into verified values
 //! - [`Sink<T>`]: Trait for operations that accept only verified values
-//! - [`Ctx`]: Validated execution context holding capabilities
+//! - [`Labeled<T, L>`](labels::Labeled): Value tagged with information-flow [`labels`] (user-supplied, third-party, PII, financial) that sinks check at compile time
+//! - [`Validation`]: Sanitizes many named inputs and reports every failing field
+//! - [`Ctx`]: Validated execution context holding capabilities, optionally typed by a [`capset`]
 //! - [`LogCap`]: Capability proving authorization for logging operations
+//! - [`DeclassifyCap`]: Capability for reading a [`Secret`] through the audited [`PolicyDeclassify`] path
+//! - [`Capability`]: Trait for action-bound capabilities, declared with [`capability!`]
 //! - [`PolicyGate`]: Builder for validating policies and creating contexts
+//! - [`Authorizer`]: Pluggable decision point consulted for `Authorized` requirements
+//! - [`HttpTransport`]: Pluggable transport that carries `PolicyHttp` requests
+//! - [`SecretStore`]: Pluggable source of named secrets, read through a [`SecretsCap`]-gated [`PolicySecrets`]
+//!
+//! # Feature Flags
+//!
+//! - `macros`: Enables `#[require(...)]`, an attribute that generates the
+//!   `extract_authed` → `PolicyGate` → `build()` sequence for web handlers
+//!   and `#[derive(Sanitize)]`, which sanitizes a whole struct from a
+//!   request's tainted inputs
+//! - `http-client`: Enables `BlockingTransport`, which performs real HTTP(S)
+//!   requests for `PolicyHttp`
+//! - `http-client-async`: Enables `AsyncTransport`, which performs real
+//!   HTTP(S) requests for `PolicyHttp`'s `*_async` methods on a Tokio runtime
+//! - `serde`: Implements `Serialize` and `Deserialize` for [`Secret`];
+//!   secrets deserialize normally but serialize as `"[REDACTED]"` unless a
+//!   field opts in with `dangerously_expose_secret`
 //!
 //! # Examples
 //!
 //! ```
-//! use policy_core::{Secret, PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
+//! use policy_core::{AllowAuthenticated, Secret, PolicyGate, RequestMeta, Principal, Authenticated, Authorized};
 //!
 //! // Secrets are automatically redacted
 //! let api_key = Secret::new("super-secret-key".to_string());
@@ -28,13 +50,11 @@
 //! // PolicyGate enforces requirements
 //! let meta = RequestMeta {
 //!     request_id: "req-123".to_string(),
-//!     principal: Some(Principal {
-//!         id: "user-1".to_string(),
-//!         name: "Alice".to_string(),
-//!     }),
+//!     principal: Some(Principal::new("user-1", "Alice")),
 //! };
 //!
 //! let ctx = PolicyGate::new(meta)
+//!     .with_authorizer(AllowAuthenticated)
 //!     .require(Authenticated)
 //!     .require(Authorized::for_action("log"))
 //!     .build()
@@ -44,42 +64,100 @@
 #![forbid(unsafe_code)]
 #![deny(missing_docs)]
 
+mod approval;
 pub mod audit;
+mod authorizer;
 mod capability;
+pub mod capset;
+mod clock;
 mod context;
+pub mod contexts;
 mod demo;
 mod error;
 mod gate;
 mod http;
+pub mod labels;
 mod logging;
 mod policy;
+mod provenance;
 mod request;
 mod sanitizer;
 mod secret;
+mod secrets;
 mod sink;
 mod state;
 mod tainted;
+mod validation;
 mod verified;
 pub mod web;
 
-pub use capability::{log_with_capability, HttpCap, LogCap};
+pub use approval::Approval;
+pub use authorizer::{AllowAuthenticated, Authorizer, DenyAll};
+pub use capability::{log_with_capability, Capability, Grant, HttpCap, LogCap};
+pub use capset::{Dynamic, Grants};
+pub use clock::{Clock, ManualClock, SystemClock};
 pub use context::Ctx;
 pub use error::{Error, Violation, ViolationKind};
 pub use gate::PolicyGate;
-pub use http::{HttpMethod, HttpRequest, PolicyHttp};
+#[cfg(feature = "http-client-async")]
+pub use http::AsyncTransport;
+#[cfg(feature = "http-client")]
+pub use http::BlockingTransport;
+pub use http::{
+    AsyncHttpTransport, EgressDenial, EgressPolicy, HttpError, HttpErrorKind, HttpMethod,
+    HttpRequest, HttpResponse, HttpTransport, HttpUrl, OutboundRequest, PolicyHttp,
+    RecordingTransport, TransportFuture, Url, REQUEST_ID_HEADER,
+};
 pub use logging::PolicyLog;
-pub use policy::{actions, Authenticated, Authorized};
-pub use request::{Principal, RequestMeta};
-pub use sanitizer::{SanitizationError, SanitizationErrorKind, Sanitizer, StringSanitizer};
+pub use policy::{actions, Authenticated, Authorized, HasRole, HasScope};
+pub use provenance::{InputSource, Provenance};
+pub use request::{Principal, RequestMeta, Role, Scope};
+pub use sanitizer::{
+    AndThen, EmailSanitizer, EnumSanitizer, Fields, HtmlAttributeEncoder, HtmlEncoder,
+    HttpBodySanitizer, IdentifierSanitizer, IntegerSanitizer, JsonStringEncoder, Map,
+    Normalization, Or, PathSegmentEncoder, PatternSanitizer, QueryValueEncoder, SanitizationError,
+    SanitizationErrorKind, Sanitizer, ShellQuoter, StringSanitizer, StructSanitizer, Timestamp,
+    TimestampSanitizer, UrlSanitizer, UuidSanitizer, VecSanitizer, VerifiedParts,
+};
 
 // Test-only sanitizers (issue #83: AcceptAllSanitizer is publicly accessible)
 #[cfg(test)]
 pub use sanitizer::{AcceptAllSanitizer, RejectAllSanitizer};
-pub use secret::Secret;
-pub use sink::{Sink, SinkError, SinkErrorKind, VecSink};
+#[cfg(feature = "serde")]
+pub use secret::dangerously_expose_secret;
+pub use secret::{DeclassifyCap, ExpiringSecret, PolicyDeclassify, RotatingSecret, Secret};
+pub use secrets::{
+    DirSecretStore, EnvSecretStore, PolicySecrets, SecretError, SecretErrorKind, SecretRequest,
+    SecretStore, SecretsCap,
+};
+
+pub use sink::{LabelsChecked, Sink, SinkError, SinkErrorKind, SinkInput, VecSink};
 pub use state::{Authed, Authorized as AuthorizedState, Unauthed};
 pub use tainted::Tainted;
+pub use validation::{FieldError, FieldValues, Sanitize, Validation, ValidationErrors};
 pub use verified::Verified;
+/// Trait for values that can be securely wiped; required of values held in a [`Secret`].
+///
+/// Re-exported from the `zeroize` crate so custom secret types can implement it.
+pub use zeroize::Zeroize;
+
+/// Attribute macro declaring a web handler's policy requirements.
+///
+/// Requires the `macros` feature. See the [`web`] module for the flow it generates.
+#[cfg(feature = "macros")]
+pub use policy_core_macros::require;
+
+/// Derive macro implementing [`Sanitize`] from per-field sanitizers.
+///
+/// Requires the `macros` feature.
+#[cfg(feature = "macros")]
+pub use policy_core_macros::Sanitize;
+
+/// Support code for `policy-core-macros` expansions. Not public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::validation::{finish_derived, DerivedSanitize};
+}
 
 #[cfg(test)]
 pub(crate) mod test_utils {
@@ -38,7 +38,7 @@ impl<'a> PolicyLog<'a> {
     /// ```no_run
     /// # use policy_core::{PolicyLog, Secret};
     /// # fn example(log: &PolicyLog) {
-    /// let secret = Secret::new("password");
+    /// let secret = Secret::new("password".to_string());
     /// log.info(format_args!("Processing with key: {:?}", secret));
     /// # }
     /// ```
@@ -8,6 +8,10 @@ pub enum PolicyReq {
     Authenticated,
     /// Requires authorization for a specific action
     Authorized { action: &'static str },
+    /// Requires the principal to hold a role
+    HasRole { role: &'static str },
+    /// Requires the principal to have been granted a scope
+    HasScope { scope: &'static str },
 }
 
 /// Standard action names for authorization policies.
@@ -31,6 +35,10 @@ pub mod actions {
     pub const HTTP: &str = "http";
     /// Audit action - grants AuditCap capability
     pub const AUDIT: &str = "audit";
+    /// Secrets action - grants SecretsCap capability
+    pub const SECRETS_READ: &str = "secrets:read";
+    /// Declassification action - grants DeclassifyCap capability
+    pub const DECLASSIFY: &str = "secrets:declassify";
 }
 
 /// Policy requiring authentication.
@@ -63,6 +71,54 @@ impl Authorized {
     }
 }
 
+/// Policy requiring the principal to hold a specific role.
+///
+/// Roles are read from [`Principal::roles`](crate::Principal::roles); a
+/// missing role fails the gate with
+/// [`ViolationKind::MissingRole`](crate::ViolationKind::MissingRole).
+pub struct HasRole {
+    role: &'static str,
+}
+
+impl HasRole {
+    /// Creates a `HasRole` policy requirement for the specified role.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::HasRole;
+    ///
+    /// let req = HasRole::named("admin");
+    /// ```
+    pub fn named(role: &'static str) -> Self {
+        Self { role }
+    }
+}
+
+/// Policy requiring the principal to have been granted a specific scope.
+///
+/// Scopes are read from [`Principal::scopes`](crate::Principal::scopes); a
+/// missing scope fails the gate with
+/// [`ViolationKind::MissingScope`](crate::ViolationKind::MissingScope).
+pub struct HasScope {
+    scope: &'static str,
+}
+
+impl HasScope {
+    /// Creates a `HasScope` policy requirement for the specified scope.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::HasScope;
+    ///
+    /// let req = HasScope::named("items:write");
+    /// ```
+    pub fn named(scope: &'static str) -> Self {
+        Self { scope }
+    }
+}
+
 // Conversions to PolicyReq
 impl From<Authenticated> for PolicyReq {
     /// Convert an `Authenticated` marker into the corresponding `PolicyReq::Authenticated` variant.
@@ -83,3 +139,21 @@ impl From<Authorized> for PolicyReq {
         }
     }
 }
+
+impl From<HasRole> for PolicyReq {
+    /// Converts a `HasRole` policy into a `PolicyReq::HasRole`, preserving the role.
+    ///
+    /// Note: `PolicyReq` is an internal type used by the policy gate.
+    fn from(req: HasRole) -> Self {
+        PolicyReq::HasRole { role: req.role }
+    }
+}
+
+impl From<HasScope> for PolicyReq {
+    /// Converts a `HasScope` policy into a `PolicyReq::HasScope`, preserving the scope.
+    ///
+    /// Note: `PolicyReq` is an internal type used by the policy gate.
+    fn from(req: HasScope) -> Self {
+        PolicyReq::HasScope { scope: req.scope }
+    }
+}
@@ -0,0 +1,170 @@
+//! Where an untrusted value came from.
+//!
+//! A [`Provenance`] names the input channel of a [`Tainted`](crate::Tainted)
+//! value: its [`InputSource`] and the key it was read under, such as
+//! header `x-forwarded-for`. It follows the value through taint-preserving
+//! transformations and into the [`SanitizationError`](crate::SanitizationError),
+//! [`Violation`](crate::Violation) and
+//! [`AuditEvent`](crate::audit::AuditEvent) produced when the value is
+//! rejected, so reviewers can see which channel was attacked.
+//!
+//! Provenance never contains the value itself. Keys are often attacker
+//! controlled too (query parameter and header names), so they are stripped of
+//! control characters and truncated before they are stored.
+
+use std::fmt;
+
+/// Maximum length of a stored key, in characters.
+const MAX_KEY_LEN: usize = 64;
+
+/// The channel an untrusted value arrived through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InputSource {
+    /// A URL query parameter
+    Query,
+    /// A request header
+    Header,
+    /// A path parameter from routing
+    Path,
+    /// A field of the request body
+    Body,
+    /// An environment variable
+    Env,
+    /// A file on disk
+    File,
+    /// The response of an outbound HTTP request
+    Upstream,
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Query => write!(f, "query"),
+            InputSource::Header => write!(f, "header"),
+            InputSource::Path => write!(f, "path"),
+            InputSource::Body => write!(f, "body"),
+            InputSource::Env => write!(f, "env"),
+            InputSource::File => write!(f, "file"),
+            InputSource::Upstream => write!(f, "upstream"),
+        }
+    }
+}
+
+/// The source and key of an untrusted value.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{InputSource, Provenance};
+///
+/// let provenance = Provenance::header("x-forwarded-for");
+/// assert_eq!(provenance.source(), InputSource::Header);
+/// assert_eq!(provenance.key(), "x-forwarded-for");
+/// assert_eq!(provenance.to_string(), "header 'x-forwarded-for'");
+///
+/// // Keys are made safe to log
+/// let provenance = Provenance::query("q\r\nlevel=admin");
+/// assert_eq!(provenance.key(), "q  level=admin");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Provenance {
+    source: InputSource,
+    key: String,
+}
+
+impl Provenance {
+    /// Creates a provenance label for the value read under `key` from `source`.
+    ///
+    /// Control characters in `key` are replaced with spaces and keys longer
+    /// than 64 characters are truncated, to prevent log injection (CWE-117).
+    pub fn new(source: InputSource, key: impl AsRef<str>) -> Self {
+        let key = key
+            .as_ref()
+            .chars()
+            .take(MAX_KEY_LEN)
+            .map(|c| if c.is_control() { ' ' } else { c })
+            .collect();
+        Self { source, key }
+    }
+
+    /// A URL query parameter named `key`.
+    pub fn query(key: impl AsRef<str>) -> Self {
+        Self::new(InputSource::Query, key)
+    }
+
+    /// A request header named `key`.
+    pub fn header(key: impl AsRef<str>) -> Self {
+        Self::new(InputSource::Header, key)
+    }
+
+    /// A path parameter named `key`.
+    pub fn path(key: impl AsRef<str>) -> Self {
+        Self::new(InputSource::Path, key)
+    }
+
+    /// A request body field named `key`.
+    pub fn body(key: impl AsRef<str>) -> Self {
+        Self::new(InputSource::Body, key)
+    }
+
+    /// An environment variable named `key`.
+    pub fn env(key: impl AsRef<str>) -> Self {
+        Self::new(InputSource::Env, key)
+    }
+
+    /// A file at path `key`.
+    pub fn file(key: impl AsRef<str>) -> Self {
+        Self::new(InputSource::File, key)
+    }
+
+    /// The response from `key`, which should be a redacted URL.
+    pub fn upstream(key: impl AsRef<str>) -> Self {
+        Self::new(InputSource::Upstream, key)
+    }
+
+    /// Returns the input channel.
+    pub fn source(&self) -> InputSource {
+        self.source
+    }
+
+    /// Returns the key the value was read under.
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl fmt::Display for Provenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} '{}'", self.source, self.key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_names_source_and_key() {
+        assert_eq!(Provenance::query("page").to_string(), "query 'page'");
+        assert_eq!(Provenance::path("id").to_string(), "path 'id'");
+        assert_eq!(
+            Provenance::upstream("https://api.example.com/v1").to_string(),
+            "upstream 'https://api.example.com/v1'"
+        );
+    }
+
+    #[test]
+    fn keys_are_stripped_of_control_characters() {
+        let provenance = Provenance::header("x-id\n[INFO] forged\0");
+        assert_eq!(provenance.key(), "x-id [INFO] forged ");
+    }
+
+    #[test]
+    fn long_keys_are_truncated() {
+        let provenance = Provenance::query("k".repeat(1000));
+        assert_eq!(provenance.key().chars().count(), MAX_KEY_LEN);
+
+        let provenance = Provenance::query("é".repeat(100));
+        assert_eq!(provenance.key(), "é".repeat(MAX_KEY_LEN));
+    }
+}
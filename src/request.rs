@@ -1,3 +1,7 @@
+use std::borrow::Borrow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
 /// Metadata about an incoming request or operation.
 ///
 /// Contains the request identifier and optional principal (authenticated user/service).
@@ -9,11 +13,325 @@ pub struct RequestMeta {
     pub principal: Option<Principal>,
 }
 
+/// Replaces control characters with a space so identity labels are safe to log.
+///
+/// Uses the same rule as `AuditEvent` field sanitization (CWE-117).
+fn sanitize_label(value: String) -> String {
+    if value.chars().any(|c| c.is_control()) {
+        value
+            .chars()
+            .map(|c| if c.is_control() { ' ' } else { c })
+            .collect()
+    } else {
+        value
+    }
+}
+
+/// A named role held by a principal (e.g., `"admin"`, `"billing"`).
+///
+/// Control characters are replaced with spaces on construction, so a role can
+/// always be printed in logs and audit events without enabling log injection.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::Role;
+///
+/// let role = Role::new("admin");
+/// assert_eq!(role.as_str(), "admin");
+/// assert_eq!(role.to_string(), "admin");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Role(String);
+
+impl Role {
+    /// Creates a role from its name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(sanitize_label(name.into()))
+    }
+
+    /// Returns the role name.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// Lets `BTreeSet<Role>` be searched by `&str`. The derived `Eq`, `Ord` and
+// `Hash` compare only the inner string, so they agree with `str`'s.
+impl Borrow<str> for Role {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// An OAuth-style scope granted to a principal (e.g., `"items:write"`).
+///
+/// Control characters are replaced with spaces on construction, so a scope can
+/// always be printed in logs and audit events without enabling log injection.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::Scope;
+///
+/// let scope = Scope::new("items:write");
+/// assert_eq!(scope.as_str(), "items:write");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Scope(String);
+
+impl Scope {
+    /// Creates a scope from its name.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(sanitize_label(name.into()))
+    }
+
+    /// Returns the scope name.
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// Lets `BTreeSet<Scope>` be searched by `&str`. The derived `Eq`, `Ord` and
+// `Hash` compare only the inner string, so they agree with `str`'s.
+impl Borrow<str> for Scope {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Scope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
 /// An authenticated user or service principal.
-#[derive(Debug, Clone)]
+///
+/// Beyond its identity, a principal carries the facts authorization decisions
+/// are based on: roles, scopes, an optional tenant and free-form attributes.
+/// These are populated by the authentication layer (session lookup, JWT claims)
+/// and checked by `PolicyGate` requirements such as [`HasRole`](crate::HasRole)
+/// or by a custom [`Authorizer`](crate::Authorizer).
+///
+/// # Safe Printing
+///
+/// Roles, scopes and the tenant id are sanitized on construction. Attribute
+/// *values* may carry sensitive claims (email, department, clearance), so the
+/// `Debug` output lists attribute keys only and redacts their values.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::Principal;
+///
+/// let principal = Principal::new("user-1", "Alice")
+///     .with_role("admin")
+///     .with_scope("items:write")
+///     .with_tenant_id("acme")
+///     .with_attribute("department", "finance");
+///
+/// assert!(principal.has_role("admin"));
+/// assert!(principal.has_scope("items:write"));
+/// assert_eq!(principal.tenant_id(), Some("acme"));
+/// assert_eq!(principal.attribute("department"), Some("finance"));
+///
+/// // Attribute values never appear in debug output
+/// assert!(!format!("{:?}", principal).contains("finance"));
+/// ```
+#[derive(Clone, PartialEq, Eq)]
 pub struct Principal {
     /// Unique identifier for this principal
     pub id: String,
     /// Display name
     pub name: String,
+    roles: BTreeSet<Role>,
+    scopes: BTreeSet<Scope>,
+    tenant_id: Option<String>,
+    attributes: BTreeMap<String, String>,
+}
+
+impl Principal {
+    /// Creates a principal with an identifier and display name.
+    ///
+    /// The principal starts with no roles, scopes, tenant or attributes.
+    pub fn new(id: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            name: name.into(),
+            roles: BTreeSet::new(),
+            scopes: BTreeSet::new(),
+            tenant_id: None,
+            attributes: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a role to the principal.
+    pub fn with_role(mut self, role: impl Into<String>) -> Self {
+        self.roles.insert(Role::new(role));
+        self
+    }
+
+    /// Adds a scope to the principal.
+    pub fn with_scope(mut self, scope: impl Into<String>) -> Self {
+        self.scopes.insert(Scope::new(scope));
+        self
+    }
+
+    /// Sets the tenant the principal belongs to.
+    ///
+    /// Control characters are replaced with spaces.
+    pub fn with_tenant_id(mut self, tenant_id: impl Into<String>) -> Self {
+        self.tenant_id = Some(sanitize_label(tenant_id.into()));
+        self
+    }
+
+    /// Sets a free-form attribute, replacing any previous value for `key`.
+    ///
+    /// Control characters in the key are replaced with spaces. The value is
+    /// stored as given and is never included in `Debug` output.
+    pub fn with_attribute(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.attributes
+            .insert(sanitize_label(key.into()), value.into());
+        self
+    }
+
+    /// Returns `true` if the principal holds `role`.
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.contains(role)
+    }
+
+    /// Returns `true` if the principal was granted `scope`.
+    pub fn has_scope(&self, scope: &str) -> bool {
+        self.scopes.contains(scope)
+    }
+
+    /// Returns the principal's roles in sorted order.
+    pub fn roles(&self) -> impl Iterator<Item = &Role> + '_ {
+        self.roles.iter()
+    }
+
+    /// Returns the principal's scopes in sorted order.
+    pub fn scopes(&self) -> impl Iterator<Item = &Scope> + '_ {
+        self.scopes.iter()
+    }
+
+    /// Returns the tenant id, if set.
+    pub fn tenant_id(&self) -> Option<&str> {
+        self.tenant_id.as_deref()
+    }
+
+    /// Returns the value of an attribute, if set.
+    ///
+    /// Attribute values are not sanitized; validate them before use in sinks.
+    pub fn attribute(&self, key: &str) -> Option<&str> {
+        self.attributes.get(key).map(String::as_str)
+    }
+
+    /// Returns the attribute keys in sorted order.
+    pub fn attribute_keys(&self) -> impl Iterator<Item = &str> + '_ {
+        self.attributes.keys().map(String::as_str)
+    }
+}
+
+impl fmt::Debug for Principal {
+    /// SECURITY: Attribute values are redacted; only their keys are shown.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Principal")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("roles", &self.roles)
+            .field("scopes", &self.scopes)
+            .field("tenant_id", &self.tenant_id)
+            .field(
+                "attributes",
+                &self
+                    .attributes
+                    .keys()
+                    .map(|k| (k.as_str(), "<redacted>"))
+                    .collect::<BTreeMap<_, _>>(),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn principal_new_has_no_authorization_facts() {
+        let p = Principal::new("user-1", "Alice");
+
+        assert_eq!(p.id, "user-1");
+        assert_eq!(p.name, "Alice");
+        assert_eq!(p.roles().count(), 0);
+        assert_eq!(p.scopes().count(), 0);
+        assert!(p.tenant_id().is_none());
+        assert_eq!(p.attribute_keys().count(), 0);
+    }
+
+    #[test]
+    fn principal_roles_and_scopes() {
+        let p = Principal::new("user-1", "Alice")
+            .with_role("admin")
+            .with_role("billing")
+            .with_role("admin")
+            .with_scope("items:read");
+
+        assert!(p.has_role("admin"));
+        assert!(p.has_role("billing"));
+        assert!(!p.has_role("auditor"));
+        assert!(p.has_scope("items:read"));
+        assert!(!p.has_scope("items:write"));
+
+        let roles: Vec<&str> = p.roles().map(Role::as_str).collect();
+        assert_eq!(roles, vec!["admin", "billing"]);
+    }
+
+    #[test]
+    fn principal_labels_strip_control_chars() {
+        let p = Principal::new("user-1", "Alice")
+            .with_role("admin\nFAKE ENTRY")
+            .with_scope("items:\rwrite")
+            .with_tenant_id("acme\x00corp")
+            .with_attribute("dept\n", "value");
+
+        assert!(p.has_role("admin FAKE ENTRY"));
+        assert!(p.has_scope("items: write"));
+        assert_eq!(p.tenant_id(), Some("acme corp"));
+        assert_eq!(p.attribute("dept "), Some("value"));
+    }
+
+    #[test]
+    fn principal_debug_redacts_attribute_values() {
+        let p = Principal::new("user-1", "Alice")
+            .with_role("admin")
+            .with_tenant_id("acme")
+            .with_attribute("email", "alice@example.com");
+
+        let debug = format!("{:?}", p);
+
+        assert!(debug.contains("admin"));
+        assert!(debug.contains("acme"));
+        assert!(debug.contains("email"));
+        assert!(debug.contains("<redacted>"));
+        assert!(!debug.contains("alice@example.com"));
+    }
+
+    #[test]
+    fn attributes_are_replaced_by_key() {
+        let p = Principal::new("user-1", "Alice")
+            .with_attribute("level", "1")
+            .with_attribute("level", "2");
+
+        assert_eq!(p.attribute("level"), Some("2"));
+        assert_eq!(p.attribute_keys().count(), 1);
+    }
 }
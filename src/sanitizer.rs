@@ -1,6 +1,37 @@
+use std::collections::BTreeSet;
 use std::fmt;
 
-use crate::{Tainted, Verified};
+use crate::contexts::{HttpTarget, LogLine, VerificationContext};
+use crate::http::url::{host_matches, host_pattern};
+use crate::http::Url;
+use crate::{Approval, Provenance, Tainted, Verified};
+
+mod body;
+mod combinators;
+mod email;
+mod encoders;
+mod enumeration;
+mod identifier;
+mod integer;
+mod pattern;
+mod timestamp;
+mod unicode;
+mod uuid;
+
+pub use body::HttpBodySanitizer;
+pub use combinators::{AndThen, Fields, Map, Or, StructSanitizer, VecSanitizer, VerifiedParts};
+pub use email::EmailSanitizer;
+pub use encoders::{
+    HtmlAttributeEncoder, HtmlEncoder, JsonStringEncoder, PathSegmentEncoder, QueryValueEncoder,
+    ShellQuoter,
+};
+pub use enumeration::EnumSanitizer;
+pub use identifier::IdentifierSanitizer;
+pub use integer::IntegerSanitizer;
+pub use pattern::PatternSanitizer;
+pub use timestamp::{Timestamp, TimestampSanitizer};
+pub use unicode::Normalization;
+pub use uuid::UuidSanitizer;
 
 /// Error returned when sanitization fails.
 ///
@@ -8,6 +39,9 @@ use crate::{Tainted, Verified};
 /// be promoted to a `Verified<T>`. The error does not leak sensitive information
 /// about the rejected input.
 ///
+/// If the input carried a [`Provenance`], the error names the channel it came
+/// from (see [`provenance`](Self::provenance)).
+///
 /// # Examples
 ///
 /// ```
@@ -20,6 +54,7 @@ use crate::{Tainted, Verified};
 pub struct SanitizationError {
     kind: SanitizationErrorKind,
     message: String,
+    provenance: Option<Provenance>,
 }
 
 impl SanitizationError {
@@ -28,7 +63,22 @@ impl SanitizationError {
         Self {
             kind,
             message: message.into(),
+            provenance: None,
+        }
+    }
+
+    /// Records which input channel the rejected value came from.
+    pub fn with_provenance(mut self, provenance: Provenance) -> Self {
+        self.provenance = Some(provenance);
+        self
+    }
+
+    /// Sets the provenance unless the error already has one.
+    pub(crate) fn or_provenance(mut self, provenance: Option<Provenance>) -> Self {
+        if self.provenance.is_none() {
+            self.provenance = provenance;
         }
+        self
     }
 
     /// Returns the error kind.
@@ -40,11 +90,20 @@ impl SanitizationError {
     pub fn message(&self) -> &str {
         &self.message
     }
+
+    /// Returns where the rejected value came from, if known.
+    pub fn provenance(&self) -> Option<&Provenance> {
+        self.provenance.as_ref()
+    }
 }
 
 impl fmt::Display for SanitizationError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "sanitization failed ({}): {}", self.kind, self.message)
+        write!(f, "sanitization failed ({}): {}", self.kind, self.message)?;
+        if let Some(provenance) = &self.provenance {
+            write!(f, " (from {})", provenance)?;
+        }
+        Ok(())
     }
 }
 
@@ -67,6 +126,19 @@ pub enum SanitizationErrorKind {
     TooLong,
     /// Input contains control or non-printable characters.
     ContainsControlChars,
+    /// Input is well-formed but outside the allowed range.
+    OutOfRange,
+    /// Input is well-formed but not one of the allowed values.
+    NotAllowed,
+    /// Input contains bidirectional formatting characters that reorder
+    /// displayed text.
+    BidiControl,
+    /// Input contains invisible characters such as zero-width spaces.
+    InvisibleChars,
+    /// Input mixes characters from more than one script.
+    MixedScript,
+    /// Input is visually confusable with different (ASCII) text.
+    Confusable,
 }
 
 impl fmt::Display for SanitizationErrorKind {
@@ -78,14 +150,56 @@ impl fmt::Display for SanitizationErrorKind {
             Self::Empty => write!(f, "empty input"),
             Self::TooLong => write!(f, "input too long"),
             Self::ContainsControlChars => write!(f, "contains control characters"),
+            Self::OutOfRange => write!(f, "out of range"),
+            Self::NotAllowed => write!(f, "value not allowed"),
+            Self::BidiControl => write!(f, "contains bidirectional control characters"),
+            Self::InvisibleChars => write!(f, "contains invisible characters"),
+            Self::MixedScript => write!(f, "mixed scripts"),
+            Self::Confusable => write!(f, "confusable characters"),
         }
     }
 }
 
 /// Trait for sanitizing tainted values into verified values.
 ///
-/// `Sanitizer<T>` defines the interface for converting untrusted, tainted data
-/// into verified data that is safe to use in security-sensitive contexts.
+/// `Sanitizer<T, U>` defines the interface for converting untrusted, tainted data
+/// into verified data that is safe to use in security-sensitive contexts. Most
+/// sanitizers return the type they validate (`U` defaults to `T`); parsing
+/// sanitizers such as [`UrlSanitizer`] return a more specific type.
+///
+/// # Output Context
+///
+/// Each sanitizer declares, through [`Context`](Self::Context), which output
+/// context its values are verified for (see [`contexts`](crate::contexts)).
+/// [`StringSanitizer`] produces [`LogLine`] values, [`UrlSanitizer`] produces
+/// [`HttpTarget`] values and structured parsers produce
+/// [`Plain`](crate::contexts::Plain) values. A [`Sink`](crate::Sink) only
+/// accepts values of its own context.
+///
+/// # Composition
+///
+/// Sanitizers compose without giving up the validation bottleneck:
+///
+/// - [`and_then`](Self::and_then) runs a second sanitizer on verified output
+/// - [`map`](Self::map) transforms verified output into another verified type
+/// - [`or`](Self::or) falls back to a second sanitizer on rejection
+/// - [`VecSanitizer`] sanitizes each element of a `Tainted<Vec<T>>`
+/// - [`StructSanitizer`] sanitizes a struct field by field
+///
+/// # Encoders
+///
+/// Encoders such as [`HtmlEncoder`] or [`ShellQuoter`] are sanitizers that
+/// escape their input for a context instead of rejecting it. Chain one after
+/// a rejecting sanitizer to both validate and encode:
+///
+/// ```
+/// use policy_core::{HtmlEncoder, Sanitizer, StringSanitizer, Tainted};
+///
+/// let sanitizer = StringSanitizer::new(64).unwrap().and_then(HtmlEncoder::new());
+///
+/// let html = sanitizer.sanitize(Tainted::new(" <i>hi</i> ".to_string())).unwrap();
+/// assert_eq!(html.as_ref(), "&lt;i&gt;hi&lt;/i&gt;");
+/// ```
 ///
 /// # Invariants
 ///
@@ -138,6 +252,7 @@ impl fmt::Display for SanitizationErrorKind {
 /// // Sanitizers will be used like this:
 /// # struct MySanitizer;
 /// # impl Sanitizer<String> for MySanitizer {
+/// #     type Context = policy_core::contexts::Plain;
 /// #     fn sanitize(&self, input: Tainted<String>) -> Result<Verified<String>, SanitizationError> {
 /// #         unimplemented!()
 /// #     }
@@ -148,7 +263,10 @@ impl fmt::Display for SanitizationErrorKind {
 /// // Sanitizer performs validation and returns Verified on success
 /// // let verified = sanitizer.sanitize(tainted_input)?;
 /// ```
-pub trait Sanitizer<T> {
+pub trait Sanitizer<T, U = T> {
+    /// The output context this sanitizer verifies values for.
+    type Context: VerificationContext;
+
     /// Sanitizes a tainted value, returning a verified value on success.
     ///
     /// # Errors
@@ -163,7 +281,88 @@ pub trait Sanitizer<T> {
     /// let tainted = Tainted::new("input".to_string());
     /// let verified = sanitizer.sanitize(tainted)?;
     /// ```
-    fn sanitize(&self, input: Tainted<T>) -> Result<Verified<T>, SanitizationError>;
+    fn sanitize(&self, input: Tainted<T>) -> Result<Verified<U, Self::Context>, SanitizationError>;
+
+    /// Chains `next` after this sanitizer.
+    ///
+    /// The verified output of this sanitizer is re-tainted and passed to `next`;
+    /// the input is accepted only if both accept it. The result is verified
+    /// for the context of `next`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{Sanitizer, StringSanitizer, Tainted, UrlSanitizer};
+    ///
+    /// // Reject overlong input before parsing it as a URL
+    /// let sanitizer = StringSanitizer::new(64).unwrap().and_then(UrlSanitizer::new());
+    ///
+    /// let url = sanitizer
+    ///     .sanitize(Tainted::new("https://example.com/a".to_string()))
+    ///     .unwrap();
+    /// assert_eq!(url.as_ref().path(), "/a");
+    /// ```
+    fn and_then<S>(self, next: S) -> AndThen<Self, S, U>
+    where
+        Self: Sized,
+    {
+        AndThen::new(self, next)
+    }
+
+    /// Transforms the verified output of this sanitizer with `f`, then
+    /// sanitizes the result with `then`.
+    ///
+    /// `f` only ever receives values this sanitizer accepted, but nothing
+    /// stops it from returning something else, so its output is re-tainted
+    /// and accepted only if `then` accepts it. The result is verified for the
+    /// context of `then`, and carries its approval marked as transformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{IntegerSanitizer, Sanitizer, StringSanitizer, Tainted};
+    ///
+    /// // Accept thousands separators in a bounded count
+    /// let sanitizer = StringSanitizer::new(16)
+    ///     .unwrap()
+    ///     .map(|count| count.replace(',', ""), IntegerSanitizer::new(0u32, 100_000).unwrap());
+    ///
+    /// let count = sanitizer.sanitize(Tainted::new(" 12,500 ".to_string())).unwrap();
+    /// assert_eq!(count.into_inner(), 12_500);
+    ///
+    /// assert!(sanitizer.sanitize(Tainted::new("1,000,000".to_string())).is_err());
+    /// ```
+    fn map<V, S>(self, f: fn(U) -> V, then: S) -> Map<Self, S, U, V>
+    where
+        Self: Sized,
+    {
+        Map::new(self, f, then)
+    }
+
+    /// Falls back to `other` if this sanitizer rejects the input.
+    ///
+    /// If both reject the input, the error from `other` is returned. Both
+    /// sanitizers must produce the same context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{Sanitizer, Tainted, UrlSanitizer};
+    ///
+    /// let sanitizer = UrlSanitizer::new()
+    ///     .allow_host("api.example.com")
+    ///     .or(UrlSanitizer::new().allow_scheme("https").allow_host("*.cdn.example.com"));
+    ///
+    /// assert!(sanitizer.sanitize(Tainted::new("http://api.example.com/".to_string())).is_ok());
+    /// assert!(sanitizer.sanitize(Tainted::new("https://img.cdn.example.com/".to_string())).is_ok());
+    /// assert!(sanitizer.sanitize(Tainted::new("http://img.cdn.example.com/".to_string())).is_err());
+    /// ```
+    fn or<S>(self, other: S) -> Or<Self, S>
+    where
+        Self: Sized,
+    {
+        Or::new(self, other)
+    }
 }
 
 /// A trivial sanitizer that accepts all input (for testing only).
@@ -195,11 +394,17 @@ pub struct AcceptAllSanitizer;
 
 #[cfg(test)]
 impl<T> Sanitizer<T> for AcceptAllSanitizer {
+    type Context = crate::contexts::Plain;
+
     fn sanitize(&self, input: Tainted<T>) -> Result<Verified<T>, SanitizationError> {
         // Extract the inner value from the tainted wrapper
         let value = input.into_inner();
         // Unconditionally wrap it as verified (no validation!)
-        Ok(Verified::new_unchecked(value))
+        Ok(Verified::new_unchecked(value).approved_by(Approval::new(
+            "AcceptAllSanitizer",
+            self,
+            false,
+        )))
     }
 }
 
@@ -231,6 +436,8 @@ pub struct RejectAllSanitizer;
 
 #[cfg(test)]
 impl<T> Sanitizer<T> for RejectAllSanitizer {
+    type Context = crate::contexts::Plain;
+
     fn sanitize(&self, _input: Tainted<T>) -> Result<Verified<T>, SanitizationError> {
         Err(SanitizationError::new(
             SanitizationErrorKind::InvalidInput,
@@ -254,6 +461,30 @@ impl<T> Sanitizer<T> for RejectAllSanitizer {
 /// - Prevents excessive memory consumption via length limits
 /// - Does not leak rejected input in error messages
 ///
+/// Output is verified for the [`LogLine`] context. It is NOT escaped for
+/// HTML, SQL or shell use.
+///
+/// # Unicode Hardening
+///
+/// Text can pass the control character check and still display as something
+/// other than what it contains. The following checks are off by default and
+/// enabled with builder methods:
+///
+/// - [`with_normalization`](Self::with_normalization): NFC or NFKC
+///   normalization of the output, rejecting output that then starts with a
+///   combining mark ([`SanitizationErrorKind::MalformedInput`])
+/// - [`reject_bidi_controls`](Self::reject_bidi_controls): bidirectional
+///   overrides and isolates ([`SanitizationErrorKind::BidiControl`])
+/// - [`reject_invisible_chars`](Self::reject_invisible_chars): zero-width and
+///   other default-ignorable characters
+///   ([`SanitizationErrorKind::InvisibleChars`])
+/// - [`reject_mixed_scripts`](Self::reject_mixed_scripts): e.g. Latin mixed
+///   with Cyrillic ([`SanitizationErrorKind::MixedScript`])
+/// - [`reject_confusables`](Self::reject_confusables): non-ASCII text that
+///   looks like ASCII text ([`SanitizationErrorKind::Confusable`])
+///
+/// [`hardened`](Self::hardened) enables all of them with NFKC normalization.
+///
 /// # Examples
 ///
 /// ```
@@ -276,6 +507,11 @@ impl<T> Sanitizer<T> for RejectAllSanitizer {
 #[derive(Debug, Clone, Copy)]
 pub struct StringSanitizer {
     max_len: usize,
+    normalization: Option<Normalization>,
+    reject_bidi: bool,
+    reject_invisible: bool,
+    reject_mixed_script: bool,
+    reject_confusables: bool,
 }
 
 impl StringSanitizer {
@@ -303,7 +539,14 @@ impl StringSanitizer {
                 "max_len must be greater than 0",
             ));
         }
-        Ok(Self { max_len })
+        Ok(Self {
+            max_len,
+            normalization: None,
+            reject_bidi: false,
+            reject_invisible: false,
+            reject_mixed_script: false,
+            reject_confusables: false,
+        })
     }
 
     /// Creates a string sanitizer with the default maximum length of 256 characters.
@@ -319,6 +562,73 @@ impl StringSanitizer {
         Self::new(256).expect("default max_len of 256 is valid")
     }
 
+    /// Enables every Unicode check with NFKC normalization.
+    ///
+    /// Suited to names, handles and other identifiers shown to other users.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{SanitizationErrorKind, Sanitizer, StringSanitizer, Tainted};
+    ///
+    /// let sanitizer = StringSanitizer::new(64).unwrap().hardened();
+    ///
+    /// // Cyrillic lookalike of "apple"
+    /// let err = sanitizer.sanitize(Tainted::new("аррӏе".to_string())).unwrap_err();
+    /// assert_eq!(err.kind(), SanitizationErrorKind::Confusable);
+    /// ```
+    pub fn hardened(self) -> Self {
+        self.with_normalization(Normalization::Nfkc)
+            .reject_bidi_controls()
+            .reject_invisible_chars()
+            .reject_mixed_scripts()
+            .reject_confusables()
+    }
+
+    /// Normalizes accepted input to the given Unicode normalization form.
+    ///
+    /// The length limit is checked again after normalization, since NFKC can
+    /// expand some characters. Output that starts with a combining mark after
+    /// normalization is rejected with [`SanitizationErrorKind::MalformedInput`].
+    pub fn with_normalization(mut self, form: Normalization) -> Self {
+        self.normalization = Some(form);
+        self
+    }
+
+    /// Rejects bidirectional formatting characters (U+202A..U+202E,
+    /// U+2066..U+2069, U+200E, U+200F, U+061C).
+    pub fn reject_bidi_controls(mut self) -> Self {
+        self.reject_bidi = true;
+        self
+    }
+
+    /// Rejects default-ignorable characters such as zero-width spaces and
+    /// joiners, soft hyphens and variation selectors.
+    ///
+    /// When bidi controls are also rejected they are reported as
+    /// [`SanitizationErrorKind::BidiControl`].
+    pub fn reject_invisible_chars(mut self) -> Self {
+        self.reject_invisible = true;
+        self
+    }
+
+    /// Rejects input that mixes scripts, as defined by UTS #39.
+    ///
+    /// Characters shared across scripts (digits, punctuation, spaces) are
+    /// allowed alongside any script, as are the usual combinations such as
+    /// Han with Hiragana and Katakana.
+    pub fn reject_mixed_scripts(mut self) -> Self {
+        self.reject_mixed_script = true;
+        self
+    }
+
+    /// Rejects non-ASCII input that is visually confusable with ASCII text,
+    /// such as Cyrillic `аррӏе` for `apple`.
+    pub fn reject_confusables(mut self) -> Self {
+        self.reject_confusables = true;
+        self
+    }
+
     /// Checks if a character is a control or non-printable character.
     ///
     /// Returns `true` for:
@@ -331,50 +641,286 @@ impl StringSanitizer {
 }
 
 impl Sanitizer<String> for StringSanitizer {
-    fn sanitize(&self, input: Tainted<String>) -> Result<Verified<String>, SanitizationError> {
-        // Extract the raw string from the tainted wrapper
-        let raw = input.into_inner();
+    type Context = LogLine;
+
+    fn sanitize(
+        &self,
+        input: Tainted<String>,
+    ) -> Result<Verified<String, LogLine>, SanitizationError> {
+        input.sanitize_inner(|raw| {
+            // Trim leading and trailing whitespace
+            let trimmed = raw.trim();
+
+            // BREAKING CHANGE WARNING: Do NOT remove the empty string check.
+            // Empty strings can cause downstream parsing errors and indicate invalid input.
+            // Reject empty strings (after trimming)
+            if trimmed.is_empty() {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::Empty,
+                    "input is empty or contains only whitespace",
+                ));
+            }
 
-        // Trim leading and trailing whitespace
-        let trimmed = raw.trim();
+            // BREAKING CHANGE WARNING: Do NOT remove or weaken the control character check.
+            // Control characters enable LOG INJECTION attacks (CWE-117).
+            // Missing newline filtering allows forging audit entries.
+            // Missing null byte filtering causes string truncation.
+            // Check for control or non-printable characters
+            if trimmed.chars().any(Self::is_control_char) {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::ContainsControlChars,
+                    "input contains control or non-printable characters",
+                ));
+            }
 
-        // BREAKING CHANGE WARNING: Do NOT remove the empty string check.
-        // Empty strings can cause downstream parsing errors and indicate invalid input.
-        // Reject empty strings (after trimming)
-        if trimmed.is_empty() {
-            return Err(SanitizationError::new(
-                SanitizationErrorKind::Empty,
-                "input is empty or contains only whitespace",
-            ));
-        }
+            if self.reject_bidi {
+                unicode::reject_bidi_controls(trimmed)?;
+            }
+            if self.reject_invisible {
+                unicode::reject_invisible(trimmed)?;
+            }
 
-        // BREAKING CHANGE WARNING: Do NOT remove or weaken the control character check.
-        // Control characters enable LOG INJECTION attacks (CWE-117).
-        // Missing newline filtering allows forging audit entries.
-        // Missing null byte filtering causes string truncation.
-        // Check for control or non-printable characters
-        if trimmed.chars().any(Self::is_control_char) {
-            return Err(SanitizationError::new(
-                SanitizationErrorKind::ContainsControlChars,
-                "input contains control or non-printable characters",
-            ));
-        }
+            // BREAKING CHANGE WARNING: Do NOT remove the length check.
+            // Unbounded input enables DENIAL OF SERVICE through memory exhaustion
+            // and regex DOS attacks (CWE-400: Uncontrolled Resource Consumption).
+            // Check length constraint
+            if trimmed.len() > self.max_len {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::TooLong,
+                    format!("input exceeds maximum length of {}", self.max_len),
+                ));
+            }
 
-        // BREAKING CHANGE WARNING: Do NOT remove the length check.
-        // Unbounded input enables DENIAL OF SERVICE through memory exhaustion
-        // and regex DOS attacks (CWE-400: Uncontrolled Resource Consumption).
-        // Check length constraint
-        if trimmed.len() > self.max_len {
-            return Err(SanitizationError::new(
-                SanitizationErrorKind::TooLong,
-                format!("input exceeds maximum length of {}", self.max_len),
-            ));
+            // Normalize before the script checks so they see the form that is
+            // returned. NFKC can expand text, so the length is checked again, and
+            // can map a character to a space plus a combining mark (U+00AF to
+            // " \u{304}"), so the result is trimmed again and must not be left
+            // starting with a mark that has no base character.
+            let normalized = match self.normalization {
+                Some(form) => form.apply(trimmed).trim().to_string(),
+                None => trimmed.to_string(),
+            };
+            if normalized.is_empty() {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::Empty,
+                    "input is empty or contains only whitespace",
+                ));
+            }
+            if self.normalization.is_some() {
+                unicode::reject_leading_combining_mark(&normalized)?;
+            }
+            if normalized.len() > self.max_len {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::TooLong,
+                    format!("input exceeds maximum length of {}", self.max_len),
+                ));
+            }
+
+            if self.reject_mixed_script {
+                unicode::reject_mixed_script(&normalized)?;
+            }
+            if self.reject_confusables {
+                unicode::reject_confusables(&normalized)?;
+            }
+
+            // BREAKING CHANGE WARNING: Verified::new_unchecked() MUST ONLY be called AFTER
+            // all validation checks pass. Moving this before validation creates a CRITICAL BYPASS.
+            // All validation passed - create verified value
+            let transformed = normalized != raw;
+            Ok(
+                Verified::new_unchecked(normalized).approved_by(Approval::new(
+                    "StringSanitizer",
+                    self,
+                    transformed,
+                )),
+            )
+        })
+    }
+}
+
+/// Trims `raw` and applies the checks every text sanitizer starts with.
+///
+/// Rejects empty input, control characters and input longer than `max_len`
+/// bytes, in that order, with the same errors as [`StringSanitizer`].
+fn checked_trim(raw: &str, max_len: usize) -> Result<&str, SanitizationError> {
+    let trimmed = raw.trim();
+
+    if trimmed.is_empty() {
+        return Err(SanitizationError::new(
+            SanitizationErrorKind::Empty,
+            "input is empty or contains only whitespace",
+        ));
+    }
+
+    if trimmed.chars().any(StringSanitizer::is_control_char) {
+        return Err(SanitizationError::new(
+            SanitizationErrorKind::ContainsControlChars,
+            "input contains control or non-printable characters",
+        ));
+    }
+
+    if trimmed.len() > max_len {
+        return Err(SanitizationError::new(
+            SanitizationErrorKind::TooLong,
+            format!("input exceeds maximum length of {}", max_len),
+        ));
+    }
+
+    Ok(trimmed)
+}
+
+/// A URL sanitizer that parses, normalizes and restricts untrusted URLs.
+///
+/// `StringSanitizer` only checks length and control characters, so
+/// `javascript:alert(1)` or `file:///etc/passwd` pass it. `UrlSanitizer`
+/// produces a structured `Verified<Url, HttpTarget>` instead:
+///
+/// - Trims surrounding whitespace; rejects empty, overlong or control-character input
+/// - Requires an absolute `scheme://host` URL and rejects ambiguous forms
+///   (backslashes, percent-encoded hosts, numeric IPv4 shorthand)
+/// - Allows only listed schemes: `http` and `https` unless configured
+/// - Optionally restricts hosts (`*.example.com` matches subdomains)
+/// - Strips credentials and fragments, and normalizes the rest (see [`Url`])
+///
+/// # Security Properties
+///
+/// - Blocks script, file and data URLs by default
+/// - Never keeps `user:password@` credentials
+/// - Does not leak rejected input in error messages
+///
+/// Host rules here describe which URLs are acceptable input. To control where
+/// `PolicyHttp` may actually send requests, also configure an
+/// [`EgressPolicy`](crate::EgressPolicy).
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{SanitizationErrorKind, Sanitizer, Tainted, UrlSanitizer};
+///
+/// let sanitizer = UrlSanitizer::new().allow_host("*.example.com");
+///
+/// let url = sanitizer
+///     .sanitize(Tainted::new(" https://api.example.com/v1/users?page=2 ".to_string()))
+///     .unwrap();
+/// assert_eq!(url.as_ref().path(), "/v1/users");
+///
+/// let err = sanitizer
+///     .sanitize(Tainted::new("javascript:alert(1)".to_string()))
+///     .unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::MalformedInput);
+///
+/// let err = sanitizer
+///     .sanitize(Tainted::new("file:///etc/passwd".to_string()))
+///     .unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::MalformedInput);
+///
+/// let err = sanitizer
+///     .sanitize(Tainted::new("https://evil.test/".to_string()))
+///     .unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::ForbiddenPattern);
+/// ```
+#[derive(Debug, Clone)]
+pub struct UrlSanitizer {
+    schemes: BTreeSet<String>,
+    hosts: BTreeSet<String>,
+    max_len: usize,
+}
+
+impl UrlSanitizer {
+    /// Creates a sanitizer accepting `http` and `https` URLs to any host, up
+    /// to 2048 characters.
+    pub fn new() -> Self {
+        Self {
+            schemes: BTreeSet::new(),
+            hosts: BTreeSet::new(),
+            max_len: 2048,
         }
+    }
 
-        // BREAKING CHANGE WARNING: Verified::new_unchecked() MUST ONLY be called AFTER
-        // all validation checks pass. Moving this before validation creates a CRITICAL BYPASS.
-        // All validation passed - create verified value
-        Ok(Verified::new_unchecked(trimmed.to_string()))
+    /// Allows a scheme (case-insensitive), replacing the `http`/`https` default.
+    pub fn allow_scheme(mut self, scheme: impl Into<String>) -> Self {
+        self.schemes.insert(scheme.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Restricts accepted URLs to a host (case-insensitive).
+    ///
+    /// Without any allowed hosts every well-formed host is accepted. A leading
+    /// `*.` allows every subdomain of the remaining name.
+    pub fn allow_host(mut self, host: impl Into<String>) -> Self {
+        self.hosts.insert(host_pattern(host.into()));
+        self
+    }
+
+    /// Sets the maximum accepted input length in bytes.
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+}
+
+impl Default for UrlSanitizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sanitizer<String, Url> for UrlSanitizer {
+    type Context = HttpTarget;
+
+    /// Sanitizes a tainted string into a verified, normalized URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SanitizationError` if the input is empty, too long, contains
+    /// control characters, is not an unambiguous absolute URL
+    /// (`MalformedInput`), or uses a scheme or host that is not allowed
+    /// (`ForbiddenPattern`).
+    fn sanitize(
+        &self,
+        input: Tainted<String>,
+    ) -> Result<Verified<Url, HttpTarget>, SanitizationError> {
+        input.sanitize_inner(|raw| {
+            // BREAKING CHANGE WARNING: Control characters MUST be rejected before parsing.
+            // Parsers disagree on how to treat tabs and newlines inside URLs, which allows
+            // smuggling a different host past validation (CWE-20).
+            let trimmed = checked_trim(&raw, self.max_len)?;
+
+            let url = Url::parse_normalized(trimmed)?;
+
+            let scheme_allowed = if self.schemes.is_empty() {
+                matches!(url.scheme(), "http" | "https")
+            } else {
+                self.schemes.contains(url.scheme())
+            };
+            if !scheme_allowed {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::ForbiddenPattern,
+                    "URL scheme is not allowed",
+                ));
+            }
+
+            if !self.hosts.is_empty()
+                && !self
+                    .hosts
+                    .iter()
+                    .any(|pattern| host_matches(pattern, &url.parts().host))
+            {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::ForbiddenPattern,
+                    "URL host is not allowed",
+                ));
+            }
+
+            // BREAKING CHANGE WARNING: Verified::new_unchecked() MUST ONLY be called AFTER
+            // all validation checks pass. Moving this before validation creates a CRITICAL BYPASS.
+            let transformed = url.as_str() != raw;
+            Ok(Verified::new_unchecked(url).approved_by(Approval::new(
+                "UrlSanitizer",
+                self,
+                transformed,
+            )))
+        })
     }
 }
 
@@ -464,6 +1010,87 @@ mod tests {
         let _: String = verified.into_inner();
     }
 
+    #[test]
+    fn verified_values_record_the_approving_sanitizer() {
+        let sanitizer = StringSanitizer::new(100).unwrap();
+
+        let verified = sanitizer
+            .sanitize(Tainted::new("hello".to_string()))
+            .unwrap();
+        let approval = *verified.approval().unwrap();
+        assert_eq!(approval.sanitizer(), "StringSanitizer");
+        assert!(!approval.transformed());
+
+        let trimmed = sanitizer
+            .sanitize(Tainted::new(" hello ".to_string()))
+            .unwrap();
+        assert!(trimmed.approval().unwrap().transformed());
+        assert_eq!(
+            trimmed.approval().unwrap().fingerprint(),
+            approval.fingerprint()
+        );
+    }
+
+    #[test]
+    fn approval_fingerprints_identify_the_configuration() {
+        let fingerprint = |sanitizer: StringSanitizer| {
+            sanitizer
+                .sanitize(Tainted::new("name".to_string()))
+                .unwrap()
+                .approval()
+                .unwrap()
+                .fingerprint()
+        };
+        let base = StringSanitizer::new(100).unwrap();
+
+        assert_eq!(
+            fingerprint(base),
+            fingerprint(StringSanitizer::new(100).unwrap())
+        );
+        assert_ne!(
+            fingerprint(base),
+            fingerprint(StringSanitizer::new(101).unwrap())
+        );
+        assert_ne!(fingerprint(base), fingerprint(base.reject_bidi_controls()));
+    }
+
+    #[test]
+    fn pattern_fingerprints_cover_the_patterns() {
+        let fingerprint = |pattern: &str| {
+            PatternSanitizer::new()
+                .allow("word", pattern)
+                .unwrap()
+                .sanitize(Tainted::new("abc".to_string()))
+                .unwrap()
+                .approval()
+                .unwrap()
+                .fingerprint()
+        };
+
+        assert_ne!(fingerprint("[a-z]+"), fingerprint("[a-z]{1,3}"));
+    }
+
+    #[test]
+    fn parsing_sanitizers_report_normalization_as_transformation() {
+        let url = UrlSanitizer::new()
+            .sanitize(Tainted::new("HTTPS://Example.com/a".to_string()))
+            .unwrap();
+        assert_eq!(url.approval().unwrap().sanitizer(), "UrlSanitizer");
+        assert!(url.approval().unwrap().transformed());
+
+        let html = HtmlEncoder::new()
+            .sanitize(Tainted::new("plain text".to_string()))
+            .unwrap();
+        assert_eq!(html.approval().unwrap().sanitizer(), "HtmlEncoder");
+        assert!(!html.approval().unwrap().transformed());
+
+        let page = IntegerSanitizer::new(1u32, 100)
+            .unwrap()
+            .sanitize(Tainted::new("7".to_string()))
+            .unwrap();
+        assert!(!page.approval().unwrap().transformed());
+    }
+
     #[test]
     fn error_kinds_display() {
         assert_eq!(
@@ -487,6 +1114,30 @@ mod tests {
             format!("{}", SanitizationErrorKind::ContainsControlChars),
             "contains control characters"
         );
+        assert_eq!(
+            format!("{}", SanitizationErrorKind::OutOfRange),
+            "out of range"
+        );
+        assert_eq!(
+            format!("{}", SanitizationErrorKind::NotAllowed),
+            "value not allowed"
+        );
+        assert_eq!(
+            format!("{}", SanitizationErrorKind::BidiControl),
+            "contains bidirectional control characters"
+        );
+        assert_eq!(
+            format!("{}", SanitizationErrorKind::InvisibleChars),
+            "contains invisible characters"
+        );
+        assert_eq!(
+            format!("{}", SanitizationErrorKind::MixedScript),
+            "mixed scripts"
+        );
+        assert_eq!(
+            format!("{}", SanitizationErrorKind::Confusable),
+            "confusable characters"
+        );
     }
 
     // StringSanitizer tests
@@ -658,6 +1309,193 @@ mod tests {
         assert_eq!(error.kind(), SanitizationErrorKind::InvalidInput);
         assert!(error.message().contains("max_len must be greater than 0"));
     }
+
+    #[test]
+    fn string_sanitizer_unicode_checks_are_off_by_default() {
+        let sanitizer = StringSanitizer::default_limits();
+        for input in ["a\u{202E}b", "a\u{200B}b", "pаypаl", "аррӏе", "e\u{301}"] {
+            let verified = sanitizer.sanitize(Tainted::new(input.to_string())).unwrap();
+            assert_eq!(verified.as_ref(), input);
+        }
+    }
+
+    #[test]
+    fn string_sanitizer_reports_distinct_unicode_error_kinds() {
+        let sanitizer = StringSanitizer::default_limits().hardened();
+        let cases = [
+            ("invoice\u{202E}fdp.exe", SanitizationErrorKind::BidiControl),
+            ("a\u{2066}b\u{2069}", SanitizationErrorKind::BidiControl),
+            ("ad\u{200B}min", SanitizationErrorKind::InvisibleChars),
+            ("admin\u{FE0F}", SanitizationErrorKind::InvisibleChars),
+            ("pаypаl", SanitizationErrorKind::MixedScript),
+            ("аррӏе", SanitizationErrorKind::Confusable),
+        ];
+        for (input, kind) in cases {
+            let err = sanitizer
+                .sanitize(Tainted::new(input.to_string()))
+                .unwrap_err();
+            assert_eq!(err.kind(), kind, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn string_sanitizer_hardened_accepts_ordinary_text() {
+        let sanitizer = StringSanitizer::default_limits().hardened();
+        for input in [
+            "hello world",
+            "café",
+            "Дмитрий",
+            "東京タワー",
+            "O'Neil-Smith 3rd",
+        ] {
+            assert!(
+                sanitizer.sanitize(Tainted::new(input.to_string())).is_ok(),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn string_sanitizer_normalizes_output() {
+        let nfc = StringSanitizer::default_limits().with_normalization(Normalization::Nfc);
+        let verified = nfc
+            .sanitize(Tainted::new("cafe\u{301}".to_string()))
+            .unwrap();
+        assert_eq!(verified.as_ref(), "caf\u{e9}");
+
+        let nfkc = StringSanitizer::default_limits().with_normalization(Normalization::Nfkc);
+        let verified = nfkc.sanitize(Tainted::new("ｒｏｏｔ".to_string())).unwrap();
+        assert_eq!(verified.as_ref(), "root");
+    }
+
+    #[test]
+    fn string_sanitizer_rejects_leading_combining_mark_after_normalization() {
+        // NFKC maps U+00AF MACRON to a space followed by U+0304, which is left
+        // without a base character once trimmed
+        let sanitizer = StringSanitizer::default_limits().with_normalization(Normalization::Nfkc);
+        let err = sanitizer
+            .sanitize(Tainted::new("\u{AF}".to_string()))
+            .unwrap_err();
+        assert_eq!(err.kind(), SanitizationErrorKind::MalformedInput);
+
+        // A mark following a base character is kept
+        let verified = sanitizer
+            .sanitize(Tainted::new("a\u{AF}".to_string()))
+            .unwrap();
+        assert_eq!(verified.as_ref(), "a \u{304}");
+    }
+
+    #[test]
+    fn string_sanitizer_checks_length_after_normalization() {
+        // U+FDFA is 3 bytes and expands to 33 bytes under NFKC
+        let sanitizer = StringSanitizer::new(10)
+            .unwrap()
+            .with_normalization(Normalization::Nfkc);
+        let err = sanitizer
+            .sanitize(Tainted::new("\u{FDFA}".to_string()))
+            .unwrap_err();
+        assert_eq!(err.kind(), SanitizationErrorKind::TooLong);
+    }
+
+    fn sanitize_url(
+        sanitizer: &UrlSanitizer,
+        input: &str,
+    ) -> Result<String, SanitizationErrorKind> {
+        sanitizer
+            .sanitize(Tainted::new(input.to_string()))
+            .map(|url| url.as_ref().as_str().to_string())
+            .map_err(|err| err.kind())
+    }
+
+    #[test]
+    fn url_sanitizer_accepts_and_normalizes_http_urls() {
+        let sanitizer = UrlSanitizer::new();
+
+        assert_eq!(
+            sanitize_url(&sanitizer, "  HTTPS://Example.COM:443/a/../b?q=1#frag  "),
+            Ok("https://example.com/b?q=1".to_string())
+        );
+        assert_eq!(
+            sanitize_url(&sanitizer, "http://user:pw@example.com:8080"),
+            Ok("http://example.com:8080/".to_string())
+        );
+    }
+
+    #[test]
+    fn url_sanitizer_rejects_dangerous_schemes() {
+        let sanitizer = UrlSanitizer::new();
+
+        for input in [
+            "javascript:alert(1)",
+            "data:text/html,<script>",
+            "file:///etc/passwd",
+            "//example.com/",
+            "/relative/path",
+        ] {
+            assert_eq!(
+                sanitize_url(&sanitizer, input),
+                Err(SanitizationErrorKind::MalformedInput),
+                "{input}"
+            );
+        }
+        for input in ["ftp://example.com/", "gopher://example.com/"] {
+            assert_eq!(
+                sanitize_url(&sanitizer, input),
+                Err(SanitizationErrorKind::ForbiddenPattern),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn url_sanitizer_allowed_schemes_replace_defaults() {
+        let sanitizer = UrlSanitizer::new().allow_scheme("HTTPS");
+
+        assert!(sanitize_url(&sanitizer, "https://example.com/").is_ok());
+        assert_eq!(
+            sanitize_url(&sanitizer, "http://example.com/"),
+            Err(SanitizationErrorKind::ForbiddenPattern)
+        );
+    }
+
+    #[test]
+    fn url_sanitizer_restricts_hosts() {
+        let sanitizer = UrlSanitizer::new()
+            .allow_host("example.com")
+            .allow_host("*.cdn.test");
+
+        assert!(sanitize_url(&sanitizer, "https://EXAMPLE.com./").is_ok());
+        assert!(sanitize_url(&sanitizer, "https://img.cdn.test/").is_ok());
+        for input in [
+            "https://cdn.test/",
+            "https://example.com.evil.test/",
+            "https://example.com@evil.test/",
+        ] {
+            assert_eq!(
+                sanitize_url(&sanitizer, input),
+                Err(SanitizationErrorKind::ForbiddenPattern),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn url_sanitizer_rejects_empty_control_chars_and_long_input() {
+        let sanitizer = UrlSanitizer::new().with_max_len(30);
+
+        assert_eq!(
+            sanitize_url(&sanitizer, "   "),
+            Err(SanitizationErrorKind::Empty)
+        );
+        assert_eq!(
+            sanitize_url(&sanitizer, "https://exa\tmple.com/"),
+            Err(SanitizationErrorKind::ContainsControlChars)
+        );
+        assert_eq!(
+            sanitize_url(&sanitizer, "https://example.com/a-very-long-path"),
+            Err(SanitizationErrorKind::TooLong)
+        );
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,124 @@
+//! Outbound HTTP request body sanitizer.
+
+use super::{SanitizationError, SanitizationErrorKind, Sanitizer, StringSanitizer};
+use crate::contexts::HttpBody;
+use crate::{Approval, Tainted, Verified};
+
+/// A sanitizer for text sent as an outbound HTTP request body.
+///
+/// The body is kept byte for byte: it is not trimmed or normalized. Line
+/// breaks and tabs are allowed so that multi-line documents such as pretty
+/// printed JSON pass, but every other control character is rejected, as is
+/// a body longer than the configured limit. Values verified for another
+/// context (HTML, a JSON string literal, a log line) are not bodies until
+/// they pass this sanitizer, for example through
+/// [`and_then`](Sanitizer::and_then).
+///
+/// # Security Properties
+///
+/// - Bounds the size of outbound bodies (CWE-400)
+/// - Prevents NUL bytes and other control characters from reaching the
+///   remote service or intermediate parsers
+/// - Does not leak rejected input in error messages
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{HttpBodySanitizer, SanitizationErrorKind, Sanitizer, Tainted};
+///
+/// let bodies = HttpBodySanitizer::new(1024).unwrap();
+/// let body = bodies
+///     .sanitize(Tainted::new("{\n  \"name\": \"Alice\"\n}".to_string()))
+///     .unwrap();
+/// assert_eq!(body.as_ref(), "{\n  \"name\": \"Alice\"\n}");
+///
+/// let err = bodies.sanitize(Tainted::new("a\0b".to_string())).unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::ContainsControlChars);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct HttpBodySanitizer {
+    max_len: usize,
+}
+
+impl HttpBodySanitizer {
+    /// Creates a body sanitizer accepting bodies up to `max_len` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SanitizationError` if `max_len` is 0.
+    pub fn new(max_len: usize) -> Result<Self, SanitizationError> {
+        if max_len == 0 {
+            return Err(SanitizationError::new(
+                SanitizationErrorKind::InvalidInput,
+                "max_len must be greater than 0",
+            ));
+        }
+        Ok(Self { max_len })
+    }
+}
+
+impl Sanitizer<String> for HttpBodySanitizer {
+    type Context = HttpBody;
+
+    fn sanitize(
+        &self,
+        input: Tainted<String>,
+    ) -> Result<Verified<String, HttpBody>, SanitizationError> {
+        input.sanitize_inner(|raw| {
+            // BREAKING CHANGE WARNING: Do NOT remove the length check.
+            // Bodies are buffered and forwarded in full (CWE-400).
+            if raw.len() > self.max_len {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::TooLong,
+                    format!("input exceeds maximum length of {}", self.max_len),
+                ));
+            }
+
+            let is_forbidden =
+                |c: char| StringSanitizer::is_control_char(c) && !matches!(c, '\t' | '\n' | '\r');
+            if raw.chars().any(is_forbidden) {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::ContainsControlChars,
+                    "input contains control or non-printable characters",
+                ));
+            }
+
+            // BREAKING CHANGE WARNING: Verified::new_unchecked() MUST ONLY be called AFTER
+            // the checks above; PolicyHttp sends HttpBody values without further checks.
+            Ok(Verified::new_unchecked(raw).approved_by(Approval::new(
+                "HttpBodySanitizer",
+                self,
+                false,
+            )))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_the_body_unchanged() {
+        let bodies = HttpBodySanitizer::new(64).unwrap();
+        let body = bodies
+            .sanitize(Tainted::new("  {\"a\":\t1}\r\n".to_string()))
+            .unwrap();
+        assert_eq!(body.as_ref(), "  {\"a\":\t1}\r\n");
+        assert!(!body.approval().unwrap().transformed());
+    }
+
+    #[test]
+    fn rejects_control_characters_and_long_bodies() {
+        let bodies = HttpBodySanitizer::new(4).unwrap();
+        for raw in ["a\0", "\u{1b}[0m", "\u{7f}", "\u{85}"] {
+            let err = bodies.sanitize(Tainted::new(raw.to_string())).unwrap_err();
+            assert_eq!(err.kind(), SanitizationErrorKind::ContainsControlChars);
+        }
+        let err = bodies
+            .sanitize(Tainted::new("abcde".to_string()))
+            .unwrap_err();
+        assert_eq!(err.kind(), SanitizationErrorKind::TooLong);
+        assert!(HttpBodySanitizer::new(0).is_err());
+    }
+}
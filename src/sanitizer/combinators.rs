@@ -0,0 +1,708 @@
+//! Combinators that build sanitizers out of other sanitizers.
+//!
+//! Every combinator only wraps values in `Verified` after the sanitizers it is
+//! built from have accepted them, so composing sanitizers never opens a path
+//! from `Tainted<T>` to `Verified<U>` that skips validation.
+//!
+//! Combinators pass on the [`Approval`] of the sanitizer that accepted the
+//! value, marking it transformed if the combinator changed the value.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use super::{SanitizationError, SanitizationErrorKind, Sanitizer};
+use crate::contexts::{Plain, VerificationContext};
+use crate::{Approval, Provenance, Tainted, Verified};
+
+/// Prefixes an error message with the location it came from, keeping its kind
+/// and provenance.
+fn located(location: fmt::Arguments<'_>, err: SanitizationError) -> SanitizationError {
+    let provenance = err.provenance().cloned();
+    SanitizationError::new(err.kind(), format!("{}: {}", location, err.message()))
+        .or_provenance(provenance)
+}
+
+/// Wraps sanitizer output derived from an approved value, keeping its approval.
+fn approved<U, C>(value: U, approval: Option<Approval>) -> Verified<U, C> {
+    let verified = Verified::new_unchecked(value);
+    match approval {
+        Some(approval) => verified.approved_by(approval),
+        None => verified,
+    }
+}
+
+/// Runs one sanitizer, then feeds its verified output to a second.
+///
+/// Created by [`Sanitizer::and_then`].
+pub struct AndThen<A, B, U> {
+    first: A,
+    second: B,
+    _intermediate: PhantomData<fn() -> U>,
+}
+
+impl<A, B, U> AndThen<A, B, U> {
+    pub(super) fn new(first: A, second: B) -> Self {
+        Self {
+            first,
+            second,
+            _intermediate: PhantomData,
+        }
+    }
+}
+
+impl<T, U, V, A, B> Sanitizer<T, V> for AndThen<A, B, U>
+where
+    A: Sanitizer<T, U>,
+    B: Sanitizer<U, V>,
+{
+    type Context = B::Context;
+
+    fn sanitize(&self, input: Tainted<T>) -> Result<Verified<V, B::Context>, SanitizationError> {
+        let provenance = input.provenance().cloned();
+        let intermediate = self.first.sanitize(input)?;
+        let transformed = intermediate.approval().is_some_and(Approval::transformed);
+        // Re-taint so the second sanitizer applies its own rules from scratch
+        let verified = self
+            .second
+            .sanitize(Tainted::from_parts(intermediate.into_inner(), provenance))?;
+        // The second sanitizer gave the final verdict on what the first produced
+        let approval = verified
+            .approval()
+            .map(|approval| approval.and_transformed(transformed));
+        Ok(approved(verified.into_inner(), approval))
+    }
+}
+
+impl<A: fmt::Debug, B: fmt::Debug, U> fmt::Debug for AndThen<A, B, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AndThen")
+            .field("first", &self.first)
+            .field("second", &self.second)
+            .finish()
+    }
+}
+
+impl<A: Clone, B: Clone, U> Clone for AndThen<A, B, U> {
+    fn clone(&self) -> Self {
+        Self::new(self.first.clone(), self.second.clone())
+    }
+}
+
+/// Transforms the verified output of a sanitizer, then verifies the result
+/// again.
+///
+/// Created by [`Sanitizer::map`].
+pub struct Map<A, B, U, V> {
+    first: A,
+    f: fn(U) -> V,
+    then: B,
+}
+
+impl<A, B, U, V> Map<A, B, U, V> {
+    pub(super) fn new(first: A, f: fn(U) -> V, then: B) -> Self {
+        Self { first, f, then }
+    }
+}
+
+impl<T, U, V, W, A, B> Sanitizer<T, W> for Map<A, B, U, V>
+where
+    A: Sanitizer<T, U>,
+    B: Sanitizer<V, W>,
+{
+    type Context = B::Context;
+
+    fn sanitize(&self, input: Tainted<T>) -> Result<Verified<W, B::Context>, SanitizationError> {
+        let provenance = input.provenance().cloned();
+        let verified = self.first.sanitize(input)?;
+        // BREAKING CHANGE WARNING: The output of `f` MUST be re-tainted and checked
+        // by `then`. `f` need not use its input at all (it can return a constant or
+        // read the environment), so wrapping its output directly would mint
+        // Verified values no sanitizer checked (CRITICAL BYPASS).
+        let mapped = Tainted::from_parts((self.f)(verified.into_inner()), provenance);
+        let verified = self.then.sanitize(mapped)?;
+        // `then` gave the final verdict on a value `f` changed
+        let approval = verified
+            .approval()
+            .map(|approval| approval.and_transformed(true));
+        Ok(approved(verified.into_inner(), approval))
+    }
+}
+
+impl<A: fmt::Debug, B: fmt::Debug, U, V> fmt::Debug for Map<A, B, U, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Map")
+            .field("first", &self.first)
+            .field("then", &self.then)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: Clone, B: Clone, U, V> Clone for Map<A, B, U, V> {
+    fn clone(&self) -> Self {
+        Self::new(self.first.clone(), self.f, self.then.clone())
+    }
+}
+
+/// Tries one sanitizer and falls back to a second if it rejects the input.
+///
+/// Created by [`Sanitizer::or`].
+#[derive(Debug, Clone)]
+pub struct Or<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Or<A, B> {
+    pub(super) fn new(first: A, second: B) -> Self {
+        Self { first, second }
+    }
+}
+
+impl<T, U, A, B> Sanitizer<T, U> for Or<A, B>
+where
+    T: Clone,
+    A: Sanitizer<T, U>,
+    B: Sanitizer<T, U, Context = A::Context>,
+{
+    type Context = A::Context;
+
+    fn sanitize(&self, input: Tainted<T>) -> Result<Verified<U, A::Context>, SanitizationError> {
+        self.first
+            .sanitize(input.clone())
+            .or_else(|_| self.second.sanitize(input))
+    }
+}
+
+/// Sanitizes every element of a `Tainted<Vec<T>>` with the same sanitizer.
+///
+/// The whole collection is rejected if any element is, and the error names
+/// the index of the first rejected element. An optional item limit bounds
+/// the work done on attacker-controlled collections.
+///
+/// The verified collection carries the [`Approval`] of the item sanitizer,
+/// marked transformed if any element was. Empty collections have none.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{Sanitizer, SanitizationErrorKind, StringSanitizer, Tainted, VecSanitizer};
+///
+/// let sanitizer = VecSanitizer::new(StringSanitizer::new(16).unwrap()).with_max_items(3);
+///
+/// let tags = Tainted::new(vec![" rust ".to_string(), "security".to_string()]);
+/// let verified = sanitizer.sanitize(tags).unwrap();
+/// assert_eq!(verified.as_ref(), &["rust", "security"]);
+///
+/// let tags = Tainted::new(vec!["ok".to_string(), "bad\ntag".to_string()]);
+/// let err = sanitizer.sanitize(tags).unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::ContainsControlChars);
+/// assert!(err.message().starts_with("item 1:"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct VecSanitizer<S> {
+    item: S,
+    max_items: Option<usize>,
+}
+
+impl<S> VecSanitizer<S> {
+    /// Creates a collection sanitizer applying `item` to each element.
+    pub fn new(item: S) -> Self {
+        Self {
+            item,
+            max_items: None,
+        }
+    }
+
+    /// Rejects collections with more than `max_items` elements.
+    pub fn with_max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+}
+
+impl<T, U, S> Sanitizer<Vec<T>, Vec<U>> for VecSanitizer<S>
+where
+    S: Sanitizer<T, U>,
+{
+    type Context = S::Context;
+
+    fn sanitize(
+        &self,
+        input: Tainted<Vec<T>>,
+    ) -> Result<Verified<Vec<U>, S::Context>, SanitizationError> {
+        let provenance = input.provenance().cloned();
+        let items = input.into_inner();
+
+        // Check the size before touching any element
+        if let Some(max_items) = self.max_items {
+            if items.len() > max_items {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::TooLong,
+                    format!("collection exceeds maximum of {} items", max_items),
+                )
+                .or_provenance(provenance));
+            }
+        }
+
+        let mut approval: Option<Approval> = None;
+        let verified = items
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let verified = self
+                    .item
+                    .sanitize(Tainted::from_parts(item, provenance.clone()))
+                    .map_err(|err| located(format_args!("item {}", index), err))?;
+                if let Some(item_approval) = verified.approval() {
+                    approval = Some(
+                        approval
+                            .unwrap_or(*item_approval)
+                            .and_transformed(item_approval.transformed()),
+                    );
+                }
+                Ok(verified.into_inner())
+            })
+            .collect::<Result<Vec<U>, _>>()?;
+
+        // BREAKING CHANGE WARNING: Verified::new_unchecked() MUST ONLY be called after
+        // every element has passed the item sanitizer.
+        Ok(approved(verified, approval))
+    }
+}
+
+/// Sanitizes a struct field by field.
+///
+/// The `build` function receives the tainted struct as [`Fields`] and returns
+/// the [`Fields::field`] results, each of which is only available as
+/// `Verified` after its own sanitizer accepted it. Field errors are prefixed
+/// with the field name.
+///
+/// `build` can only return [`VerifiedParts`]: a `Verified` value, an
+/// `Option` of one, or a tuple of those. The output is the parts themselves,
+/// wrapped once more to record that every field passed, so each field keeps
+/// the context its sanitizer verified it for. Assemble your own type from
+/// them after sanitization.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{
+///     Fields, Sanitizer, SanitizationErrorKind, StringSanitizer, StructSanitizer, Tainted,
+///     UrlSanitizer,
+/// };
+///
+/// struct Webhook {
+///     name: String,
+///     target: String,
+/// }
+///
+/// let sanitizer = StructSanitizer::new(|fields: &Fields<Webhook>| {
+///     let names = StringSanitizer::new(32).unwrap();
+///     let urls = UrlSanitizer::new().allow_scheme("https");
+///     Ok((
+///         fields.field("name", |w| &w.name, &names)?,
+///         fields.field("target", |w| &w.target, &urls)?,
+///     ))
+/// });
+///
+/// let webhook = Tainted::new(Webhook {
+///     name: "deploys".to_string(),
+///     target: "https://hooks.example.com/deploy".to_string(),
+/// });
+/// // `Verified<Url, HttpTarget>`, ready for `PolicyHttp`
+/// let (_name, target) = sanitizer.sanitize(webhook).unwrap().into_inner();
+/// assert_eq!(target.as_ref().host(), "hooks.example.com");
+///
+/// let webhook = Tainted::new(Webhook {
+///     name: "deploys".to_string(),
+///     target: "javascript:alert(1)".to_string(),
+/// });
+/// let err = sanitizer.sanitize(webhook).unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::MalformedInput);
+/// assert!(err.message().starts_with("target:"));
+/// ```
+///
+/// Values that did not come out of a sanitizer are rejected at compile time:
+///
+/// ```compile_fail
+/// use policy_core::{Fields, StructSanitizer};
+///
+/// // Error: `String` is not `VerifiedParts`
+/// let sanitizer = StructSanitizer::new(|_: &Fields<String>| Ok("forged".to_string()));
+/// ```
+pub struct StructSanitizer<T, P> {
+    build: fn(&Fields<T>) -> Result<P, SanitizationError>,
+}
+
+impl<T, P: VerifiedParts> StructSanitizer<T, P> {
+    /// Creates a struct sanitizer from a function that sanitizes each field.
+    pub fn new(build: fn(&Fields<T>) -> Result<P, SanitizationError>) -> Self {
+        Self { build }
+    }
+}
+
+impl<T, P: VerifiedParts> Sanitizer<T, P> for StructSanitizer<T, P> {
+    type Context = Plain;
+
+    fn sanitize(&self, input: Tainted<T>) -> Result<Verified<P>, SanitizationError> {
+        let fields = Fields {
+            provenance: input.provenance().cloned(),
+            value: input.into_inner(),
+        };
+        // BREAKING CHANGE WARNING: P MUST stay limited to VerifiedParts. `build`
+        // need not use its input, so any other output type would let it return
+        // data no sanitizer checked as Verified (CRITICAL BYPASS).
+        let parts = (self.build)(&fields)?;
+        Ok(Verified::new_unchecked(parts))
+    }
+}
+
+impl<T, P> Clone for StructSanitizer<T, P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, P> Copy for StructSanitizer<T, P> {}
+
+impl<T, P> fmt::Debug for StructSanitizer<T, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StructSanitizer").finish_non_exhaustive()
+    }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Values a [`StructSanitizer`] can return: `Verified<T, C>`,
+/// `Option<Verified<T, C>>` for optional fields, and tuples of those (up to
+/// 12, and tuples may nest).
+///
+/// Every part can only have come out of a sanitizer. This trait is sealed
+/// and cannot be implemented outside policy-core.
+pub trait VerifiedParts: sealed::Sealed {}
+
+impl<T, C: VerificationContext> sealed::Sealed for Verified<T, C> {}
+impl<T, C: VerificationContext> VerifiedParts for Verified<T, C> {}
+
+impl<T, C: VerificationContext> sealed::Sealed for Option<Verified<T, C>> {}
+impl<T, C: VerificationContext> VerifiedParts for Option<Verified<T, C>> {}
+
+macro_rules! tuple_verified_parts {
+    ($($name:ident),+) => {
+        impl<$($name: VerifiedParts),+> sealed::Sealed for ($($name,)+) {}
+        impl<$($name: VerifiedParts),+> VerifiedParts for ($($name,)+) {}
+    };
+}
+
+tuple_verified_parts!(A);
+tuple_verified_parts!(A, B);
+tuple_verified_parts!(A, B, C);
+tuple_verified_parts!(A, B, C, D);
+tuple_verified_parts!(A, B, C, D, E);
+tuple_verified_parts!(A, B, C, D, E, F);
+tuple_verified_parts!(A, B, C, D, E, F, G);
+tuple_verified_parts!(A, B, C, D, E, F, G, H);
+tuple_verified_parts!(A, B, C, D, E, F, G, H, I);
+tuple_verified_parts!(A, B, C, D, E, F, G, H, I, J);
+tuple_verified_parts!(A, B, C, D, E, F, G, H, I, J, K);
+tuple_verified_parts!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// A tainted struct whose fields can be sanitized one at a time.
+///
+/// Passed to the closure of a [`StructSanitizer`].
+pub struct Fields<T> {
+    value: T,
+    provenance: Option<Provenance>,
+}
+
+impl<T> Fields<T> {
+    /// Sanitizes one field, selected by `project`, with `sanitizer`.
+    ///
+    /// `project` must only select the field; the selected value is cloned and
+    /// handed to the sanitizer as tainted input.
+    ///
+    /// # Errors
+    ///
+    /// Returns the sanitizer's error with its message prefixed by `name`.
+    pub fn field<F, V, S>(
+        &self,
+        name: &str,
+        project: impl FnOnce(&T) -> &F,
+        sanitizer: &S,
+    ) -> Result<Verified<V, S::Context>, SanitizationError>
+    where
+        F: Clone,
+        S: Sanitizer<F, V>,
+    {
+        let raw = project(&self.value).clone();
+        sanitizer
+            .sanitize(Tainted::from_parts(raw, self.provenance.clone()))
+            .map_err(|err| located(format_args!("{}", name), err))
+    }
+}
+
+impl<T> fmt::Debug for Fields<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // SECURITY: the fields are still tainted
+        f.write_str("Fields { <redacted> }")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sanitizer::AcceptAllSanitizer;
+    use crate::StringSanitizer;
+
+    /// Parses a verified decimal string into a number.
+    #[derive(Debug, Clone, Copy)]
+    struct DigitsSanitizer;
+
+    impl Sanitizer<String, u32> for DigitsSanitizer {
+        type Context = Plain;
+
+        fn sanitize(&self, input: Tainted<String>) -> Result<Verified<u32>, SanitizationError> {
+            input
+                .into_inner()
+                .parse()
+                .map(Verified::new_unchecked)
+                .map_err(|_| SanitizationError::new(SanitizationErrorKind::MalformedInput, "nan"))
+        }
+    }
+
+    fn strings(items: &[&str]) -> Tainted<Vec<String>> {
+        Tainted::new(items.iter().map(|s| s.to_string()).collect())
+    }
+
+    #[test]
+    fn item_errors_keep_the_collection_provenance() {
+        let tags = Tainted::new(vec!["ok".to_string(), "a\nb".to_string()])
+            .with_provenance(crate::Provenance::body("tags"));
+        let err = VecSanitizer::new(StringSanitizer::new(8).unwrap())
+            .sanitize(tags)
+            .unwrap_err();
+
+        assert!(err.message().starts_with("item 1:"));
+        assert_eq!(err.provenance(), Some(&crate::Provenance::body("tags")));
+    }
+
+    #[test]
+    fn vec_sanitizer_carries_the_item_approval() {
+        let sanitizer = VecSanitizer::new(StringSanitizer::new(8).unwrap());
+
+        let tags = sanitizer
+            .sanitize(Tainted::new(vec!["a".to_string(), "b".to_string()]))
+            .unwrap();
+        let approval = tags.approval().unwrap();
+        assert_eq!(approval.sanitizer(), "StringSanitizer");
+        assert!(!approval.transformed());
+
+        let tags = sanitizer
+            .sanitize(Tainted::new(vec!["a".to_string(), " b ".to_string()]))
+            .unwrap();
+        assert!(tags.approval().unwrap().transformed());
+
+        let empty = sanitizer.sanitize(Tainted::new(Vec::new())).unwrap();
+        assert!(empty.approval().is_none());
+    }
+
+    #[test]
+    fn combinators_keep_the_final_approval() {
+        let slug = crate::IdentifierSanitizer::slug(16).unwrap();
+        let sanitizer = StringSanitizer::new(16).unwrap().and_then(slug.clone());
+        let verified = sanitizer
+            .sanitize(Tainted::new(" rust ".to_string()))
+            .unwrap();
+        let approval = verified.approval().unwrap();
+        assert_eq!(approval.sanitizer(), "IdentifierSanitizer");
+        // Trimmed by the first sanitizer
+        assert!(approval.transformed());
+
+        let sanitizer = StringSanitizer::new(16)
+            .unwrap()
+            .map(|s| s.replace('_', "-"), slug);
+        let verified = sanitizer
+            .sanitize(Tainted::new("rust_lang".to_string()))
+            .unwrap();
+        let approval = verified.approval().unwrap();
+        assert_eq!(approval.sanitizer(), "IdentifierSanitizer");
+        assert!(approval.transformed());
+    }
+
+    #[test]
+    fn and_then_runs_both_sanitizers_in_order() {
+        let sanitizer = StringSanitizer::new(8).unwrap().and_then(DigitsSanitizer);
+
+        let verified = sanitizer
+            .sanitize(Tainted::new(" 42 ".to_string()))
+            .unwrap();
+        assert_eq!(verified.into_inner(), 42);
+
+        let err = sanitizer
+            .sanitize(Tainted::new("123456789".to_string()))
+            .unwrap_err();
+        assert_eq!(err.kind(), SanitizationErrorKind::TooLong);
+
+        let err = sanitizer
+            .sanitize(Tainted::new("4x".to_string()))
+            .unwrap_err();
+        assert_eq!(err.kind(), SanitizationErrorKind::MalformedInput);
+    }
+
+    #[test]
+    fn map_transforms_only_verified_values() {
+        let names = StringSanitizer::new(16).unwrap();
+        let sanitizer = names.map(|s| s.to_lowercase(), names);
+
+        let verified = sanitizer
+            .sanitize(Tainted::new(" Alice ".to_string()))
+            .unwrap();
+        assert_eq!(verified.as_ref(), "alice");
+
+        let err = sanitizer
+            .sanitize(Tainted::new("a\nb".to_string()))
+            .unwrap_err();
+        assert_eq!(err.kind(), SanitizationErrorKind::ContainsControlChars);
+    }
+
+    #[test]
+    fn map_verifies_the_mapped_value_again() {
+        let names = StringSanitizer::new(64).unwrap();
+        // `f` ignores its input; its output is still checked by `then`
+        let sanitizer = names.map(|_| "evil\r\nX-Injected: 1".to_string(), names);
+
+        let err = sanitizer
+            .sanitize(Tainted::new("ok".to_string()))
+            .unwrap_err();
+        assert_eq!(err.kind(), SanitizationErrorKind::ContainsControlChars);
+    }
+
+    #[test]
+    fn or_falls_back_to_second_sanitizer() {
+        let sanitizer = StringSanitizer::new(1)
+            .unwrap()
+            .or(StringSanitizer::default_limits());
+        let verified = sanitizer
+            .sanitize(Tainted::new(" ok ".to_string()))
+            .unwrap();
+        assert_eq!(verified.as_ref(), "ok");
+
+        let sanitizer = StringSanitizer::new(2)
+            .unwrap()
+            .or(StringSanitizer::new(3).unwrap());
+        let err = sanitizer
+            .sanitize(Tainted::new("abcd".to_string()))
+            .unwrap_err();
+        assert_eq!(err.kind(), SanitizationErrorKind::TooLong);
+        assert!(err.message().ends_with("of 3"));
+    }
+
+    #[test]
+    fn vec_sanitizer_reports_failing_index() {
+        let sanitizer = VecSanitizer::new(StringSanitizer::new(8).unwrap());
+
+        let verified = sanitizer.sanitize(strings(&[" a ", "b"])).unwrap();
+        assert_eq!(verified.as_ref(), &["a", "b"]);
+
+        let err = sanitizer.sanitize(strings(&["a", "b", ""])).unwrap_err();
+        assert_eq!(err.kind(), SanitizationErrorKind::Empty);
+        assert!(err.message().starts_with("item 2: "));
+
+        assert!(sanitizer
+            .sanitize(strings(&[]))
+            .unwrap()
+            .as_ref()
+            .is_empty());
+    }
+
+    #[test]
+    fn vec_sanitizer_enforces_max_items() {
+        let sanitizer = VecSanitizer::new(AcceptAllSanitizer).with_max_items(2);
+
+        assert!(sanitizer.sanitize(Tainted::new(vec![1, 2])).is_ok());
+        let err = sanitizer.sanitize(Tainted::new(vec![1, 2, 3])).unwrap_err();
+        assert_eq!(err.kind(), SanitizationErrorKind::TooLong);
+    }
+
+    #[test]
+    fn struct_sanitizer_prefixes_field_errors() {
+        struct Signup {
+            name: String,
+            age: String,
+        }
+
+        let sanitizer = StructSanitizer::new(|fields: &Fields<Signup>| {
+            let names = StringSanitizer::new(16).unwrap();
+            let ages = StringSanitizer::new(3).unwrap().and_then(DigitsSanitizer);
+            Ok((
+                fields.field("name", |s| &s.name, &names)?,
+                fields.field("age", |s| &s.age, &ages)?,
+            ))
+        });
+
+        let verified = sanitizer
+            .sanitize(Tainted::new(Signup {
+                name: "Alice".to_string(),
+                age: "37".to_string(),
+            }))
+            .unwrap();
+        let (name, age) = verified.into_inner();
+        assert_eq!(name.as_ref(), "Alice");
+        assert_eq!(age.into_inner(), 37);
+
+        let err = sanitizer
+            .sanitize(Tainted::new(Signup {
+                name: "SECRET\u{0}".to_string(),
+                age: "37".to_string(),
+            }))
+            .unwrap_err();
+        assert_eq!(err.kind(), SanitizationErrorKind::ContainsControlChars);
+        assert!(err.message().starts_with("name: "));
+        assert!(!err.message().contains("SECRET"));
+    }
+}
+
+#[cfg(test)]
+mod proptests {
+    use super::*;
+    use crate::StringSanitizer;
+    use proptest::prelude::*;
+
+    proptest! {
+        /// Property: A collection is accepted exactly when every element is
+        #[test]
+        fn proptest_vec_sanitizer_matches_item_sanitizer(
+            items in prop::collection::vec("[a-z \n]{0,12}", 0..8)
+        ) {
+            let item = StringSanitizer::new(8).unwrap();
+            let expected: Result<Vec<String>, _> = items
+                .iter()
+                .map(|i| item.sanitize(Tainted::new(i.clone())).map(Verified::into_inner))
+                .collect();
+
+            let actual = VecSanitizer::new(item).sanitize(Tainted::new(items));
+
+            match expected {
+                Ok(expected) => prop_assert_eq!(actual.unwrap().into_inner(), expected),
+                Err(err) => prop_assert_eq!(actual.unwrap_err().kind(), err.kind()),
+            }
+        }
+
+        /// Property: and_then accepts only input both sanitizers accept
+        #[test]
+        fn proptest_and_then_is_intersection(input in "[ a-z0-9\t]{0,24}") {
+            let first = StringSanitizer::new(16).unwrap();
+            let second = StringSanitizer::new(8).unwrap();
+            let chained = first.and_then(second);
+
+            let expected = first
+                .sanitize(Tainted::new(input.clone()))
+                .and_then(|v| second.sanitize(Tainted::new(v.into_inner())));
+            let actual = chained.sanitize(Tainted::new(input));
+
+            prop_assert_eq!(actual, expected);
+        }
+    }
+}
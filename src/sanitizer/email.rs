@@ -0,0 +1,268 @@
+//! Email address sanitizer.
+
+use std::collections::BTreeSet;
+
+use super::{checked_trim, SanitizationError, SanitizationErrorKind, Sanitizer};
+use crate::contexts::Plain;
+use crate::http::url::{host_matches, host_pattern, Host};
+use crate::{Approval, Tainted, Verified};
+
+/// Maximum length of an address (RFC 5321 path limit minus the angle brackets).
+const MAX_ADDRESS_LEN: usize = 254;
+/// Maximum length of the local part (RFC 5321).
+const MAX_LOCAL_LEN: usize = 64;
+
+/// A sanitizer for email addresses.
+///
+/// Accepts a conservative subset of RFC 5321 addresses, `local@domain`:
+///
+/// - The local part uses ASCII letters, digits and `.`, `_`, `%`, `+`, `-`,
+///   without leading, trailing or consecutive dots
+/// - The domain is a DNS name with at least two labels; it is lowercased
+/// - Quoted local parts, comments, IP-literal domains and non-ASCII
+///   addresses are rejected
+///
+/// Quotes, backticks and pipes are valid in RFC 5322 local parts but are
+/// rejected here, since they are rarely legitimate and often reach a shell,
+/// template or header later.
+///
+/// # Security Properties
+///
+/// - Prevents header injection (CRLF and other control characters are rejected)
+/// - Rejects characters with special meaning in shells, SQL and markup
+/// - Does not leak rejected input in error messages
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{EmailSanitizer, SanitizationErrorKind, Sanitizer, Tainted};
+///
+/// let sanitizer = EmailSanitizer::new();
+///
+/// let email = sanitizer
+///     .sanitize(Tainted::new(" Alice.Smith+news@Example.COM ".to_string()))
+///     .unwrap();
+/// assert_eq!(email.as_ref(), "Alice.Smith+news@example.com");
+///
+/// let err = sanitizer
+///     .sanitize(Tainted::new("alice@example.com\r\nBcc: eve@evil.test".to_string()))
+///     .unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::ContainsControlChars);
+///
+/// let err = sanitizer
+///     .sanitize(Tainted::new("\"alice\"@example.com".to_string()))
+///     .unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::MalformedInput);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EmailSanitizer {
+    domains: BTreeSet<String>,
+}
+
+impl EmailSanitizer {
+    /// Creates a sanitizer accepting addresses at any domain.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts accepted addresses to a domain (case-insensitive).
+    ///
+    /// Without any allowed domains every well-formed domain is accepted. A
+    /// leading `*.` allows every subdomain of the remaining name.
+    pub fn allow_domain(mut self, domain: impl Into<String>) -> Self {
+        self.domains.insert(host_pattern(domain.into()));
+        self
+    }
+}
+
+impl Sanitizer<String> for EmailSanitizer {
+    type Context = Plain;
+
+    fn sanitize(&self, input: Tainted<String>) -> Result<Verified<String>, SanitizationError> {
+        input.sanitize_inner(|raw| {
+            let trimmed = checked_trim(&raw, MAX_ADDRESS_LEN)?;
+            let malformed = |message: &str| {
+                SanitizationError::new(SanitizationErrorKind::MalformedInput, message)
+            };
+
+            let (local, domain) = trimmed
+                .split_once('@')
+                .ok_or_else(|| malformed("address must contain '@'"))?;
+
+            if local.is_empty() || local.len() > MAX_LOCAL_LEN {
+                return Err(malformed("local part must be 1 to 64 characters"));
+            }
+            if !local.bytes().all(is_local_byte) {
+                return Err(malformed("local part contains a disallowed character"));
+            }
+            if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+                return Err(malformed("local part has a misplaced dot"));
+            }
+
+            let domain = domain.to_ascii_lowercase();
+            if !is_domain(&domain) {
+                return Err(malformed("domain is not a valid DNS name"));
+            }
+
+            if !self.domains.is_empty() {
+                let host = Host::Domain(domain.clone());
+                if !self
+                    .domains
+                    .iter()
+                    .any(|pattern| host_matches(pattern, &host))
+                {
+                    return Err(SanitizationError::new(
+                        SanitizationErrorKind::NotAllowed,
+                        "email domain is not allowed",
+                    ));
+                }
+            }
+
+            // BREAKING CHANGE WARNING: Verified::new_unchecked() MUST ONLY be called AFTER
+            // all validation checks pass. Moving this before validation creates a CRITICAL BYPASS.
+            let address = format!("{}@{}", local, domain);
+            let transformed = address != raw;
+            Ok(Verified::new_unchecked(address).approved_by(Approval::new(
+                "EmailSanitizer",
+                self,
+                transformed,
+            )))
+        })
+    }
+}
+
+fn is_local_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'.' | b'_' | b'%' | b'+' | b'-')
+}
+
+/// Checks for a lowercase DNS name with at least two labels and a
+/// non-numeric top-level label. `@` in the domain also fails here.
+fn is_domain(domain: &str) -> bool {
+    let labels: Vec<&str> = domain.split('.').collect();
+    let tld = labels.last().copied().unwrap_or_default();
+
+    labels.len() >= 2
+        && !tld.bytes().all(|b| b.is_ascii_digit())
+        && labels.iter().all(|label| {
+            (1..=63).contains(&label.len())
+                && label
+                    .bytes()
+                    .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+                && !label.starts_with('-')
+                && !label.ends_with('-')
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sanitize(sanitizer: &EmailSanitizer, input: &str) -> Result<String, SanitizationErrorKind> {
+        sanitizer
+            .sanitize(Tainted::new(input.to_string()))
+            .map(Verified::into_inner)
+            .map_err(|err| err.kind())
+    }
+
+    #[test]
+    fn accepts_common_addresses() {
+        let sanitizer = EmailSanitizer::new();
+
+        for input in [
+            "alice@example.com",
+            "a.b_c%d+e-f@mail.example.co.uk",
+            "x@a-b.io",
+            "123@example.org",
+        ] {
+            assert_eq!(sanitize(&sanitizer, input), Ok(input.to_string()));
+        }
+    }
+
+    #[test]
+    fn lowercases_only_the_domain() {
+        assert_eq!(
+            sanitize(&EmailSanitizer::new(), "Bob@Example.Com"),
+            Ok("Bob@example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_addresses() {
+        let sanitizer = EmailSanitizer::new();
+
+        for input in [
+            "alice",
+            "@example.com",
+            "alice@",
+            "alice@@example.com",
+            "a@b@example.com",
+            ".alice@example.com",
+            "alice.@example.com",
+            "al..ice@example.com",
+            "\"alice\"@example.com",
+            "al ice@example.com",
+            "alice`id`@example.com",
+            "alice@localhost",
+            "alice@[127.0.0.1]",
+            "alice@127.0.0.1",
+            "alice@-example.com",
+            "alice@example..com",
+            "ålice@example.com",
+            "alice@exämple.com",
+        ] {
+            assert_eq!(
+                sanitize(&sanitizer, input),
+                Err(SanitizationErrorKind::MalformedInput),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn enforces_length_limits() {
+        let sanitizer = EmailSanitizer::new();
+
+        let local = "a".repeat(65);
+        assert_eq!(
+            sanitize(&sanitizer, &format!("{}@example.com", local)),
+            Err(SanitizationErrorKind::MalformedInput)
+        );
+
+        let domain = format!("{}.com", "a.".repeat(130));
+        assert_eq!(
+            sanitize(&sanitizer, &format!("alice@{}", domain)),
+            Err(SanitizationErrorKind::TooLong)
+        );
+    }
+
+    #[test]
+    fn rejects_empty_and_control_chars() {
+        let sanitizer = EmailSanitizer::new();
+
+        assert_eq!(
+            sanitize(&sanitizer, "  "),
+            Err(SanitizationErrorKind::Empty)
+        );
+        assert_eq!(
+            sanitize(&sanitizer, "alice@example.com\nBcc: x@y.z"),
+            Err(SanitizationErrorKind::ContainsControlChars)
+        );
+    }
+
+    #[test]
+    fn restricts_domains() {
+        let sanitizer = EmailSanitizer::new()
+            .allow_domain("Example.com")
+            .allow_domain("*.corp.test");
+
+        assert!(sanitize(&sanitizer, "a@example.com").is_ok());
+        assert!(sanitize(&sanitizer, "a@eu.corp.test").is_ok());
+        for input in ["a@corp.test", "a@example.com.evil.test", "a@evil.test"] {
+            assert_eq!(
+                sanitize(&sanitizer, input),
+                Err(SanitizationErrorKind::NotAllowed),
+                "{input}"
+            );
+        }
+    }
+}
@@ -0,0 +1,404 @@
+//! Output encoders that escape untrusted text for a target context.
+//!
+//! Unlike rejecting sanitizers such as [`StringSanitizer`](crate::StringSanitizer),
+//! encoders accept almost any input and transform it so that it can only ever
+//! be read as data in its context. Each encoder produces a `Verified` value
+//! for its own [context](crate::contexts).
+
+use std::fmt::Write as _;
+
+use super::{SanitizationError, SanitizationErrorKind, Sanitizer};
+use crate::contexts::{Html, HtmlAttribute, JsonString, ShellArg, UrlPathSegment, UrlQueryValue};
+use crate::{Approval, Tainted, Verified};
+
+/// Default maximum input length for encoders, in bytes.
+const DEFAULT_MAX_LEN: usize = 8192;
+
+/// Rejects input longer than `max_len` bytes.
+fn check_len(raw: &str, max_len: usize) -> Result<(), SanitizationError> {
+    // BREAKING CHANGE WARNING: Do NOT remove the length check.
+    // Escaping can grow input several times over (CWE-400).
+    if raw.len() > max_len {
+        return Err(SanitizationError::new(
+            SanitizationErrorKind::TooLong,
+            format!("input exceeds maximum length of {}", max_len),
+        ));
+    }
+    Ok(())
+}
+
+macro_rules! encoders {
+    ($(
+        $(#[$doc:meta])*
+        $name:ident => $context:ty, $encode:path;
+    )*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Clone, Copy)]
+            pub struct $name {
+                max_len: usize,
+            }
+
+            impl $name {
+                /// Creates an encoder accepting input up to 8192 bytes.
+                pub fn new() -> Self {
+                    Self {
+                        max_len: DEFAULT_MAX_LEN,
+                    }
+                }
+
+                /// Sets the maximum accepted input length in bytes.
+                pub fn with_max_len(mut self, max_len: usize) -> Self {
+                    self.max_len = max_len;
+                    self
+                }
+            }
+
+            impl Default for $name {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
+            impl Sanitizer<String> for $name {
+                type Context = $context;
+
+                fn sanitize(
+                    &self,
+                    input: Tainted<String>,
+                ) -> Result<Verified<String, $context>, SanitizationError> {
+                    input.sanitize_inner(|raw| {
+                        check_len(&raw, self.max_len)?;
+                        let encoded = $encode(&raw)?;
+                        // BREAKING CHANGE WARNING: Only the encoded form may be wrapped.
+                        // Wrapping `raw` lets the input break out of its context.
+                        let transformed = encoded != raw;
+                        Ok(Verified::new_unchecked(encoded).approved_by(Approval::new(
+                            stringify!($name),
+                            self,
+                            transformed,
+                        )))
+                    })
+                }
+            }
+        )*
+    };
+}
+
+encoders! {
+    /// Escapes text for HTML element content and quoted attribute values.
+    ///
+    /// Replaces `&`, `<`, `>`, `"` and `'` with character references and
+    /// leaves everything else unchanged. The output is verified for the
+    /// [`Html`] context. It is NOT safe in unquoted attributes, URLs,
+    /// `<script>` or `<style>` blocks; use [`HtmlAttributeEncoder`] for
+    /// unquoted attributes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{HtmlEncoder, Sanitizer, Tainted};
+    ///
+    /// let html = HtmlEncoder::new()
+    ///     .sanitize(Tainted::new("<b>Tom & \"Jerry\"</b>".to_string()))
+    ///     .unwrap();
+    /// assert_eq!(html.as_ref(), "&lt;b&gt;Tom &amp; &quot;Jerry&quot;&lt;/b&gt;");
+    /// ```
+    HtmlEncoder => Html, encode_html;
+
+    /// Escapes text for any HTML attribute value, quoted or unquoted.
+    ///
+    /// Replaces every ASCII character other than letters and digits with a
+    /// hexadecimal character reference (`&#x20;` for a space). Non-ASCII
+    /// characters are kept. The output is verified for the [`HtmlAttribute`]
+    /// context. It is NOT safe in URL-valued attributes such as `href`, or in
+    /// event handler attributes such as `onclick`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{HtmlAttributeEncoder, Sanitizer, Tainted};
+    ///
+    /// let value = HtmlAttributeEncoder::new()
+    ///     .sanitize(Tainted::new("x onmouseover=alert(1)".to_string()))
+    ///     .unwrap();
+    /// assert_eq!(value.as_ref(), "x&#x20;onmouseover&#x3d;alert&#x28;1&#x29;");
+    /// ```
+    HtmlAttributeEncoder => HtmlAttribute, encode_html_attribute;
+
+    /// Percent-encodes text as a single URL path segment.
+    ///
+    /// Every byte outside the RFC 3986 unreserved set (`A-Z a-z 0-9 - . _ ~`)
+    /// is percent-encoded, so the output cannot contain `/`, `?` or `#`.
+    /// Empty input and the dot segments `.` and `..` are rejected with
+    /// `ForbiddenPattern`, since they would change the path rather than name
+    /// a segment. The output is verified for the [`UrlPathSegment`] context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{PathSegmentEncoder, SanitizationErrorKind, Sanitizer, Tainted};
+    ///
+    /// let encoder = PathSegmentEncoder::new();
+    ///
+    /// let segment = encoder.sanitize(Tainted::new("../etc/passwd".to_string())).unwrap();
+    /// assert_eq!(segment.as_ref(), "..%2Fetc%2Fpasswd");
+    ///
+    /// let err = encoder.sanitize(Tainted::new("..".to_string())).unwrap_err();
+    /// assert_eq!(err.kind(), SanitizationErrorKind::ForbiddenPattern);
+    /// ```
+    PathSegmentEncoder => UrlPathSegment, encode_path_segment;
+
+    /// Percent-encodes text as a URL query parameter name or value.
+    ///
+    /// Every byte outside the RFC 3986 unreserved set (`A-Z a-z 0-9 - . _ ~`)
+    /// is percent-encoded; spaces become `%20`. The output cannot contain `&`,
+    /// `=`, `+` or `#`, so it cannot add parameters or end the query. The
+    /// output is verified for the [`UrlQueryValue`] context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{QueryValueEncoder, Sanitizer, Tainted};
+    ///
+    /// let value = QueryValueEncoder::new()
+    ///     .sanitize(Tainted::new("a b&admin=1".to_string()))
+    ///     .unwrap();
+    /// assert_eq!(value.as_ref(), "a%20b%26admin%3D1");
+    /// ```
+    QueryValueEncoder => UrlQueryValue, encode_query_value;
+
+    /// Quotes text as a single POSIX shell argument.
+    ///
+    /// Wraps the input in single quotes and replaces each `'` with `'\''`,
+    /// so the shell performs no expansion, splitting or globbing on it.
+    /// Input containing a NUL byte is rejected with `ContainsControlChars`,
+    /// since no argument can hold one. The output is verified for the
+    /// [`ShellArg`] context and includes the surrounding quotes.
+    ///
+    /// Quoting keeps the value a single argument; it does not stop a command
+    /// from interpreting an argument that starts with `-` as an option. Put
+    /// `--` before untrusted arguments where the command supports it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{Sanitizer, ShellQuoter, Tainted};
+    ///
+    /// let arg = ShellQuoter::new()
+    ///     .sanitize(Tainted::new("it's; rm -rf ~".to_string()))
+    ///     .unwrap();
+    /// assert_eq!(arg.as_ref(), r"'it'\''s; rm -rf ~'");
+    /// ```
+    ShellQuoter => ShellArg, quote_shell;
+
+    /// Escapes text as a JSON string literal.
+    ///
+    /// Produces a complete literal including the surrounding double quotes.
+    /// `"` and `\` are backslash-escaped, control characters use their short
+    /// or `\u00XX` escapes, and `<`, `>`, `&`, U+2028 and U+2029 are written as
+    /// `\uXXXX` so the literal is also safe inside an HTML `<script>` block and
+    /// in JavaScript. The output is verified for the [`JsonString`] context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{JsonStringEncoder, Sanitizer, Tainted};
+    ///
+    /// let json = JsonStringEncoder::new()
+    ///     .sanitize(Tainted::new("\"}</script>\n".to_string()))
+    ///     .unwrap();
+    /// assert_eq!(json.as_ref(), r#""\"}\u003c/script\u003e\n""#);
+    /// ```
+    JsonStringEncoder => JsonString, encode_json_string;
+}
+
+fn encode_html(raw: &str) -> Result<String, SanitizationError> {
+    let mut out = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#x27;"),
+            c => out.push(c),
+        }
+    }
+    Ok(out)
+}
+
+fn encode_html_attribute(raw: &str) -> Result<String, SanitizationError> {
+    let mut out = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        if c.is_ascii_alphanumeric() || !c.is_ascii() {
+            out.push(c);
+        } else {
+            let _ = write!(out, "&#x{:x};", c as u32);
+        }
+    }
+    Ok(out)
+}
+
+/// Percent-encodes every byte outside the RFC 3986 unreserved set.
+fn percent_encode(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    for b in raw.bytes() {
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+            out.push(char::from(b));
+        } else {
+            let _ = write!(out, "%{:02X}", b);
+        }
+    }
+    out
+}
+
+fn encode_path_segment(raw: &str) -> Result<String, SanitizationError> {
+    // BREAKING CHANGE WARNING: Dot segments MUST be rejected. "." and ".." are
+    // unreserved, so encoding leaves them intact and they traverse the path (CWE-22).
+    if matches!(raw, "" | "." | "..") {
+        return Err(SanitizationError::new(
+            SanitizationErrorKind::ForbiddenPattern,
+            "input is empty or a dot segment",
+        ));
+    }
+    Ok(percent_encode(raw))
+}
+
+fn encode_query_value(raw: &str) -> Result<String, SanitizationError> {
+    Ok(percent_encode(raw))
+}
+
+fn quote_shell(raw: &str) -> Result<String, SanitizationError> {
+    if raw.contains('\0') {
+        return Err(SanitizationError::new(
+            SanitizationErrorKind::ContainsControlChars,
+            "input contains a NUL byte",
+        ));
+    }
+
+    let mut out = String::with_capacity(raw.len() + 2);
+    out.push('\'');
+    for c in raw.chars() {
+        if c == '\'' {
+            // Close the quote, emit an escaped quote, reopen
+            out.push_str("'\\''");
+        } else {
+            out.push(c);
+        }
+    }
+    out.push('\'');
+    Ok(out)
+}
+
+fn encode_json_string(raw: &str) -> Result<String, SanitizationError> {
+    let mut out = String::with_capacity(raw.len() + 2);
+    out.push('"');
+    for c in raw.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{08}' => out.push_str("\\b"),
+            '\u{0C}' => out.push_str("\\f"),
+            c if c < '\u{20}' || matches!(c, '<' | '>' | '&' | '\u{2028}' | '\u{2029}') => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode<S: Sanitizer<String>>(
+        encoder: &S,
+        input: &str,
+    ) -> Result<String, SanitizationErrorKind> {
+        encoder
+            .sanitize(Tainted::new(input.to_string()))
+            .map(Verified::into_inner)
+            .map_err(|err| err.kind())
+    }
+
+    #[test]
+    fn html_escapes_markup_and_quotes() {
+        assert_eq!(
+            encode(&HtmlEncoder::new(), r#"<a href='x'>"&"</a>"#),
+            Ok("&lt;a href=&#x27;x&#x27;&gt;&quot;&amp;&quot;&lt;/a&gt;".to_string())
+        );
+        assert_eq!(
+            encode(&HtmlEncoder::new(), "plain café"),
+            Ok("plain café".to_string())
+        );
+    }
+
+    #[test]
+    fn html_attribute_escapes_all_ascii_punctuation() {
+        assert_eq!(
+            encode(&HtmlAttributeEncoder::new(), "a b=`c`/é"),
+            Ok("a&#x20;b&#x3d;&#x60;c&#x60;&#x2f;é".to_string())
+        );
+    }
+
+    #[test]
+    fn path_segment_encodes_separators_and_rejects_dot_segments() {
+        let encoder = PathSegmentEncoder::new();
+
+        assert_eq!(encode(&encoder, "a/b?c#d"), Ok("a%2Fb%3Fc%23d".to_string()));
+        assert_eq!(encode(&encoder, "ü"), Ok("%C3%BC".to_string()));
+        assert_eq!(encode(&encoder, "..."), Ok("...".to_string()));
+        for input in ["", ".", ".."] {
+            assert_eq!(
+                encode(&encoder, input),
+                Err(SanitizationErrorKind::ForbiddenPattern),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn query_value_encodes_delimiters() {
+        assert_eq!(
+            encode(&QueryValueEncoder::new(), "x+y=1&z#"),
+            Ok("x%2By%3D1%26z%23".to_string())
+        );
+        assert_eq!(encode(&QueryValueEncoder::new(), ""), Ok(String::new()));
+    }
+
+    #[test]
+    fn shell_quotes_single_quotes_and_rejects_nul() {
+        assert_eq!(encode(&ShellQuoter::new(), ""), Ok("''".to_string()));
+        assert_eq!(
+            encode(&ShellQuoter::new(), "$(id) `id` 'x'"),
+            Ok(r"'$(id) `id` '\''x'\'''".to_string())
+        );
+        assert_eq!(
+            encode(&ShellQuoter::new(), "a\0b"),
+            Err(SanitizationErrorKind::ContainsControlChars)
+        );
+    }
+
+    #[test]
+    fn json_escapes_quotes_controls_and_script_breakers() {
+        assert_eq!(
+            encode(&JsonStringEncoder::new(), "a\"b\\c\u{1}\u{2028}<&>"),
+            Ok(r#""a\"b\\c\u0001\u2028\u003c\u0026\u003e""#.to_string())
+        );
+    }
+
+    #[test]
+    fn encoders_enforce_max_len() {
+        assert_eq!(
+            encode(&HtmlEncoder::new().with_max_len(3), "abcd"),
+            Err(SanitizationErrorKind::TooLong)
+        );
+        assert!(encode(&JsonStringEncoder::new().with_max_len(4), "abcd").is_ok());
+    }
+}
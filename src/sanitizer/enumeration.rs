@@ -0,0 +1,177 @@
+//! Allow-listed enum value sanitizer.
+
+use std::fmt;
+
+use super::{SanitizationError, SanitizationErrorKind, Sanitizer};
+use crate::contexts::Plain;
+use crate::{Approval, Tainted, Verified};
+
+/// A sanitizer that maps one of a fixed set of strings to a value.
+///
+/// Each allowed input string is paired with the value it produces, typically
+/// an enum variant. Anything else fails with
+/// [`SanitizationErrorKind::NotAllowed`]; the error lists the allowed
+/// strings, never the rejected one.
+///
+/// Matching is exact after trimming surrounding whitespace, unless
+/// [`case_insensitive`](Self::case_insensitive) is set (ASCII only).
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{EnumSanitizer, SanitizationErrorKind, Sanitizer, Tainted};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum SortOrder {
+///     Asc,
+///     Desc,
+/// }
+///
+/// let sanitizer =
+///     EnumSanitizer::new([("asc", SortOrder::Asc), ("desc", SortOrder::Desc)]).case_insensitive();
+///
+/// let order = sanitizer.sanitize(Tainted::new("DESC".to_string())).unwrap();
+/// assert_eq!(order.into_inner(), SortOrder::Desc);
+///
+/// let err = sanitizer
+///     .sanitize(Tainted::new("name; DROP TABLE users".to_string()))
+///     .unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::NotAllowed);
+/// assert_eq!(err.message(), "expected one of: asc, desc");
+/// ```
+#[derive(Clone)]
+pub struct EnumSanitizer<E> {
+    variants: Vec<(String, E)>,
+    case_insensitive: bool,
+}
+
+impl<E> EnumSanitizer<E> {
+    /// Creates a sanitizer accepting exactly the given strings.
+    pub fn new<S>(variants: impl IntoIterator<Item = (S, E)>) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            variants: variants
+                .into_iter()
+                .map(|(name, value)| (name.into(), value))
+                .collect(),
+            case_insensitive: false,
+        }
+    }
+
+    /// Matches input strings ignoring ASCII case.
+    pub fn case_insensitive(mut self) -> Self {
+        self.case_insensitive = true;
+        self
+    }
+}
+
+impl<E: Clone> Sanitizer<String, E> for EnumSanitizer<E> {
+    type Context = Plain;
+
+    fn sanitize(&self, input: Tainted<String>) -> Result<Verified<E>, SanitizationError> {
+        input.sanitize_inner(|raw| {
+            let trimmed = raw.trim();
+
+            if trimmed.is_empty() {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::Empty,
+                    "input is empty or contains only whitespace",
+                ));
+            }
+
+            let matches = |name: &str| {
+                if self.case_insensitive {
+                    name.eq_ignore_ascii_case(trimmed)
+                } else {
+                    name == trimmed
+                }
+            };
+            match self.variants.iter().find(|(name, _)| matches(name)) {
+                // The value comes from the allowlist, not from the input
+                Some((name, value)) => Ok(Verified::new_unchecked(value.clone())
+                    .approved_by(Approval::new("EnumSanitizer", self, *name != raw))),
+                None => {
+                    let names: Vec<&str> = self
+                        .variants
+                        .iter()
+                        .map(|(name, _)| name.as_str())
+                        .collect();
+                    Err(SanitizationError::new(
+                        SanitizationErrorKind::NotAllowed,
+                        format!("expected one of: {}", names.join(", ")),
+                    ))
+                }
+            }
+        })
+    }
+}
+
+impl<E> fmt::Debug for EnumSanitizer<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<&str> = self
+            .variants
+            .iter()
+            .map(|(name, _)| name.as_str())
+            .collect();
+        f.debug_struct("EnumSanitizer")
+            .field("variants", &names)
+            .field("case_insensitive", &self.case_insensitive)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sanitize<E: Clone>(
+        sanitizer: &EnumSanitizer<E>,
+        input: &str,
+    ) -> Result<E, SanitizationErrorKind> {
+        sanitizer
+            .sanitize(Tainted::new(input.to_string()))
+            .map(Verified::into_inner)
+            .map_err(|err| err.kind())
+    }
+
+    #[test]
+    fn matches_exactly_by_default() {
+        let sanitizer = EnumSanitizer::new([("read", 1), ("write", 2)]);
+
+        assert_eq!(sanitize(&sanitizer, " write "), Ok(2));
+        for input in ["WRITE", "wri", "wr\nite", "writes", "read,write"] {
+            assert_eq!(
+                sanitize(&sanitizer, input),
+                Err(SanitizationErrorKind::NotAllowed),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            sanitize(&sanitizer, "  "),
+            Err(SanitizationErrorKind::Empty)
+        );
+    }
+
+    #[test]
+    fn case_insensitive_matching_is_ascii_only() {
+        let sanitizer = EnumSanitizer::new([("info", "info")]).case_insensitive();
+
+        assert_eq!(sanitize(&sanitizer, "INFO"), Ok("info"));
+        // Turkish dotted capital I does not fold to 'i'
+        assert_eq!(
+            sanitize(&sanitizer, "\u{0130}NFO"),
+            Err(SanitizationErrorKind::NotAllowed)
+        );
+    }
+
+    #[test]
+    fn errors_do_not_echo_input() {
+        let err = EnumSanitizer::new([("a", ())])
+            .sanitize(Tainted::new("SECRET_VALUE".to_string()))
+            .unwrap_err();
+
+        assert!(!err.message().contains("SECRET_VALUE"));
+    }
+}
@@ -0,0 +1,247 @@
+//! Identifier and slug sanitizer.
+
+use super::{checked_trim, SanitizationError, SanitizationErrorKind, Sanitizer};
+use crate::contexts::Plain;
+use crate::{Approval, Tainted, Verified};
+
+/// A sanitizer for identifiers such as usernames, slugs and resource keys.
+///
+/// An identifier consists of ASCII letters and digits plus a configurable set
+/// of punctuation characters (`_` and `-` by default). Anything else,
+/// including whitespace, `/`, `.` and all non-ASCII characters, is rejected
+/// rather than removed.
+///
+/// [`slug`](Self::slug) preconfigures the URL-slug shape: lowercase letters,
+/// digits and single hyphens between them.
+///
+/// # Security Properties
+///
+/// - Prevents path traversal and injection: no separators, quotes or dots
+///   unless explicitly allowed
+/// - Prevents homoglyph spoofing: only ASCII is accepted
+/// - Does not leak rejected input in error messages
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{IdentifierSanitizer, SanitizationErrorKind, Sanitizer, Tainted};
+///
+/// let usernames = IdentifierSanitizer::new(32).unwrap();
+/// assert!(usernames.sanitize(Tainted::new("alice_01".to_string())).is_ok());
+///
+/// let err = usernames
+///     .sanitize(Tainted::new("../etc/passwd".to_string()))
+///     .unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::ForbiddenPattern);
+///
+/// let slugs = IdentifierSanitizer::slug(64).unwrap();
+/// assert!(slugs.sanitize(Tainted::new("hello-world-2".to_string())).is_ok());
+///
+/// let err = slugs.sanitize(Tainted::new("hello--world".to_string())).unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::MalformedInput);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IdentifierSanitizer {
+    max_len: usize,
+    punctuation: String,
+    lowercase_only: bool,
+    separators_inside_only: bool,
+}
+
+impl IdentifierSanitizer {
+    /// Creates an identifier sanitizer allowing ASCII letters, digits, `_`
+    /// and `-`, up to `max_len` characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SanitizationError` if `max_len` is 0.
+    pub fn new(max_len: usize) -> Result<Self, SanitizationError> {
+        if max_len == 0 {
+            return Err(SanitizationError::new(
+                SanitizationErrorKind::InvalidInput,
+                "max_len must be greater than 0",
+            ));
+        }
+        Ok(Self {
+            max_len,
+            punctuation: "_-".to_string(),
+            lowercase_only: false,
+            separators_inside_only: false,
+        })
+    }
+
+    /// Creates a slug sanitizer: lowercase ASCII letters and digits separated
+    /// by single hyphens, up to `max_len` characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SanitizationError` if `max_len` is 0.
+    pub fn slug(max_len: usize) -> Result<Self, SanitizationError> {
+        Ok(Self::new(max_len)?
+            .with_punctuation("-")
+            .lowercase_only()
+            .separators_inside_only())
+    }
+
+    /// Replaces the allowed punctuation characters.
+    ///
+    /// Only ASCII punctuation is honored; letters, digits, whitespace and
+    /// non-ASCII characters in `punctuation` are ignored.
+    pub fn with_punctuation(mut self, punctuation: &str) -> Self {
+        self.punctuation = punctuation
+            .chars()
+            .filter(char::is_ascii_punctuation)
+            .collect();
+        self
+    }
+
+    /// Rejects uppercase letters.
+    pub fn lowercase_only(mut self) -> Self {
+        self.lowercase_only = true;
+        self
+    }
+
+    /// Rejects punctuation at the start or end, and repeated punctuation.
+    pub fn separators_inside_only(mut self) -> Self {
+        self.separators_inside_only = true;
+        self
+    }
+
+    fn is_punctuation(&self, c: char) -> bool {
+        self.punctuation.contains(c)
+    }
+}
+
+impl Sanitizer<String> for IdentifierSanitizer {
+    type Context = Plain;
+
+    fn sanitize(&self, input: Tainted<String>) -> Result<Verified<String>, SanitizationError> {
+        input.sanitize_inner(|raw| {
+            let trimmed = checked_trim(&raw, self.max_len)?;
+
+            let allowed = |c: char| {
+                c.is_ascii_digit()
+                    || c.is_ascii_lowercase()
+                    || (c.is_ascii_uppercase() && !self.lowercase_only)
+                    || self.is_punctuation(c)
+            };
+            if !trimmed.chars().all(allowed) {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::ForbiddenPattern,
+                    "input contains a character outside the allowed set",
+                ));
+            }
+
+            if self.separators_inside_only {
+                let misplaced = trimmed.starts_with(|c| self.is_punctuation(c))
+                    || trimmed.ends_with(|c| self.is_punctuation(c))
+                    || trimmed
+                        .as_bytes()
+                        .windows(2)
+                        .any(|pair| pair.iter().all(|&b| self.is_punctuation(char::from(b))));
+                if misplaced {
+                    return Err(SanitizationError::new(
+                        SanitizationErrorKind::MalformedInput,
+                        "separators must be single and between letters or digits",
+                    ));
+                }
+            }
+
+            // BREAKING CHANGE WARNING: Verified::new_unchecked() MUST ONLY be called AFTER
+            // all validation checks pass. Moving this before validation creates a CRITICAL BYPASS.
+            let transformed = trimmed != raw;
+            Ok(
+                Verified::new_unchecked(trimmed.to_string()).approved_by(Approval::new(
+                    "IdentifierSanitizer",
+                    self,
+                    transformed,
+                )),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sanitize(
+        sanitizer: &IdentifierSanitizer,
+        input: &str,
+    ) -> Result<String, SanitizationErrorKind> {
+        sanitizer
+            .sanitize(Tainted::new(input.to_string()))
+            .map(Verified::into_inner)
+            .map_err(|err| err.kind())
+    }
+
+    #[test]
+    fn default_charset() {
+        let sanitizer = IdentifierSanitizer::new(16).unwrap();
+
+        for input in ["alice", "Alice_01", "-x-", "__init__"] {
+            assert_eq!(sanitize(&sanitizer, input), Ok(input.to_string()));
+        }
+        for input in ["a.b", "a/b", "a b", "a'b", "ålice", "a\u{0430}"] {
+            assert_eq!(
+                sanitize(&sanitizer, input),
+                Err(SanitizationErrorKind::ForbiddenPattern),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn custom_punctuation_replaces_default() {
+        let sanitizer = IdentifierSanitizer::new(16)
+            .unwrap()
+            .with_punctuation(".a :");
+
+        assert!(sanitize(&sanitizer, "a.b:c").is_ok());
+        assert_eq!(
+            sanitize(&sanitizer, "a_b"),
+            Err(SanitizationErrorKind::ForbiddenPattern)
+        );
+        assert_eq!(
+            sanitize(&sanitizer, "a b"),
+            Err(SanitizationErrorKind::ForbiddenPattern)
+        );
+    }
+
+    #[test]
+    fn slug_shape() {
+        let sanitizer = IdentifierSanitizer::slug(16).unwrap();
+
+        assert!(sanitize(&sanitizer, "hello-world").is_ok());
+        assert_eq!(
+            sanitize(&sanitizer, "Hello"),
+            Err(SanitizationErrorKind::ForbiddenPattern)
+        );
+        for input in ["-hello", "hello-", "hello--world", "-"] {
+            assert_eq!(
+                sanitize(&sanitizer, input),
+                Err(SanitizationErrorKind::MalformedInput),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn common_checks() {
+        let sanitizer = IdentifierSanitizer::new(4).unwrap();
+
+        assert_eq!(sanitize(&sanitizer, " "), Err(SanitizationErrorKind::Empty));
+        assert_eq!(
+            sanitize(&sanitizer, "a\u{0}"),
+            Err(SanitizationErrorKind::ContainsControlChars)
+        );
+        assert_eq!(
+            sanitize(&sanitizer, "abcde"),
+            Err(SanitizationErrorKind::TooLong)
+        );
+        assert_eq!(
+            IdentifierSanitizer::new(0).unwrap_err().kind(),
+            SanitizationErrorKind::InvalidInput
+        );
+    }
+}
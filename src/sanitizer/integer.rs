@@ -0,0 +1,196 @@
+//! Bounded integer sanitizer.
+
+use std::fmt;
+use std::str::FromStr;
+
+use super::{checked_trim, SanitizationError, SanitizationErrorKind, Sanitizer};
+use crate::contexts::Plain;
+use crate::{Approval, Tainted, Verified};
+
+/// Longest accepted input; enough for any `i128` or `u128`.
+const MAX_INTEGER_LEN: usize = 40;
+
+/// A sanitizer that parses a decimal integer and checks it against a range.
+///
+/// Produces `Verified<N>` for any primitive integer type `N`. Only the
+/// canonical form is accepted: an optional `-` followed by digits, without a
+/// `+` sign, leading zeros, separators or exponent. Surrounding whitespace is
+/// trimmed.
+///
+/// # Security Properties
+///
+/// - Values outside the range (including overflow) fail with
+///   [`SanitizationErrorKind::OutOfRange`] instead of wrapping or saturating
+/// - Leading zeros are rejected, so `010` is never read as octal downstream
+/// - Input length is bounded before parsing
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{IntegerSanitizer, SanitizationErrorKind, Sanitizer, Tainted};
+///
+/// let page_size = IntegerSanitizer::<u32>::new(1, 100).unwrap();
+///
+/// let size = page_size.sanitize(Tainted::new(" 25 ".to_string())).unwrap();
+/// assert_eq!(size.into_inner(), 25u32);
+///
+/// let err = page_size.sanitize(Tainted::new("1000".to_string())).unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::OutOfRange);
+///
+/// let err = page_size.sanitize(Tainted::new("0x10".to_string())).unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::MalformedInput);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct IntegerSanitizer<N> {
+    min: N,
+    max: N,
+}
+
+impl<N> IntegerSanitizer<N>
+where
+    N: FromStr + PartialOrd + Copy + fmt::Display,
+{
+    /// Creates a sanitizer accepting integers in `min..=max`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SanitizationError` if `min` is greater than `max`.
+    pub fn new(min: N, max: N) -> Result<Self, SanitizationError> {
+        if min > max {
+            return Err(SanitizationError::new(
+                SanitizationErrorKind::InvalidInput,
+                "min must not be greater than max",
+            ));
+        }
+        Ok(Self { min, max })
+    }
+
+    fn out_of_range(&self) -> SanitizationError {
+        SanitizationError::new(
+            SanitizationErrorKind::OutOfRange,
+            format!("value must be between {} and {}", self.min, self.max),
+        )
+    }
+}
+
+impl<N> Sanitizer<String, N> for IntegerSanitizer<N>
+where
+    N: FromStr + PartialOrd + Copy + fmt::Display,
+{
+    type Context = Plain;
+
+    fn sanitize(&self, input: Tainted<String>) -> Result<Verified<N>, SanitizationError> {
+        input.sanitize_inner(|raw| {
+            let trimmed = checked_trim(&raw, MAX_INTEGER_LEN)?;
+
+            let digits = trimmed.strip_prefix('-').unwrap_or(trimmed);
+            let canonical = !digits.is_empty()
+                && digits.bytes().all(|b| b.is_ascii_digit())
+                && (digits == "0" || !digits.starts_with('0'))
+                && trimmed != "-0";
+            if !canonical {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::MalformedInput,
+                    "input is not a canonical decimal integer",
+                ));
+            }
+
+            // The input is a well-formed integer, so parsing can only fail on overflow
+            let value: N = trimmed.parse().map_err(|_| self.out_of_range())?;
+            if value < self.min || value > self.max {
+                return Err(self.out_of_range());
+            }
+
+            // BREAKING CHANGE WARNING: Verified::new_unchecked() MUST ONLY be called AFTER
+            // all validation checks pass. Moving this before validation creates a CRITICAL BYPASS.
+            let config = format_args!("{}..={}", self.min, self.max);
+            let transformed = trimmed != raw;
+            Ok(Verified::new_unchecked(value).approved_by(Approval::new(
+                "IntegerSanitizer",
+                &config,
+                transformed,
+            )))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sanitize<N>(sanitizer: &IntegerSanitizer<N>, input: &str) -> Result<N, SanitizationErrorKind>
+    where
+        N: FromStr + PartialOrd + Copy + fmt::Display,
+    {
+        sanitizer
+            .sanitize(Tainted::new(input.to_string()))
+            .map(Verified::into_inner)
+            .map_err(|err| err.kind())
+    }
+
+    #[test]
+    fn accepts_values_in_range() {
+        let sanitizer = IntegerSanitizer::new(-10i64, 10).unwrap();
+
+        assert_eq!(sanitize(&sanitizer, "-10"), Ok(-10));
+        assert_eq!(sanitize(&sanitizer, "0"), Ok(0));
+        assert_eq!(sanitize(&sanitizer, " 10\t"), Ok(10));
+    }
+
+    #[test]
+    fn rejects_values_out_of_range_or_overflowing() {
+        let sanitizer = IntegerSanitizer::new(0u8, 200).unwrap();
+
+        for input in ["201", "256", "99999999999999999999", "-1"] {
+            assert_eq!(
+                sanitize(&sanitizer, input),
+                Err(SanitizationErrorKind::OutOfRange),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_non_canonical_forms() {
+        let sanitizer = IntegerSanitizer::new(i32::MIN, i32::MAX).unwrap();
+
+        for input in [
+            "+1", "01", "-0", "-", "1_000", "1,000", "1e3", "1.0", "0x1f", "- 1", "١",
+        ] {
+            assert_eq!(
+                sanitize(&sanitizer, input),
+                Err(SanitizationErrorKind::MalformedInput),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn common_checks() {
+        let sanitizer = IntegerSanitizer::new(0u128, u128::MAX).unwrap();
+
+        assert_eq!(sanitize(&sanitizer, ""), Err(SanitizationErrorKind::Empty));
+        assert_eq!(
+            sanitize(&sanitizer, "1\n"),
+            Ok(1),
+            "trailing newline is trimmed"
+        );
+        assert_eq!(
+            sanitize(&sanitizer, "1\n2"),
+            Err(SanitizationErrorKind::ContainsControlChars)
+        );
+        assert_eq!(
+            sanitize(&sanitizer, &"9".repeat(41)),
+            Err(SanitizationErrorKind::TooLong)
+        );
+        assert_eq!(sanitize(&sanitizer, &u128::MAX.to_string()), Ok(u128::MAX));
+    }
+
+    #[test]
+    fn rejects_inverted_range() {
+        assert_eq!(
+            IntegerSanitizer::new(5, 1).unwrap_err().kind(),
+            SanitizationErrorKind::InvalidInput
+        );
+    }
+}
@@ -0,0 +1,345 @@
+//! Pattern-based sanitizer with named allow and deny rules.
+
+use std::fmt;
+
+use regex::{Regex, RegexBuilder};
+
+use super::{checked_trim, SanitizationError, SanitizationErrorKind, Sanitizer};
+use crate::contexts::Plain;
+use crate::{Approval, Tainted, Verified};
+
+/// Upper bound on the compiled size of a single rule, in bytes.
+///
+/// Counted repetitions such as `a{1000}{1000}` compile to very large programs;
+/// rules over this limit are rejected when they are added.
+const MAX_COMPILED_SIZE: usize = 1 << 20;
+
+/// A sanitizer that checks strings against named regular expressions.
+///
+/// - **Deny rules** reject the input if they match anywhere in it, with
+///   [`SanitizationErrorKind::ForbiddenPattern`]. Rules are checked in the
+///   order they were added and the first match is reported.
+/// - **Allow rules** must match the *whole* input; they are anchored at both
+///   ends automatically. With at least one allow rule, input matching none of
+///   them is rejected with [`SanitizationErrorKind::NotAllowed`].
+///
+/// Deny rules are checked first. Error messages name the rule, never the
+/// input.
+///
+/// # Security Properties
+///
+/// - Matching runs in time linear in the input length for every rule:
+///   patterns use the `regex` crate's syntax, which has no backreferences
+///   or lookaround, so a rule cannot be written to backtrack
+///   catastrophically (no ReDoS, CWE-1333)
+/// - Input is trimmed and bounded by [`with_max_len`](Self::with_max_len)
+///   before any rule runs, and control characters are rejected
+/// - Invalid or oversized patterns are rejected when the rule is added
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{PatternSanitizer, SanitizationErrorKind, Sanitizer, Tainted};
+///
+/// # fn main() -> Result<(), policy_core::SanitizationError> {
+/// let sanitizer = PatternSanitizer::new()
+///     .deny("path_traversal", r"\.\.[/\\]")?
+///     .allow("relative_path", r"[A-Za-z0-9_./-]+")?;
+///
+/// let path = sanitizer.sanitize(Tainted::new("docs/guide.md".to_string()))?;
+/// assert_eq!(path.as_ref(), "docs/guide.md");
+///
+/// let err = sanitizer
+///     .sanitize(Tainted::new("../etc/passwd".to_string()))
+///     .unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::ForbiddenPattern);
+/// assert_eq!(err.message(), "input matches forbidden pattern `path_traversal`");
+///
+/// let err = sanitizer
+///     .sanitize(Tainted::new("docs/$(reboot)".to_string()))
+///     .unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::NotAllowed);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct PatternSanitizer {
+    allow: Vec<Rule>,
+    deny: Vec<Rule>,
+    max_len: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    name: String,
+    regex: Regex,
+}
+
+impl PatternSanitizer {
+    /// Creates a sanitizer without rules and a 1024-byte length limit.
+    ///
+    /// Without rules every trimmed, control-free input within the limit is
+    /// accepted.
+    pub fn new() -> Self {
+        Self {
+            allow: Vec::new(),
+            deny: Vec::new(),
+            max_len: 1024,
+        }
+    }
+
+    /// Sets the maximum input length in bytes.
+    pub fn with_max_len(mut self, max_len: usize) -> Self {
+        self.max_len = max_len;
+        self
+    }
+
+    /// Adds a rule the whole input may match.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SanitizationErrorKind::InvalidInput`] if `pattern` is not a
+    /// valid regular expression or exceeds the compiled size limit.
+    pub fn allow(
+        mut self,
+        name: impl Into<String>,
+        pattern: &str,
+    ) -> Result<Self, SanitizationError> {
+        let name = name.into();
+        let regex = compile(&name, &format!(r"\A(?:{})\z", pattern))?;
+        self.allow.push(Rule { name, regex });
+        Ok(self)
+    }
+
+    /// Adds a rule that must not match anywhere in the input.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SanitizationErrorKind::InvalidInput`] if `pattern` is not a
+    /// valid regular expression or exceeds the compiled size limit.
+    pub fn deny(
+        mut self,
+        name: impl Into<String>,
+        pattern: &str,
+    ) -> Result<Self, SanitizationError> {
+        let name = name.into();
+        let regex = compile(&name, pattern)?;
+        self.deny.push(Rule { name, regex });
+        Ok(self)
+    }
+}
+
+impl Default for PatternSanitizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn compile(name: &str, pattern: &str) -> Result<Regex, SanitizationError> {
+    RegexBuilder::new(pattern)
+        .size_limit(MAX_COMPILED_SIZE)
+        .build()
+        .map_err(|err| {
+            SanitizationError::new(
+                SanitizationErrorKind::InvalidInput,
+                format!("invalid pattern for rule `{}`: {}", name, err),
+            )
+        })
+}
+
+impl Sanitizer<String> for PatternSanitizer {
+    type Context = Plain;
+
+    fn sanitize(&self, input: Tainted<String>) -> Result<Verified<String>, SanitizationError> {
+        input.sanitize_inner(|raw| {
+            let trimmed = checked_trim(&raw, self.max_len)?;
+
+            if let Some(rule) = self.deny.iter().find(|rule| rule.regex.is_match(trimmed)) {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::ForbiddenPattern,
+                    format!("input matches forbidden pattern `{}`", rule.name),
+                ));
+            }
+
+            if !self.allow.is_empty() && !self.allow.iter().any(|rule| rule.regex.is_match(trimmed))
+            {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::NotAllowed,
+                    "input does not match any allowed pattern",
+                ));
+            }
+
+            // BREAKING CHANGE WARNING: Verified::new_unchecked() MUST ONLY be called AFTER
+            // all validation checks pass. Moving this before validation creates a CRITICAL BYPASS.
+            // Debug on the sanitizer omits the patterns, so fingerprint the rules
+            let config = (&self.allow, &self.deny, self.max_len);
+            let transformed = trimmed != raw;
+            Ok(
+                Verified::new_unchecked(trimmed.to_string()).approved_by(Approval::new(
+                    "PatternSanitizer",
+                    &config,
+                    transformed,
+                )),
+            )
+        })
+    }
+}
+
+impl fmt::Debug for PatternSanitizer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = |rules: &[Rule]| -> Vec<String> {
+            rules.iter().map(|rule| rule.name.clone()).collect()
+        };
+        f.debug_struct("PatternSanitizer")
+            .field("allow", &names(&self.allow))
+            .field("deny", &names(&self.deny))
+            .field("max_len", &self.max_len)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+
+    fn sanitize(sanitizer: &PatternSanitizer, input: &str) -> Result<String, SanitizationError> {
+        sanitizer
+            .sanitize(Tainted::new(input.to_string()))
+            .map(Verified::into_inner)
+    }
+
+    #[test]
+    fn without_rules_only_base_checks_apply() {
+        let sanitizer = PatternSanitizer::new().with_max_len(8);
+
+        assert_eq!(sanitize(&sanitizer, " anything ").unwrap(), "anything");
+        let kind = |input| sanitize(&sanitizer, input).unwrap_err().kind();
+        assert_eq!(kind("   "), SanitizationErrorKind::Empty);
+        assert_eq!(kind("a\nb"), SanitizationErrorKind::ContainsControlChars);
+        assert_eq!(kind("123456789"), SanitizationErrorKind::TooLong);
+    }
+
+    #[test]
+    fn deny_rules_match_anywhere_and_report_the_first_rule() {
+        let sanitizer = PatternSanitizer::new()
+            .deny("sql_comment", r"--|/\*")
+            .unwrap()
+            .deny("union_select", r"(?i)union\s+select")
+            .unwrap();
+
+        assert!(sanitize(&sanitizer, "plain text").is_ok());
+
+        let err = sanitize(&sanitizer, "1 UNION  select password").unwrap_err();
+        assert_eq!(err.kind(), SanitizationErrorKind::ForbiddenPattern);
+        assert_eq!(
+            err.message(),
+            "input matches forbidden pattern `union_select`"
+        );
+
+        let err = sanitize(&sanitizer, "1 union select 1 -- x").unwrap_err();
+        assert_eq!(
+            err.message(),
+            "input matches forbidden pattern `sql_comment`"
+        );
+    }
+
+    #[test]
+    fn allow_rules_must_match_the_whole_input() {
+        let sanitizer = PatternSanitizer::new()
+            .allow("digits", "[0-9]+")
+            .unwrap()
+            .allow("hex", "0x[0-9a-f]+")
+            .unwrap();
+
+        assert!(sanitize(&sanitizer, "42").is_ok());
+        assert!(sanitize(&sanitizer, "0xff").is_ok());
+        for input in ["42; rm -rf /", "x42", "0xfg"] {
+            let err = sanitize(&sanitizer, input).unwrap_err();
+            assert_eq!(err.kind(), SanitizationErrorKind::NotAllowed, "{input}");
+        }
+    }
+
+    #[test]
+    fn anchoring_survives_top_level_alternation() {
+        let sanitizer = PatternSanitizer::new().allow("ab", "a|b").unwrap();
+
+        assert!(sanitize(&sanitizer, "a").is_ok());
+        assert!(sanitize(&sanitizer, "ax").is_err());
+        assert!(sanitize(&sanitizer, "xb").is_err());
+    }
+
+    #[test]
+    fn deny_is_checked_before_allow() {
+        let sanitizer = PatternSanitizer::new()
+            .allow("word", "[a-z]+")
+            .unwrap()
+            .deny("reserved", "^(admin|root)$")
+            .unwrap();
+
+        let err = sanitize(&sanitizer, "root").unwrap_err();
+        assert_eq!(err.kind(), SanitizationErrorKind::ForbiddenPattern);
+        assert!(sanitize(&sanitizer, "alice").is_ok());
+    }
+
+    #[test]
+    fn errors_do_not_echo_input() {
+        let sanitizer = PatternSanitizer::new()
+            .deny("secret", "SECRET")
+            .unwrap()
+            .allow("short", "[a-z]{1,3}")
+            .unwrap();
+
+        for input in ["SECRET_VALUE", "secret_value"] {
+            let err = sanitize(&sanitizer, input).unwrap_err();
+            assert!(!err.message().contains(input));
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_patterns_when_added() {
+        for pattern in ["(unclosed", r"(a)\1", "(?=lookahead)"] {
+            let err = PatternSanitizer::new().deny("bad", pattern).unwrap_err();
+            assert_eq!(err.kind(), SanitizationErrorKind::InvalidInput);
+            assert!(err.message().starts_with("invalid pattern for rule `bad`"));
+        }
+    }
+
+    #[test]
+    fn rejects_oversized_patterns_when_added() {
+        let err = PatternSanitizer::new()
+            .allow("huge", "[a-z]{1000}{1000}")
+            .unwrap_err();
+        assert_eq!(err.kind(), SanitizationErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn classic_redos_patterns_run_in_linear_time() {
+        // Each of these takes exponential time in a backtracking engine
+        let sanitizer = PatternSanitizer::new()
+            .with_max_len(100_000)
+            .deny("nested", "(a+)+$")
+            .unwrap()
+            .deny("alternation", "(a|aa)+$")
+            .unwrap()
+            .allow("overlap", r"(\w+\s?)*")
+            .unwrap();
+
+        let input = format!("{}!", "a".repeat(50_000));
+        let start = Instant::now();
+        let err = sanitize(&sanitizer, &input).unwrap_err();
+
+        assert_eq!(err.kind(), SanitizationErrorKind::NotAllowed);
+        assert!(start.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn debug_lists_rule_names_only() {
+        let sanitizer = PatternSanitizer::new().deny("no_tags", "<[a-z]+>").unwrap();
+
+        let debug = format!("{:?}", sanitizer);
+        assert!(debug.contains("no_tags"));
+        assert!(!debug.contains("<[a-z]+>"));
+    }
+}
@@ -0,0 +1,363 @@
+//! ISO-8601 timestamp sanitizer.
+
+use std::fmt;
+
+use super::{checked_trim, SanitizationError, SanitizationErrorKind, Sanitizer};
+use crate::contexts::Plain;
+use crate::{Approval, Tainted, Verified};
+
+/// Longest accepted input: date, time, nanoseconds and a numeric offset.
+const MAX_TIMESTAMP_LEN: usize = 35;
+
+/// A calendar timestamp with a UTC offset, produced by [`TimestampSanitizer`].
+///
+/// `Display` writes the RFC 3339 form, using `Z` for a zero offset and
+/// dropping trailing zeros from the fractional second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Timestamp {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+    offset_minutes: i16,
+}
+
+impl Timestamp {
+    /// Returns the year (0 to 9999).
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month (1 to 12).
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month (1 to 31).
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the hour (0 to 23).
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute (0 to 59).
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second (0 to 59).
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Returns the fractional second in nanoseconds.
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    /// Returns the offset from UTC in minutes (east is positive).
+    pub fn offset_minutes(&self) -> i16 {
+        self.offset_minutes
+    }
+
+    /// Returns the number of whole seconds since 1970-01-01T00:00:00Z.
+    pub fn unix_timestamp(&self) -> i64 {
+        let days = days_from_civil(
+            i64::from(self.year),
+            i64::from(self.month),
+            i64::from(self.day),
+        );
+        days * 86_400
+            + i64::from(self.hour) * 3_600
+            + i64::from(self.minute) * 60
+            + i64::from(self.second)
+            - i64::from(self.offset_minutes) * 60
+    }
+}
+
+impl fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        if self.nanosecond != 0 {
+            let fraction = format!("{:09}", self.nanosecond);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        match self.offset_minutes {
+            0 => f.write_str("Z"),
+            offset => {
+                let sign = if offset < 0 { '-' } else { '+' };
+                let offset = offset.unsigned_abs();
+                write!(f, "{}{:02}:{:02}", sign, offset / 60, offset % 60)
+            }
+        }
+    }
+}
+
+/// A sanitizer for ISO-8601 timestamps in the RFC 3339 profile.
+///
+/// Accepts `YYYY-MM-DDTHH:MM:SS[.fraction](Z|+HH:MM|-HH:MM)`. The offset is
+/// required, since a timestamp without one is ambiguous. `T` and `Z` may be
+/// lowercase; the fraction may have 1 to 9 digits. Leap seconds (`:60`) are
+/// rejected.
+///
+/// Syntax errors fail with [`SanitizationErrorKind::MalformedInput`];
+/// well-formed dates that do not exist (month 13, February 30) fail with
+/// [`SanitizationErrorKind::OutOfRange`].
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{SanitizationErrorKind, Sanitizer, Tainted, TimestampSanitizer};
+///
+/// let sanitizer = TimestampSanitizer::new();
+///
+/// let ts = sanitizer
+///     .sanitize(Tainted::new("2024-02-29T12:30:00.250+02:00".to_string()))
+///     .unwrap();
+/// assert_eq!(ts.as_ref().unix_timestamp(), 1_709_202_600);
+/// assert_eq!(ts.as_ref().to_string(), "2024-02-29T12:30:00.25+02:00");
+///
+/// let err = sanitizer
+///     .sanitize(Tainted::new("2023-02-29T12:30:00Z".to_string()))
+///     .unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::OutOfRange);
+///
+/// let err = sanitizer
+///     .sanitize(Tainted::new("2024-02-29 12:30".to_string()))
+///     .unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::MalformedInput);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TimestampSanitizer {
+    _private: (),
+}
+
+impl TimestampSanitizer {
+    /// Creates a timestamp sanitizer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Sanitizer<String, Timestamp> for TimestampSanitizer {
+    type Context = Plain;
+
+    fn sanitize(&self, input: Tainted<String>) -> Result<Verified<Timestamp>, SanitizationError> {
+        input.sanitize_inner(|raw| {
+            let trimmed = checked_trim(&raw, MAX_TIMESTAMP_LEN)?;
+
+            let (timestamp, offset_in_range) = parse(trimmed.as_bytes()).ok_or_else(|| {
+                SanitizationError::new(
+                    SanitizationErrorKind::MalformedInput,
+                    "input is not an RFC 3339 timestamp",
+                )
+            })?;
+
+            let in_range = (1..=12).contains(&timestamp.month)
+                && (1..=days_in_month(timestamp.year, timestamp.month)).contains(&timestamp.day)
+                && timestamp.hour < 24
+                && timestamp.minute < 60
+                && timestamp.second < 60
+                && offset_in_range;
+            if !in_range {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::OutOfRange,
+                    "timestamp is not a valid calendar date and time",
+                ));
+            }
+
+            // BREAKING CHANGE WARNING: Verified::new_unchecked() MUST ONLY be called AFTER
+            // all validation checks pass. Moving this before validation creates a CRITICAL BYPASS.
+            let transformed = timestamp.to_string() != raw;
+            Ok(
+                Verified::new_unchecked(timestamp).approved_by(Approval::new(
+                    "TimestampSanitizer",
+                    self,
+                    transformed,
+                )),
+            )
+        })
+    }
+}
+
+/// Parses the RFC 3339 syntax without checking date and time ranges.
+///
+/// Also returns whether the offset's hours and minutes are in range.
+fn parse(input: &[u8]) -> Option<(Timestamp, bool)> {
+    let (head, rest) = input.split_at_checked(19)?;
+    let separators_ok = head[4] == b'-'
+        && head[7] == b'-'
+        && matches!(head[10], b'T' | b't')
+        && head[13] == b':'
+        && head[16] == b':';
+    if !separators_ok {
+        return None;
+    }
+
+    let (nanosecond, zone) = match rest.strip_prefix(b".") {
+        Some(rest) => {
+            let len = rest.iter().take_while(|b| b.is_ascii_digit()).count();
+            if !(1..=9).contains(&len) {
+                return None;
+            }
+            let (fraction, zone) = rest.split_at(len);
+            let scale = 10u32.pow(9 - u32::try_from(len).ok()?);
+            (digits(fraction)? * scale, zone)
+        }
+        None => (0, rest),
+    };
+
+    let (offset_minutes, offset_in_range) = match zone {
+        b"Z" | b"z" => (0, true),
+        [sign @ (b'+' | b'-'), h1, h2, b':', m1, m2] => {
+            let (hours, minutes) = (digits(&[*h1, *h2])?, digits(&[*m1, *m2])?);
+            let total = i16::try_from(hours * 60 + minutes).ok()?;
+            let total = if *sign == b'-' { -total } else { total };
+            (total, hours < 24 && minutes < 60)
+        }
+        _ => return None,
+    };
+
+    let timestamp = Timestamp {
+        year: u16::try_from(digits(&head[0..4])?).ok()?,
+        month: u8::try_from(digits(&head[5..7])?).ok()?,
+        day: u8::try_from(digits(&head[8..10])?).ok()?,
+        hour: u8::try_from(digits(&head[11..13])?).ok()?,
+        minute: u8::try_from(digits(&head[14..16])?).ok()?,
+        second: u8::try_from(digits(&head[17..19])?).ok()?,
+        nanosecond,
+        offset_minutes,
+    };
+    Some((timestamp, offset_in_range))
+}
+
+/// Parses a run of at most nine ASCII digits.
+fn digits(bytes: &[u8]) -> Option<u32> {
+    bytes.iter().try_fold(0u32, |acc, &b| {
+        b.is_ascii_digit().then(|| acc * 10 + u32::from(b - b'0'))
+    })
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sanitize(input: &str) -> Result<Timestamp, SanitizationErrorKind> {
+        TimestampSanitizer::new()
+            .sanitize(Tainted::new(input.to_string()))
+            .map(Verified::into_inner)
+            .map_err(|err| err.kind())
+    }
+
+    #[test]
+    fn parses_fields_and_unix_time() {
+        let ts = sanitize("1970-01-01T00:00:00Z").unwrap();
+        assert_eq!(ts.unix_timestamp(), 0);
+
+        let ts = sanitize("2000-03-01t01:02:03.000000004-05:30").unwrap();
+        assert_eq!((ts.year(), ts.month(), ts.day()), (2000, 3, 1));
+        assert_eq!((ts.hour(), ts.minute(), ts.second()), (1, 2, 3));
+        assert_eq!(ts.nanosecond(), 4);
+        assert_eq!(ts.offset_minutes(), -330);
+        assert_eq!(ts.unix_timestamp(), 951_872_523 + 330 * 60);
+
+        assert_eq!(
+            sanitize("1969-12-31T23:59:59Z").unwrap().unix_timestamp(),
+            -1
+        );
+    }
+
+    #[test]
+    fn displays_rfc3339() {
+        for (input, expected) in [
+            ("2024-01-02T03:04:05Z", "2024-01-02T03:04:05Z"),
+            ("2024-01-02t03:04:05.120z", "2024-01-02T03:04:05.12Z"),
+            ("2024-01-02T03:04:05+00:00", "2024-01-02T03:04:05Z"),
+            ("2024-01-02T03:04:05-08:00", "2024-01-02T03:04:05-08:00"),
+        ] {
+            assert_eq!(sanitize(input).unwrap().to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_syntax() {
+        for input in [
+            "2024-01-02",
+            "2024-01-02T03:04:05",
+            "2024-01-02 03:04:05Z",
+            "2024/01/02T03:04:05Z",
+            "24-01-02T03:04:05Z",
+            "2024-01-02T03:04:05.Z",
+            "2024-01-02T03:04:05.1234567890Z",
+            "2024-01-02T03:04:05+0100",
+            "2024-01-02T03:04:05+01",
+            "+2024-01-02T03:04:05Z",
+            "2024-1-02T03:04:05Z",
+            "2024-01-02T03:04:05ZZ",
+            "２０２４-01-02T03:04:05Z",
+        ] {
+            assert!(
+                matches!(
+                    sanitize(input),
+                    Err(SanitizationErrorKind::MalformedInput | SanitizationErrorKind::TooLong)
+                ),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_impossible_dates_and_times() {
+        for input in [
+            "2023-02-29T00:00:00Z",
+            "1900-02-29T00:00:00Z",
+            "2024-13-01T00:00:00Z",
+            "2024-00-01T00:00:00Z",
+            "2024-04-31T00:00:00Z",
+            "2024-01-01T24:00:00Z",
+            "2024-01-01T00:60:00Z",
+            "2016-12-31T23:59:60Z",
+            "2024-01-01T00:00:00+24:00",
+            "2024-01-01T00:00:00-01:60",
+        ] {
+            assert_eq!(
+                sanitize(input),
+                Err(SanitizationErrorKind::OutOfRange),
+                "{input}"
+            );
+        }
+        assert!(sanitize("2000-02-29T00:00:00Z").is_ok());
+    }
+}
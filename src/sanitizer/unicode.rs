@@ -0,0 +1,179 @@
+//! Unicode checks used by [`StringSanitizer`](super::StringSanitizer).
+//!
+//! These target text that renders differently from what it contains:
+//! reordering controls, characters that render as nothing, and letters that
+//! look like letters of another script.
+
+use std::borrow::Cow;
+
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::{is_nfc_quick, is_nfkc_quick, IsNormalized, UnicodeNormalization};
+use unicode_security::confusable_detection::skeleton;
+use unicode_security::general_security_profile::IdentifierType;
+use unicode_security::{GeneralSecurityProfile, MixedScript};
+
+use super::{SanitizationError, SanitizationErrorKind};
+
+/// Unicode normalization form applied by
+/// [`StringSanitizer::with_normalization`](super::StringSanitizer::with_normalization).
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{Normalization, Sanitizer, StringSanitizer, Tainted};
+///
+/// let sanitizer = StringSanitizer::default_limits().with_normalization(Normalization::Nfkc);
+///
+/// // Fullwidth letters fold to ASCII under NFKC
+/// let name = sanitizer.sanitize(Tainted::new("ａｄｍｉｎ".to_string())).unwrap();
+/// assert_eq!(name.as_ref(), "admin");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Normalization {
+    /// Canonical composition: equivalent sequences such as `e` + U+0301 and
+    /// `é` become the same string.
+    Nfc,
+    /// Compatibility composition: also folds presentation variants such as
+    /// fullwidth letters and ligatures. Preferred for identifiers.
+    Nfkc,
+}
+
+impl Normalization {
+    /// Returns `input` in this normalization form, borrowing when it already is.
+    pub(super) fn apply(self, input: &str) -> Cow<'_, str> {
+        let quick = match self {
+            Self::Nfc => is_nfc_quick(input.chars()),
+            Self::Nfkc => is_nfkc_quick(input.chars()),
+        };
+        match (quick, self) {
+            (IsNormalized::Yes, _) => Cow::Borrowed(input),
+            (_, Self::Nfc) => Cow::Owned(input.nfc().collect()),
+            (_, Self::Nfkc) => Cow::Owned(input.nfkc().collect()),
+        }
+    }
+}
+
+/// Returns `true` for explicit bidirectional formatting characters.
+///
+/// These reorder the surrounding text when rendered, e.g. U+202E makes
+/// `user\u{202E}gpj.exe` display as `userexe.jpg` (CVE-2021-42574).
+fn is_bidi_control(c: char) -> bool {
+    matches!(
+        c,
+        '\u{061C}' | '\u{200E}' | '\u{200F}' | '\u{202A}'..='\u{202E}' | '\u{2066}'..='\u{2069}'
+    )
+}
+
+/// Returns `true` for default-ignorable characters, which render as nothing:
+/// zero-width spaces and joiners, soft hyphens, variation selectors, tag
+/// characters and similar.
+fn is_invisible(c: char) -> bool {
+    c.identifier_type() == Some(IdentifierType::Default_Ignorable)
+}
+
+/// Rejects text that starts with a combining mark (General_Category M*).
+///
+/// A leading mark has no base character of its own and renders on top of
+/// whatever precedes the value, such as a label or a closing quote. NFKC can
+/// produce one from a single character (U+00AF becomes U+0304 once the
+/// leading space is trimmed).
+pub(super) fn reject_leading_combining_mark(input: &str) -> Result<(), SanitizationError> {
+    if input.chars().next().is_some_and(is_combining_mark) {
+        return Err(SanitizationError::new(
+            SanitizationErrorKind::MalformedInput,
+            "input starts with a combining mark",
+        ));
+    }
+    Ok(())
+}
+
+pub(super) fn reject_bidi_controls(input: &str) -> Result<(), SanitizationError> {
+    if input.chars().any(is_bidi_control) {
+        return Err(SanitizationError::new(
+            SanitizationErrorKind::BidiControl,
+            "input contains bidirectional control characters",
+        ));
+    }
+    Ok(())
+}
+
+pub(super) fn reject_invisible(input: &str) -> Result<(), SanitizationError> {
+    if input.chars().any(is_invisible) {
+        return Err(SanitizationError::new(
+            SanitizationErrorKind::InvisibleChars,
+            "input contains invisible characters",
+        ));
+    }
+    Ok(())
+}
+
+pub(super) fn reject_mixed_script(input: &str) -> Result<(), SanitizationError> {
+    if !input.is_single_script() {
+        return Err(SanitizationError::new(
+            SanitizationErrorKind::MixedScript,
+            "input mixes characters from different scripts",
+        ));
+    }
+    Ok(())
+}
+
+/// Rejects non-ASCII input whose confusable skeleton (UTS #39) is pure ASCII,
+/// i.e. text built to look like an ASCII string it is not, such as Cyrillic
+/// `аррӏе` posing as `apple`.
+pub(super) fn reject_confusables(input: &str) -> Result<(), SanitizationError> {
+    if !input.is_ascii() && skeleton(input).all(|c| c.is_ascii()) {
+        return Err(SanitizationError::new(
+            SanitizationErrorKind::Confusable,
+            "input is confusable with ASCII text",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalization_composes_and_folds() {
+        assert_eq!(Normalization::Nfc.apply("e\u{301}"), "\u{e9}");
+        assert_eq!(Normalization::Nfc.apply("\u{FB01}le"), "\u{FB01}le");
+        assert_eq!(Normalization::Nfkc.apply("\u{FB01}le"), "file");
+        assert!(matches!(
+            Normalization::Nfc.apply("plain"),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn bidi_and_invisible_are_told_apart() {
+        assert!(reject_bidi_controls("abc\u{202E}fed").is_err());
+        assert!(reject_bidi_controls("a\u{2067}b").is_err());
+        assert!(reject_bidi_controls("a\u{200D}b").is_ok());
+
+        for c in [
+            '\u{200B}', '\u{200D}', '\u{00AD}', '\u{2060}', '\u{FEFF}', '\u{FE0F}',
+        ] {
+            let err = reject_invisible(&format!("a{}b", c)).unwrap_err();
+            assert_eq!(err.kind(), SanitizationErrorKind::InvisibleChars, "{c:?}");
+        }
+        assert!(reject_invisible("visible text").is_ok());
+    }
+
+    #[test]
+    fn mixed_script_allows_common_characters() {
+        assert!(reject_mixed_script("Alice O'Neil 2nd").is_ok());
+        assert!(reject_mixed_script("Дмитрий").is_ok());
+        assert!(reject_mixed_script("ひらがなカタカナ漢字").is_ok());
+        // Latin with Cyrillic "а"
+        assert!(reject_mixed_script("pаypаl").is_err());
+    }
+
+    #[test]
+    fn confusables_flag_lookalikes_of_ascii() {
+        assert!(reject_confusables("аррӏе").is_err());
+        assert!(reject_confusables("paypal").is_ok());
+        assert!(reject_confusables("café").is_ok());
+        assert!(reject_confusables("Дмитрий").is_ok());
+    }
+}
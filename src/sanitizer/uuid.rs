@@ -0,0 +1,174 @@
+//! UUID sanitizer.
+
+use super::{checked_trim, SanitizationError, SanitizationErrorKind, Sanitizer};
+use crate::contexts::Plain;
+use crate::{Approval, Tainted, Verified};
+
+/// Length of the hyphenated form, `8-4-4-4-12` hex digits.
+const UUID_LEN: usize = 36;
+
+/// A sanitizer for UUIDs in the hyphenated `8-4-4-4-12` form.
+///
+/// Hex digits may be upper- or lowercase; the verified value is always the
+/// lowercase hyphenated form, so equal UUIDs compare equal as strings. Braced,
+/// URN and unhyphenated forms are rejected.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{SanitizationErrorKind, Sanitizer, Tainted, UuidSanitizer};
+///
+/// let sanitizer = UuidSanitizer::new().with_version(4);
+///
+/// let id = sanitizer
+///     .sanitize(Tainted::new("3F2504E0-4F89-41D3-9A0C-0305E82C3301".to_string()))
+///     .unwrap();
+/// assert_eq!(id.as_ref(), "3f2504e0-4f89-41d3-9a0c-0305e82c3301");
+///
+/// let err = sanitizer
+///     .sanitize(Tainted::new("3f2504e0-4f89-11d3-9a0c-0305e82c3301".to_string()))
+///     .unwrap_err();
+/// assert_eq!(err.kind(), SanitizationErrorKind::NotAllowed);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UuidSanitizer {
+    version: Option<u8>,
+    allow_nil: bool,
+}
+
+impl UuidSanitizer {
+    /// Creates a sanitizer accepting any non-nil UUID.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requires the given UUID version (e.g. 4 for random, 7 for time-ordered).
+    pub fn with_version(mut self, version: u8) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Accepts the nil UUID (all zeros), which is rejected by default.
+    pub fn allow_nil(mut self) -> Self {
+        self.allow_nil = true;
+        self
+    }
+}
+
+impl Sanitizer<String> for UuidSanitizer {
+    type Context = Plain;
+
+    fn sanitize(&self, input: Tainted<String>) -> Result<Verified<String>, SanitizationError> {
+        input.sanitize_inner(|raw| {
+            let trimmed = checked_trim(&raw, UUID_LEN)?;
+
+            let well_formed = trimmed.len() == UUID_LEN
+                && trimmed.bytes().enumerate().all(|(i, b)| match i {
+                    8 | 13 | 18 | 23 => b == b'-',
+                    _ => b.is_ascii_hexdigit(),
+                });
+            if !well_formed {
+                return Err(SanitizationError::new(
+                    SanitizationErrorKind::MalformedInput,
+                    "input is not a hyphenated UUID",
+                ));
+            }
+
+            let uuid = trimmed.to_ascii_lowercase();
+
+            if uuid.bytes().all(|b| b == b'0' || b == b'-') {
+                if !self.allow_nil {
+                    return Err(SanitizationError::new(
+                        SanitizationErrorKind::NotAllowed,
+                        "nil UUID is not allowed",
+                    ));
+                }
+            } else if let Some(version) = self.version {
+                // The version is the first hex digit of the third group
+                let actual = u8::from_str_radix(&uuid[14..15], 16).unwrap_or_default();
+                if actual != version {
+                    return Err(SanitizationError::new(
+                        SanitizationErrorKind::NotAllowed,
+                        format!("UUID must be version {}", version),
+                    ));
+                }
+            }
+
+            // BREAKING CHANGE WARNING: Verified::new_unchecked() MUST ONLY be called AFTER
+            // all validation checks pass. Moving this before validation creates a CRITICAL BYPASS.
+            let transformed = uuid != raw;
+            Ok(Verified::new_unchecked(uuid).approved_by(Approval::new(
+                "UuidSanitizer",
+                self,
+                transformed,
+            )))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NIL: &str = "00000000-0000-0000-0000-000000000000";
+
+    fn sanitize(sanitizer: &UuidSanitizer, input: &str) -> Result<String, SanitizationErrorKind> {
+        sanitizer
+            .sanitize(Tainted::new(input.to_string()))
+            .map(Verified::into_inner)
+            .map_err(|err| err.kind())
+    }
+
+    #[test]
+    fn accepts_and_lowercases() {
+        assert_eq!(
+            sanitize(
+                &UuidSanitizer::new(),
+                " 01890A5D-AC96-774B-BCCE-B302099A8057 "
+            ),
+            Ok("01890a5d-ac96-774b-bcce-b302099a8057".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_other_forms() {
+        let sanitizer = UuidSanitizer::new();
+
+        for input in [
+            "{01890a5d-ac96-774b-bcce-b302099a8057}",
+            "urn:uuid:01890a5d-ac96-774b-bcce-b302099a8057",
+            "01890a5dac96774bbccEb302099a8057",
+            "01890a5d-ac96-774b-bcce-b302099a805",
+            "01890a5d-ac96-774b-bcce-b302099a805g",
+            "01890a5d_ac96_774b_bcce_b302099a8057",
+        ] {
+            assert!(
+                matches!(
+                    sanitize(&sanitizer, input),
+                    Err(SanitizationErrorKind::MalformedInput | SanitizationErrorKind::TooLong)
+                ),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn nil_is_opt_in() {
+        assert_eq!(
+            sanitize(&UuidSanitizer::new(), NIL),
+            Err(SanitizationErrorKind::NotAllowed)
+        );
+        assert!(sanitize(&UuidSanitizer::new().allow_nil().with_version(4), NIL).is_ok());
+    }
+
+    #[test]
+    fn version_is_checked() {
+        let v7 = UuidSanitizer::new().with_version(7);
+
+        assert!(sanitize(&v7, "01890a5d-ac96-774b-bcce-b302099a8057").is_ok());
+        assert_eq!(
+            sanitize(&v7, "3f2504e0-4f89-41d3-9a0c-0305e82c3301"),
+            Err(SanitizationErrorKind::NotAllowed)
+        );
+    }
+}
@@ -1,5 +1,21 @@
 use std::fmt;
 
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+use crate::Tainted;
+
+mod declassify;
+mod expiring;
+#[cfg(feature = "serde")]
+mod serialization;
+
+pub use declassify::{DeclassifyCap, PolicyDeclassify};
+pub use expiring::{ExpiringSecret, RotatingSecret};
+
+#[cfg(feature = "serde")]
+pub use serialization::dangerously_expose_secret;
+
 /// A wrapper that prevents accidental exposure of sensitive values.
 ///
 /// `Secret<T>` ensures that sensitive data (passwords, API keys, tokens, etc.)
@@ -12,6 +28,20 @@ use std::fmt;
 /// - Debug and Display output is always `[REDACTED]`
 /// - No type information is leaked in formatted output
 /// - Access requires explicit, intentionally "scary" method call
+/// - The value is wiped with [`Zeroize`] when the `Secret` is dropped
+/// - Comparisons with [`ct_eq`](Self::ct_eq) take time independent of the contents
+/// - With the `serde` feature, deserializes like `T` but serializes as `"[REDACTED]"`
+///
+/// # Zeroization
+///
+/// `T` must implement [`Zeroize`], which covers `String`, `Vec<u8>`, byte
+/// arrays, integers and `Option`s of them, and can be derived for your own
+/// types with the `zeroize` crate. The writes cannot be optimized away.
+///
+/// Only the buffer owned by the `Secret` is wiped. Copies made before the
+/// value was wrapped (for example by a `String` reallocating while it was
+/// built) and copies taken from [`expose_secret`](Self::expose_secret) are
+/// not, so wrap secrets as soon as they are read.
 ///
 /// # Examples
 ///
@@ -30,13 +60,14 @@ use std::fmt;
 /// ```
 // BREAKING CHANGE WARNING: Do NOT add Clone, Copy, or Default derives.
 // These would bypass redaction protections and allow secrets to be duplicated carelessly.
-pub struct Secret<T> {
+// Do NOT remove the `Zeroize` bound: it is what lets `Drop` wipe the value (CWE-226).
+pub struct Secret<T: Zeroize> {
     // BREAKING CHANGE WARNING: This field MUST remain private.
     // Making it public exposes secrets directly, defeating automatic redaction (CWE-532).
     inner: T,
 }
 
-impl<T> Secret<T> {
+impl<T: Zeroize> Secret<T> {
     /// Wraps a sensitive value in a `Secret`.
     ///
     /// The value will be protected from accidental exposure through
@@ -57,11 +88,69 @@ impl<T> Secret<T> {
     }
 }
 
+impl<T: Zeroize + AsRef<[u8]>> Secret<T> {
+    /// Compares two secrets in constant time.
+    ///
+    /// Use this instead of `expose_secret() ==`, which returns as soon as a
+    /// byte differs and so reveals through timing how much of a guess was
+    /// right (CWE-208). Only the lengths of the two values may leak.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::Secret;
+    ///
+    /// let stored = Secret::new("sk-1234567890".to_string());
+    /// assert!(stored.ct_eq(&Secret::new(b"sk-1234567890".to_vec())));
+    /// assert!(!stored.ct_eq(&Secret::new("sk-1234567899".to_string())));
+    /// ```
+    pub fn ct_eq<U: Zeroize + AsRef<[u8]>>(&self, other: &Secret<U>) -> bool {
+        self.inner.as_ref().ct_eq(other.inner.as_ref()).into()
+    }
+
+    /// Checks an untrusted candidate, such as a presented API key or webhook
+    /// token, against this secret in constant time.
+    ///
+    /// The candidate does not need to be sanitized first: only whether it
+    /// matches is revealed, never the secret or the candidate. Only the
+    /// lengths of the two values may leak through timing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{Secret, Tainted};
+    ///
+    /// let webhook_token = Secret::new("whsec_9f8e7d".to_string());
+    ///
+    /// let presented = Tainted::new("whsec_9f8e7d".to_string());
+    /// assert!(webhook_token.ct_eq_tainted(&presented));
+    ///
+    /// let forged = Tainted::new("whsec_000000".to_string());
+    /// assert!(!webhook_token.ct_eq_tainted(&forged));
+    /// ```
+    pub fn ct_eq_tainted<U: AsRef<[u8]>>(&self, candidate: &Tainted<U>) -> bool {
+        self.inner
+            .as_ref()
+            .ct_eq(candidate.as_inner().as_ref())
+            .into()
+    }
+}
+
+impl<T: Zeroize> Drop for Secret<T> {
+    /// BREAKING CHANGE WARNING: This MUST wipe the value. Secrets left in freed
+    /// memory can be recovered from core dumps, swap or later allocations (CWE-226).
+    fn drop(&mut self) {
+        self.inner.zeroize();
+    }
+}
+
 // BREAKING CHANGE WARNING: Do NOT implement Deref, AsRef, Borrow, Display (that shows the value),
 // Debug (that shows the value), or any other trait that would expose the secret implicitly.
 // The ONLY access should be through expose_secret(), which has an intentionally scary name.
+// Do NOT implement PartialEq either: a derived comparison short-circuits and leaks timing.
+// Use ct_eq() and ct_eq_tainted() instead.
 
-impl<T> fmt::Debug for Secret<T> {
+impl<T: Zeroize> fmt::Debug for Secret<T> {
     /// BREAKING CHANGE WARNING: This MUST unconditionally return "\[REDACTED\]".
     /// Changing this to show the actual value (even in debug builds) defeats the
     /// entire purpose of `Secret<T>` and enables accidental secret exposure in logs (CWE-532).
@@ -70,7 +159,7 @@ impl<T> fmt::Debug for Secret<T> {
     }
 }
 
-impl<T> fmt::Display for Secret<T> {
+impl<T: Zeroize> fmt::Display for Secret<T> {
     /// BREAKING CHANGE WARNING: This MUST unconditionally return "\[REDACTED\]".
     /// Changing this to show the actual value exposes secrets in user-facing output,
     /// error messages, and logs (CWE-532: Insertion of Sensitive Information into Log File).
@@ -81,6 +170,9 @@ impl<T> fmt::Display for Secret<T> {
 
 #[cfg(test)]
 mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
     use super::*;
 
     #[test]
@@ -95,7 +187,7 @@ mod tests {
 
     #[test]
     fn secret_redacts_display() {
-        let api_key = Secret::new("sk-1234567890");
+        let api_key = Secret::new("sk-1234567890".to_string());
         let display_output = format!("{}", api_key);
 
         assert_eq!(display_output, "[REDACTED]");
@@ -121,4 +213,44 @@ mod tests {
         let vec_ref = secret.expose_secret();
         assert_eq!(vec_ref, &vec![1, 2, 3]);
     }
+
+    /// Records whether it was wiped, since freed memory cannot be inspected
+    /// without unsafe code.
+    struct Probe(Rc<Cell<bool>>);
+
+    impl Zeroize for Probe {
+        fn zeroize(&mut self) {
+            self.0.set(true);
+        }
+    }
+
+    #[test]
+    fn secret_zeroizes_on_drop() {
+        let wiped = Rc::new(Cell::new(false));
+        let secret = Secret::new(Probe(Rc::clone(&wiped)));
+        assert!(!wiped.get());
+
+        drop(secret);
+        assert!(wiped.get());
+    }
+
+    #[test]
+    fn secret_ct_eq_compares_contents() {
+        let key = Secret::new("sk-1234567890".to_string());
+
+        assert!(key.ct_eq(&Secret::new("sk-1234567890".to_string())));
+        assert!(key.ct_eq(&Secret::new(*b"sk-1234567890")));
+        assert!(!key.ct_eq(&Secret::new("sk-1234567891".to_string())));
+        assert!(!key.ct_eq(&Secret::new("sk-123".to_string())));
+        assert!(!key.ct_eq(&Secret::new(String::new())));
+    }
+
+    #[test]
+    fn secret_ct_eq_tainted_checks_untrusted_candidates() {
+        let token = Secret::new(b"whsec_9f8e7d".to_vec());
+
+        assert!(token.ct_eq_tainted(&Tainted::new("whsec_9f8e7d".to_string())));
+        assert!(!token.ct_eq_tainted(&Tainted::new("WHSEC_9F8E7D".to_string())));
+        assert!(!token.ct_eq_tainted(&Tainted::new("whsec_9f8e7d\0".to_string())));
+    }
 }
@@ -0,0 +1,309 @@
+//! Audited declassification of secrets and labeled values.
+//!
+//! [`Secret::expose_secret`] leaves no trace. [`PolicyDeclassify`], obtained
+//! from [`Ctx::declassify`](crate::Ctx::declassify) with a [`DeclassifyCap`],
+//! is the accountable alternative: every exposure names a reason and is
+//! reported on the `policy_audit` tracing target with the caller's source
+//! location and principal before the plaintext is returned.
+//!
+//! It is also the only way to remove the information-flow
+//! [labels](crate::labels) from a [`Labeled`] value.
+//!
+//! Applications that route all access through it can forbid the bare method,
+//! for example with Clippy's `disallowed-methods` in `clippy.toml`:
+//!
+//! ```toml
+//! disallowed-methods = [
+//!     { path = "policy_core::Secret::expose_secret", reason = "use ctx.declassify()" },
+//! ]
+//! ```
+
+use std::panic::Location;
+
+use zeroize::Zeroize;
+
+use super::Secret;
+use crate::audit::{emit_event, AuditEvent, AuditEventKind, AuditOutcome};
+use crate::capability::{Capability, Grant};
+use crate::error::{Violation, ViolationKind};
+use crate::labels::{LabelSet, Labeled};
+use crate::policy::actions;
+
+/// Capability proving authorization to declassify secrets and labeled values.
+///
+/// Granted when the [`actions::DECLASSIFY`] action (`"secrets:declassify"`)
+/// is authorized. Required by [`Ctx::declassify`](crate::Ctx::declassify).
+///
+/// # Example
+///
+/// ```
+/// use policy_core::{actions, AllowAuthenticated, Authorized, PolicyGate, Principal, RequestMeta};
+///
+/// let meta = RequestMeta {
+///     request_id: "req-123".to_string(),
+///     principal: Some(Principal::new("svc-1", "Billing")),
+/// };
+/// let ctx = PolicyGate::new(meta)
+///     .with_authorizer(AllowAuthenticated)
+///     .require(Authorized::for_action(actions::DECLASSIFY))
+///     .build()
+///     .unwrap();
+///
+/// assert!(ctx.declassify_cap().is_some());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct DeclassifyCap {
+    // BREAKING CHANGE WARNING: This field MUST remain private.
+    // Making it public allows external code to forge declassification capabilities via
+    // struct literal, exposing secrets without authorization.
+    _private: (),
+}
+
+impl DeclassifyCap {
+    /// Creates a new `DeclassifyCap`.
+    ///
+    /// This is `pub(crate)` to prevent external forgery. Only the policy
+    /// gate can create capabilities after validating authorization.
+    ///
+    /// BREAKING CHANGE WARNING: Changing visibility to `pub` allows CAPABILITY FORGERY.
+    /// External code could expose secrets through an apparently audited path
+    /// without authorization (CWE-863: Incorrect Authorization).
+    pub(crate) fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl Capability for DeclassifyCap {
+    const ACTION: &'static str = actions::DECLASSIFY;
+
+    fn from_grant(_grant: Grant<Self>) -> Self {
+        Self::new()
+    }
+}
+
+/// A capability-gated, audited way to read [`Secret`] values and remove
+/// labels from [`Labeled`] values.
+///
+/// `PolicyDeclassify` is obtained from `Ctx::declassify()` and requires
+/// `DeclassifyCap`. It is lifetime-bound to the context to prevent misuse.
+///
+/// # Security Properties
+///
+/// - Requires `DeclassifyCap` to construct (capability-gated)
+/// - Requires a non-empty reason, fixed at compile time
+/// - Audits every exposure before returning the value, with the request ID,
+///   principal, reason and caller location (and the removed labels for
+///   [`unlabel`](Self::unlabel)), but never the value
+///
+/// # Examples
+///
+/// ```
+/// # use policy_core::{actions, AllowAuthenticated, Authorized, PolicyGate, Principal, RequestMeta, Secret};
+/// # let meta = RequestMeta {
+/// #     request_id: "req-1".to_string(),
+/// #     principal: Some(Principal::new("svc-1", "Billing")),
+/// # };
+/// let ctx = PolicyGate::new(meta)
+///     .with_authorizer(AllowAuthenticated)
+///     .require(Authorized::for_action(actions::DECLASSIFY))
+///     .build()
+///     .unwrap();
+///
+/// let api_key = Secret::new("sk-live-123".to_string());
+/// let declassify = ctx.declassify().expect("DeclassifyCap required");
+///
+/// let key = declassify
+///     .expose(&api_key, "sign outbound payment request")
+///     .unwrap();
+/// assert_eq!(key, "sk-live-123");
+/// ```
+#[derive(Debug)]
+pub struct PolicyDeclassify<'a> {
+    request_id: &'a str,
+    principal: Option<&'a str>,
+}
+
+impl<'a> PolicyDeclassify<'a> {
+    /// Creates a new PolicyDeclassify for a request.
+    ///
+    /// This is `pub(crate)` - only `Ctx` can create it.
+    pub(crate) fn new(request_id: &'a str, principal: Option<&'a str>) -> Self {
+        Self {
+            request_id,
+            principal,
+        }
+    }
+
+    /// Returns the request ID associated with this declassifier.
+    pub fn request_id(&self) -> &str {
+        self.request_id
+    }
+
+    /// Returns the value of `secret` after auditing the exposure.
+    ///
+    /// `reason` is a string literal so that it is reviewed with the code and
+    /// cannot be built from runtime data, including the secret itself. The
+    /// audit event records it together with the source location of this call.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(Violation)` with [`ViolationKind::InvalidInput`] if
+    /// `reason` is empty or only whitespace. The refused attempt is audited
+    /// as [`AuditOutcome::Denied`].
+    #[track_caller]
+    pub fn expose<'s, T: Zeroize>(
+        &self,
+        secret: &'s Secret<T>,
+        reason: &'static str,
+    ) -> Result<&'s T, Violation> {
+        self.audit(reason, None, Location::caller())?;
+        Ok(secret.expose_secret())
+    }
+
+    /// Removes every label from `value` after auditing the removal.
+    ///
+    /// From here on the value may flow to any sink, so `reason` should say
+    /// why that is acceptable. As for [`expose`](Self::expose), it is a
+    /// string literal, and the audit event records it with the source
+    /// location of this call and the names of the removed labels.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err(Violation)` with [`ViolationKind::InvalidInput`] if
+    /// `reason` is empty or only whitespace. The refused attempt is audited
+    /// as [`AuditOutcome::Denied`], and the value is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use policy_core::{actions, AllowAuthenticated, Authorized, PolicyGate, Principal, RequestMeta};
+    /// use policy_core::web::{ExtractTaintedInputs, RequestAdapter};
+    /// use policy_core::StringSanitizer;
+    ///
+    /// # let meta = RequestMeta {
+    /// #     request_id: "req-1".to_string(),
+    /// #     principal: Some(Principal::new("u1", "Alice")),
+    /// # };
+    /// let ctx = PolicyGate::new(meta)
+    ///     .with_authorizer(AllowAuthenticated)
+    ///     .require(Authorized::for_action(actions::DECLASSIFY))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let mut request = RequestAdapter::new("req-1".to_string());
+    /// request.add_query_param("name".to_string(), " Alice ".to_string());
+    /// let name = request
+    ///     .extract_tainted_inputs()
+    ///     .get_query("name")
+    ///     .unwrap()
+    ///     .sanitize(&StringSanitizer::new(64).unwrap())
+    ///     .unwrap();
+    ///
+    /// let name = ctx
+    ///     .declassify()
+    ///     .expect("DeclassifyCap required")
+    ///     .unlabel(name, "display name shown back to its owner")
+    ///     .unwrap();
+    /// assert_eq!(name.as_ref(), "Alice");
+    /// ```
+    #[track_caller]
+    pub fn unlabel<T, L: LabelSet>(
+        &self,
+        value: Labeled<T, L>,
+        reason: &'static str,
+    ) -> Result<T, Violation> {
+        let labels = L::names().join(",");
+        self.audit(reason, Some(&labels), Location::caller())?;
+        Ok(value.into_value())
+    }
+
+    /// Audits a declassification and rejects it if `reason` is blank.
+    fn audit(
+        &self,
+        reason: &'static str,
+        labels: Option<&str>,
+        location: &'static Location<'static>,
+    ) -> Result<(), Violation> {
+        let reason = reason.trim();
+        let outcome = if reason.is_empty() {
+            AuditOutcome::Denied
+        } else {
+            AuditOutcome::Success
+        };
+
+        let mut event = AuditEvent::new(
+            self.request_id,
+            self.principal,
+            AuditEventKind::ResourceAccess,
+            outcome,
+        )
+        .with_action(DeclassifyCap::ACTION)
+        .with_location(location);
+        match (reason.is_empty(), labels) {
+            (true, _) => {}
+            (false, None) => event = event.with_reason(reason),
+            (false, Some(labels)) => {
+                event = event.with_reason(format!("{} (labels: {})", reason, labels))
+            }
+        }
+
+        // BREAKING CHANGE WARNING: The event MUST be emitted before the value is
+        // returned. Returning first lets a panicking or early-exiting caller read
+        // the value without leaving a record (CWE-778).
+        emit_event(&event);
+
+        if reason.is_empty() {
+            return Err(Violation::new(
+                ViolationKind::InvalidInput,
+                "Declassification requires a reason",
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn declassify_cap_is_zero_sized_and_bound_to_action() {
+        assert_eq!(std::mem::size_of::<DeclassifyCap>(), 0);
+        assert_eq!(DeclassifyCap::ACTION, "secrets:declassify");
+    }
+
+    #[test]
+    fn expose_returns_value_with_reason() {
+        let declassify = PolicyDeclassify::new("req-1", Some("svc-1"));
+        let secret = Secret::new("hunter2".to_string());
+
+        let value = declassify.expose(&secret, "verify webhook").unwrap();
+
+        assert_eq!(value, "hunter2");
+    }
+
+    #[test]
+    fn expose_requires_a_reason() {
+        let declassify = PolicyDeclassify::new("req-2", None);
+        let secret = Secret::new("hunter2".to_string());
+
+        for reason in ["", "  \t"] {
+            let err = declassify.expose(&secret, reason).unwrap_err();
+            assert_eq!(err.kind, ViolationKind::InvalidInput);
+        }
+    }
+
+    #[test]
+    fn unlabel_returns_value_with_reason() {
+        use crate::labels::PersonalData;
+
+        let declassify = PolicyDeclassify::new("req-3", Some("svc-1"));
+        let email = Labeled::<_, PersonalData>::new("ann@example.com".to_string());
+
+        let err = declassify.unlabel(email.clone(), " ").unwrap_err();
+        assert_eq!(err.kind, ViolationKind::InvalidInput);
+
+        let value = declassify.unlabel(email, "send receipt").unwrap();
+        assert_eq!(value, "ann@example.com");
+    }
+}
@@ -0,0 +1,442 @@
+//! Secrets with a lifetime.
+//!
+//! [`ExpiringSecret`] refuses access once its deadline has passed, and
+//! [`RotatingSecret`] replaces an expiring value with a fresh one from a
+//! refresh callback. Both read the time from a [`Clock`].
+
+use std::fmt;
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::time::{Duration, SystemTime};
+
+use zeroize::Zeroize;
+
+use super::Secret;
+use crate::clock::{Clock, SystemClock};
+use crate::secrets::{SecretError, SecretErrorKind};
+
+/// A [`Secret`] that can only be read until a deadline.
+///
+/// Use it for credentials with a lifetime, such as OAuth access tokens or
+/// signed URLs. Once the clock reaches `expires_at`, [`secret`](Self::secret)
+/// and [`expose_secret`](Self::expose_secret) fail with
+/// [`SecretErrorKind::Expired`]. The value itself is wiped when the
+/// `ExpiringSecret` is dropped, like any `Secret`.
+///
+/// Time comes from the [`SystemClock`] unless another [`Clock`] is set with
+/// [`with_clock`](Self::with_clock).
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use policy_core::{ExpiringSecret, ManualClock, SecretErrorKind};
+///
+/// let clock = Arc::new(ManualClock::new(UNIX_EPOCH));
+/// let token = ExpiringSecret::new("ya29.token".to_string(), UNIX_EPOCH + Duration::from_secs(3600))
+///     .with_clock(Arc::clone(&clock));
+///
+/// assert_eq!(token.expose_secret().unwrap(), "ya29.token");
+///
+/// clock.advance(Duration::from_secs(3600));
+/// assert_eq!(token.expose_secret().unwrap_err().kind(), SecretErrorKind::Expired);
+/// ```
+pub struct ExpiringSecret<T: Zeroize> {
+    secret: Secret<T>,
+    expires_at: SystemTime,
+    clock: Arc<dyn Clock>,
+}
+
+impl<T: Zeroize> ExpiringSecret<T> {
+    /// Wraps a value that may be read until `expires_at`.
+    pub fn new(value: T, expires_at: SystemTime) -> Self {
+        Self {
+            secret: Secret::new(value),
+            expires_at,
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Sets the clock deadlines are checked against.
+    pub fn with_clock(self, clock: impl Clock + 'static) -> Self {
+        self.with_shared_clock(Arc::new(clock))
+    }
+
+    /// Sets the clock without wrapping it in another `Arc`.
+    pub(crate) fn with_shared_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Returns the deadline after which the value can no longer be read.
+    pub fn expires_at(&self) -> SystemTime {
+        self.expires_at
+    }
+
+    /// Returns whether the deadline has been reached.
+    pub fn is_expired(&self) -> bool {
+        self.clock.now() >= self.expires_at
+    }
+
+    /// Returns the time left before the deadline, or `None` if it has passed.
+    pub fn remaining(&self) -> Option<Duration> {
+        self.expires_at
+            .duration_since(self.clock.now())
+            .ok()
+            .filter(|left| !left.is_zero())
+    }
+
+    /// Returns the wrapped [`Secret`] if the deadline has not been reached.
+    ///
+    /// Use this to pass the value on without exposing it, for example to
+    /// [`PolicyDeclassify::expose`](crate::PolicyDeclassify::expose) or
+    /// [`Secret::ct_eq`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretErrorKind::Expired`] once the deadline has passed.
+    pub fn secret(&self) -> Result<&Secret<T>, SecretError> {
+        // BREAKING CHANGE WARNING: Every accessor MUST go through this check.
+        // Handing out the value after its deadline defeats the expiry (CWE-613).
+        if self.is_expired() {
+            return Err(SecretError::new(SecretErrorKind::Expired));
+        }
+        Ok(&self.secret)
+    }
+
+    /// Explicitly exposes the value if the deadline has not been reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretErrorKind::Expired`] once the deadline has passed.
+    pub fn expose_secret(&self) -> Result<&T, SecretError> {
+        self.secret().map(Secret::expose_secret)
+    }
+}
+
+impl<T: Zeroize> fmt::Debug for ExpiringSecret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExpiringSecret")
+            .field("value", &self.secret)
+            .field("expires_at", &self.expires_at)
+            .finish()
+    }
+}
+
+/// Callback producing a fresh value for a [`RotatingSecret`].
+type Refresh<T> = dyn Fn() -> Result<ExpiringSecret<T>, SecretError> + Send + Sync;
+
+/// Holder for a credential that is replaced before it expires.
+///
+/// The first call to [`current`](Self::current), and every call once the
+/// held value is expired or within the refresh margin of its deadline, runs
+/// the refresh callback and swaps in its result. If a refresh fails while
+/// the held value is still valid, that value keeps being returned until it
+/// expires.
+///
+/// Refreshed values are checked against the holder's [`Clock`], whatever
+/// clock the callback gave them. Concurrent callers share one refresh.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicU32, Ordering};
+/// use std::time::{Duration, UNIX_EPOCH};
+/// use policy_core::{Clock, ExpiringSecret, ManualClock, RotatingSecret};
+///
+/// let clock = Arc::new(ManualClock::new(UNIX_EPOCH));
+/// let issued = Arc::new(AtomicU32::new(0));
+///
+/// let token = RotatingSecret::new({
+///     let clock = Arc::clone(&clock);
+///     let issued = Arc::clone(&issued);
+///     move || {
+///         let n = issued.fetch_add(1, Ordering::SeqCst);
+///         Ok(ExpiringSecret::new(format!("token-{n}"), clock.now() + Duration::from_secs(60)))
+///     }
+/// })
+/// .with_clock(Arc::clone(&clock))
+/// .with_refresh_margin(Duration::from_secs(10));
+///
+/// assert_eq!(token.current().unwrap().expose_secret().unwrap(), "token-0");
+///
+/// // Within 10 seconds of the deadline the token is rotated
+/// clock.advance(Duration::from_secs(55));
+/// assert_eq!(token.current().unwrap().expose_secret().unwrap(), "token-1");
+/// ```
+pub struct RotatingSecret<T: Zeroize> {
+    current: RwLock<Option<Arc<ExpiringSecret<T>>>>,
+    refresh: Box<Refresh<T>>,
+    clock: Arc<dyn Clock>,
+    refresh_margin: Duration,
+}
+
+impl<T: Zeroize> RotatingSecret<T> {
+    /// Creates a holder that obtains values from `refresh`.
+    ///
+    /// Nothing is fetched until the first call to [`current`](Self::current).
+    pub fn new(
+        refresh: impl Fn() -> Result<ExpiringSecret<T>, SecretError> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            current: RwLock::new(None),
+            refresh: Box::new(refresh),
+            clock: Arc::new(SystemClock),
+            refresh_margin: Duration::ZERO,
+        }
+    }
+
+    /// Sets the clock deadlines are checked against.
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Refreshes values this long before their deadline instead of at it.
+    pub fn with_refresh_margin(mut self, margin: Duration) -> Self {
+        self.refresh_margin = margin;
+        self
+    }
+
+    /// Returns the current value, refreshing it first if it is due.
+    ///
+    /// The returned `ExpiringSecret` still enforces its own deadline, so a
+    /// copy held for too long stops working.
+    ///
+    /// # Errors
+    ///
+    /// Returns the refresh callback's error if no valid value is held, or
+    /// [`SecretErrorKind::Expired`] if the callback returned a value that
+    /// had already expired.
+    pub fn current(&self) -> Result<Arc<ExpiringSecret<T>>, SecretError> {
+        if let Some(current) = self.read().as_ref() {
+            if !self.is_due(current) {
+                return Ok(Arc::clone(current));
+            }
+        }
+
+        let mut slot = self.write();
+        // Another caller may have refreshed while we waited for the lock
+        if let Some(current) = slot.as_ref() {
+            if !self.is_due(current) {
+                return Ok(Arc::clone(current));
+            }
+        }
+        match self.fetch() {
+            Ok(fresh) => {
+                *slot = Some(Arc::clone(&fresh));
+                Ok(fresh)
+            }
+            Err(e) => match slot.as_ref() {
+                Some(current) if !current.is_expired() => Ok(Arc::clone(current)),
+                _ => Err(e),
+            },
+        }
+    }
+
+    /// Replaces the held value with a fresh one, regardless of its deadline.
+    ///
+    /// Use this when a credential was revoked early, e.g. after a `401`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the refresh callback's error, in which case the held value is
+    /// kept.
+    pub fn rotate(&self) -> Result<Arc<ExpiringSecret<T>>, SecretError> {
+        let fresh = self.fetch()?;
+        *self.write() = Some(Arc::clone(&fresh));
+        Ok(fresh)
+    }
+
+    fn fetch(&self) -> Result<Arc<ExpiringSecret<T>>, SecretError> {
+        let fresh = (self.refresh)()?.with_shared_clock(Arc::clone(&self.clock));
+        if fresh.is_expired() {
+            return Err(SecretError::with_message(
+                SecretErrorKind::Expired,
+                "refresh returned an already expired secret",
+            ));
+        }
+        Ok(Arc::new(fresh))
+    }
+
+    fn is_due(&self, secret: &ExpiringSecret<T>) -> bool {
+        match secret.expires_at().checked_sub(self.refresh_margin) {
+            Some(refresh_at) => self.clock.now() >= refresh_at,
+            None => true,
+        }
+    }
+
+    // The lock only guards the swap, so its data stays consistent even if a
+    // holder panicked.
+    fn read(&self) -> RwLockReadGuard<'_, Option<Arc<ExpiringSecret<T>>>> {
+        self.current.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Option<Arc<ExpiringSecret<T>>>> {
+        self.current.write().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T: Zeroize> fmt::Debug for RotatingSecret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RotatingSecret")
+            .field("current", &*self.read())
+            .field("refresh_margin", &self.refresh_margin)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ManualClock;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::time::UNIX_EPOCH;
+
+    fn at(secs: u64) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(secs)
+    }
+
+    #[test]
+    fn expiring_secret_is_readable_until_its_deadline() {
+        let clock = Arc::new(ManualClock::new(at(0)));
+        let secret = ExpiringSecret::new("token".to_string(), at(10)).with_clock(clock.clone());
+
+        clock.set(at(9));
+        assert_eq!(secret.expose_secret().unwrap(), "token");
+        assert_eq!(secret.remaining(), Some(Duration::from_secs(1)));
+
+        clock.set(at(10));
+        assert!(secret.is_expired());
+        assert_eq!(secret.remaining(), None);
+        assert_eq!(
+            secret.secret().unwrap_err().kind(),
+            SecretErrorKind::Expired
+        );
+    }
+
+    #[test]
+    fn expiring_secret_debug_is_redacted() {
+        let secret = ExpiringSecret::new("token".to_string(), at(10));
+
+        let debug = format!("{:?}", secret);
+
+        assert!(debug.contains("[REDACTED]"));
+        assert!(!debug.contains("token"));
+    }
+
+    /// Rotating secret whose values are `v0`, `v1`, ... valid for 60s each.
+    fn counting(clock: &Arc<ManualClock>) -> (RotatingSecret<String>, Arc<AtomicU32>) {
+        let calls = Arc::new(AtomicU32::new(0));
+        let secret = RotatingSecret::new({
+            let clock = Arc::clone(clock);
+            let calls = Arc::clone(&calls);
+            move || {
+                let n = calls.fetch_add(1, Ordering::SeqCst);
+                Ok(ExpiringSecret::new(
+                    format!("v{n}"),
+                    clock.now() + Duration::from_secs(60),
+                ))
+            }
+        })
+        .with_clock(Arc::clone(clock));
+        (secret, calls)
+    }
+
+    #[test]
+    fn rotating_secret_refreshes_lazily_and_on_expiry() {
+        let clock = Arc::new(ManualClock::new(at(0)));
+        let (secret, calls) = counting(&clock);
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+        assert_eq!(secret.current().unwrap().expose_secret().unwrap(), "v0");
+        clock.set(at(59));
+        assert_eq!(secret.current().unwrap().expose_secret().unwrap(), "v0");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        clock.set(at(60));
+        assert_eq!(secret.current().unwrap().expose_secret().unwrap(), "v1");
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn rotating_secret_refreshes_within_margin() {
+        let clock = Arc::new(ManualClock::new(at(0)));
+        let (secret, _) = counting(&clock);
+        let secret = secret.with_refresh_margin(Duration::from_secs(15));
+
+        let first = secret.current().unwrap();
+        clock.set(at(45));
+        let second = secret.current().unwrap();
+
+        assert_eq!(second.expose_secret().unwrap(), "v1");
+        // The old copy is still valid until its own deadline
+        assert_eq!(first.expose_secret().unwrap(), "v0");
+        clock.set(at(60));
+        assert!(first.expose_secret().is_err());
+    }
+
+    #[test]
+    fn rotating_secret_keeps_valid_value_when_refresh_fails() {
+        let clock = Arc::new(ManualClock::new(at(0)));
+        let fail = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let secret = RotatingSecret::new({
+            let clock = Arc::clone(&clock);
+            let fail = Arc::clone(&fail);
+            move || {
+                if fail.load(Ordering::SeqCst) {
+                    return Err(SecretError::new(SecretErrorKind::Unavailable));
+                }
+                Ok(ExpiringSecret::new(
+                    "v".to_string(),
+                    clock.now() + Duration::from_secs(60),
+                ))
+            }
+        })
+        .with_clock(Arc::clone(&clock))
+        .with_refresh_margin(Duration::from_secs(30));
+
+        secret.current().unwrap();
+        fail.store(true, Ordering::SeqCst);
+
+        clock.set(at(40));
+        assert_eq!(secret.current().unwrap().expose_secret().unwrap(), "v");
+        assert!(secret.rotate().is_err());
+
+        clock.set(at(60));
+        assert_eq!(
+            secret.current().unwrap_err().kind(),
+            SecretErrorKind::Unavailable
+        );
+    }
+
+    #[test]
+    fn rotating_secret_rejects_already_expired_refresh() {
+        let secret = RotatingSecret::new(|| Ok(ExpiringSecret::new("stale".to_string(), at(5))))
+            .with_clock(ManualClock::new(at(10)));
+
+        assert_eq!(
+            secret.current().unwrap_err().kind(),
+            SecretErrorKind::Expired
+        );
+    }
+
+    #[test]
+    fn rotate_replaces_value_before_deadline() {
+        let clock = Arc::new(ManualClock::new(at(0)));
+        let (secret, _) = counting(&clock);
+
+        secret.current().unwrap();
+        secret.rotate().unwrap();
+
+        assert_eq!(secret.current().unwrap().expose_secret().unwrap(), "v1");
+    }
+
+    #[test]
+    fn rotating_secret_is_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<RotatingSecret<String>>();
+        assert_send_sync::<ExpiringSecret<String>>();
+    }
+}
@@ -0,0 +1,129 @@
+//! `serde` support for [`Secret`], behind the `serde` feature.
+//!
+//! Secrets deserialize like the value they wrap, so configuration can be
+//! loaded straight into `Secret` fields, but always serialize as
+//! `"[REDACTED]"` so they cannot leak into API responses or exports. Fields
+//! that genuinely need the value serialized must opt in with
+//! [`dangerously_expose_secret`].
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use zeroize::Zeroize;
+
+use super::Secret;
+
+impl<T: Zeroize> Serialize for Secret<T> {
+    /// BREAKING CHANGE WARNING: This MUST unconditionally serialize "\[REDACTED\]".
+    /// Serializing the value leaks credentials into JSON responses, audit exports
+    /// and caches (CWE-200). Exposure is opt-in per field via `dangerously_expose_secret`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str("[REDACTED]")
+    }
+}
+
+/// Deserializes the wrapped value and wraps it immediately.
+///
+/// Buffers the deserializer used while parsing are not wiped.
+impl<'de, T: Zeroize + Deserialize<'de>> Deserialize<'de> for Secret<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Secret::new)
+    }
+}
+
+/// Serializes the plaintext of a secret field.
+///
+/// For use with `#[serde(serialize_with = "...")]` on the few fields whose
+/// value must be written out, such as a credentials file generated by an
+/// admin tool. Every other `Secret` serializes as `"[REDACTED]"`.
+///
+/// # Security Warning
+///
+/// The name is intentionally alarming: every use writes secret material to
+/// wherever the serializer sends it. Never use it on types returned from
+/// API handlers or written to logs and audit exports.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::Secret;
+/// use serde::Serialize;
+///
+/// #[derive(Serialize)]
+/// struct Credentials {
+///     #[serde(serialize_with = "policy_core::dangerously_expose_secret")]
+///     api_key: Secret<String>,
+///     webhook_token: Secret<String>,
+/// }
+///
+/// let credentials = Credentials {
+///     api_key: Secret::new("sk-123".to_string()),
+///     webhook_token: Secret::new("whsec_456".to_string()),
+/// };
+/// assert_eq!(
+///     serde_json::to_string(&credentials).unwrap(),
+///     r#"{"api_key":"sk-123","webhook_token":"[REDACTED]"}"#
+/// );
+/// ```
+pub fn dangerously_expose_secret<T, S>(secret: &Secret<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Zeroize + Serialize,
+    S: Serializer,
+{
+    secret.expose_secret().serialize(serializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize, Serialize)]
+    struct Config {
+        user: String,
+        password: Secret<String>,
+    }
+
+    #[test]
+    fn secrets_deserialize_normally() {
+        let config: Config =
+            serde_json::from_str(r#"{"user":"svc","password":"hunter2"}"#).unwrap();
+
+        assert_eq!(config.password.expose_secret(), "hunter2");
+    }
+
+    #[test]
+    fn secrets_serialize_redacted() {
+        let config = Config {
+            user: "svc".to_string(),
+            password: Secret::new("hunter2".to_string()),
+        };
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(json, r#"{"user":"svc","password":"[REDACTED]"}"#);
+        assert!(!json.contains("hunter2"));
+    }
+
+    #[test]
+    fn redaction_does_not_depend_on_the_wrapped_type() {
+        let key = Secret::new(vec![1u8, 2, 3]);
+        assert_eq!(serde_json::to_string(&key).unwrap(), r#""[REDACTED]""#);
+
+        let pin = Secret::new(1234u32);
+        assert_eq!(serde_json::to_string(&pin).unwrap(), r#""[REDACTED]""#);
+    }
+
+    #[test]
+    fn exposing_serializer_writes_the_value() {
+        #[derive(Serialize)]
+        struct Export {
+            #[serde(serialize_with = "dangerously_expose_secret")]
+            password: Secret<String>,
+        }
+
+        let export = Export {
+            password: Secret::new("hunter2".to_string()),
+        };
+        assert_eq!(
+            serde_json::to_string(&export).unwrap(),
+            r#"{"password":"hunter2"}"#
+        );
+    }
+}
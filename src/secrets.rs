@@ -0,0 +1,509 @@
+//! Capability-gated access to named secrets.
+//!
+//! Services read credentials through [`PolicySecrets`], obtained from
+//! [`Ctx::secrets`](crate::Ctx::secrets) with a [`SecretsCap`], instead of
+//! calling `std::env::var` wherever a key is needed. Values come from the
+//! [`SecretStore`] configured with
+//! [`PolicyGate::with_secret_store`](crate::PolicyGate::with_secret_store) and
+//! are only ever handed out as [`Secret<String>`].
+//!
+//! [`SecretsCap`] only opens the reader. Each fetch is also authorized by
+//! name: the gate's [`Authorizer`] must allow `secrets:read:<name>`, so a
+//! service can be limited to the secrets it owns.
+//!
+//! Every fetch is reported as an [`AuditEventKind::ResourceAccess`] event on
+//! the `policy_audit` tracing target, naming the secret but never its value.
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::audit::{emit_event, AuditEvent, AuditEventKind, AuditOutcome};
+use crate::authorizer::{Authorizer, DenyAll};
+use crate::capability::Capability;
+use crate::request::Principal;
+use crate::Secret;
+
+mod capability;
+mod dir;
+mod env;
+
+pub use capability::SecretsCap;
+pub use dir::DirSecretStore;
+pub use env::EnvSecretStore;
+
+/// Maximum length of a secret name in bytes.
+pub(crate) const MAX_NAME_LEN: usize = 128;
+
+/// A request for a named secret, built by [`PolicySecrets`].
+///
+/// Stores receive this instead of a raw string. It cannot be constructed
+/// outside policy-core, so every `SecretRequest` a store sees has passed
+/// through a capability-gated `PolicySecrets` and carries a validated name.
+#[derive(Debug)]
+pub struct SecretRequest<'r> {
+    request_id: &'r str,
+    name: &'r str,
+}
+
+impl<'r> SecretRequest<'r> {
+    /// Creates a secret request.
+    ///
+    /// This is `pub(crate)` so stores only ever see names that passed
+    /// [`is_valid_name`].
+    pub(crate) fn new(request_id: &'r str, name: &'r str) -> Self {
+        Self { request_id, name }
+    }
+
+    /// Returns the ID of the request fetching the secret.
+    pub fn request_id(&self) -> &str {
+        self.request_id
+    }
+
+    /// Returns the name of the secret.
+    ///
+    /// Names are 1 to 128 ASCII letters, digits, `_`, `-` and `.`, and start
+    /// with a letter, digit or `_`. They never contain path separators or
+    /// `..` on their own.
+    pub fn name(&self) -> &str {
+        self.name
+    }
+}
+
+/// Error returned when a secret cannot be fetched.
+///
+/// Messages name the secret and the failure; they never include its value.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{SecretError, SecretErrorKind};
+///
+/// let error = SecretError::with_message(SecretErrorKind::NotFound, "secret 'db_password' is not set");
+/// assert_eq!(error.kind(), SecretErrorKind::NotFound);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretError {
+    kind: SecretErrorKind,
+    message: Option<String>,
+}
+
+impl SecretError {
+    /// Creates a new secret error with the specified kind.
+    pub fn new(kind: SecretErrorKind) -> Self {
+        Self {
+            kind,
+            message: None,
+        }
+    }
+
+    /// Creates a new secret error with a custom message.
+    pub fn with_message(kind: SecretErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: Some(message.into()),
+        }
+    }
+
+    /// Returns the error kind.
+    pub fn kind(&self) -> SecretErrorKind {
+        self.kind
+    }
+
+    /// Returns the error message, if any.
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+}
+
+impl fmt::Display for SecretError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(msg) = &self.message {
+            write!(f, "secret error ({}): {}", self.kind, msg)
+        } else {
+            write!(f, "secret error ({})", self.kind)
+        }
+    }
+}
+
+impl std::error::Error for SecretError {}
+
+/// Kind of secret store error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SecretErrorKind {
+    /// The secret name is empty, too long or contains disallowed characters.
+    InvalidName,
+    /// The store has no secret with this name.
+    NotFound,
+    /// The secret exists but could not be read (I/O error, too large, not UTF-8).
+    Unavailable,
+    /// No secret store was configured on the gate.
+    NotConfigured,
+    /// The principal is not authorized to read this secret.
+    Unauthorized,
+    /// The secret's deadline has passed.
+    Expired,
+}
+
+impl fmt::Display for SecretErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName => write!(f, "invalid name"),
+            Self::NotFound => write!(f, "not found"),
+            Self::Unavailable => write!(f, "unavailable"),
+            Self::NotConfigured => write!(f, "no secret store configured"),
+            Self::Unauthorized => write!(f, "unauthorized"),
+            Self::Expired => write!(f, "expired"),
+        }
+    }
+}
+
+/// Loads named secrets for [`PolicySecrets`].
+///
+/// Implementations must be `Send + Sync` so one store can be shared by every
+/// request handler. Configure it on the gate with
+/// [`PolicyGate::with_secret_store`](crate::PolicyGate::with_secret_store).
+///
+/// # Security Considerations
+///
+/// - **Never log values**: return them wrapped in `Secret` and nothing else
+/// - **Keep values out of errors**: `SecretError` messages end up in logs
+/// - **Do not audit**: `PolicySecrets` already records every fetch
+pub trait SecretStore: Send + Sync + fmt::Debug {
+    /// Loads the secret named by `request`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretErrorKind::NotFound`] if the store has no such secret,
+    /// or [`SecretErrorKind::Unavailable`] if it could not be read.
+    fn load(&self, request: &SecretRequest<'_>) -> Result<Secret<String>, SecretError>;
+}
+
+impl<T: SecretStore + ?Sized> SecretStore for Arc<T> {
+    fn load(&self, request: &SecretRequest<'_>) -> Result<Secret<String>, SecretError> {
+        (**self).load(request)
+    }
+}
+
+/// Secret settings configured on `PolicyGate` and shared by every
+/// `PolicySecrets` handed out by the resulting context.
+#[derive(Clone)]
+pub(crate) struct SecretsConfig {
+    /// Store secrets are loaded from; `None` fails every fetch with `NotConfigured`
+    pub(crate) store: Option<Arc<dyn SecretStore>>,
+    /// The gate's authorizer, asked for `secrets:read:<name>` on every fetch
+    pub(crate) authorizer: Arc<dyn Authorizer>,
+}
+
+impl Default for SecretsConfig {
+    fn default() -> Self {
+        Self {
+            store: None,
+            authorizer: Arc::new(DenyAll),
+        }
+    }
+}
+
+impl fmt::Debug for SecretsConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecretsConfig")
+            .field("store", &self.store)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A capability-gated secret reader.
+///
+/// `PolicySecrets` is obtained from `Ctx::secrets()` and requires
+/// `SecretsCap`. It is lifetime-bound to the context to prevent misuse.
+///
+/// # Security Properties
+///
+/// - Requires `SecretsCap` to construct (capability-gated)
+/// - Authorizes every fetch by name: the gate's [`Authorizer`] must allow
+///   `secrets:read:<name>` for the principal
+/// - Rejects names that could escape a store's namespace (`../`, `/`)
+/// - Returns values only as `Secret<String>`
+/// - Audits every fetch, successful, denied or failed, with the secret name only
+///
+/// # Examples
+///
+/// ```
+/// # use policy_core::{actions, Authorized, EnvSecretStore, PolicyGate, Principal, RequestMeta, SecretErrorKind};
+/// # std::env::set_var("DOCS_APP_DB_PASSWORD", "hunter2");
+/// # let meta = RequestMeta {
+/// #     request_id: "req-1".to_string(),
+/// #     principal: Some(Principal::new("svc-1", "Billing")),
+/// # };
+/// let ctx = PolicyGate::new(meta)
+///     .with_authorizer(|_: &Principal, action: &str| {
+///         matches!(action, actions::SECRETS_READ | "secrets:read:DB_PASSWORD")
+///     })
+///     .with_secret_store(EnvSecretStore::new().with_prefix("DOCS_APP_"))
+///     .require(Authorized::for_action(actions::SECRETS_READ))
+///     .build()
+///     .unwrap();
+///
+/// let secrets = ctx.secrets().unwrap();
+/// let password = secrets.fetch("DB_PASSWORD").unwrap();
+/// assert_eq!(format!("{:?}", password), "[REDACTED]");
+///
+/// // SecretsCap alone does not unlock other names
+/// let err = secrets.fetch("STRIPE_KEY").unwrap_err();
+/// assert_eq!(err.kind(), SecretErrorKind::Unauthorized);
+/// ```
+pub struct PolicySecrets<'a> {
+    request_id: &'a str,
+    principal: Option<&'a Principal>,
+    config: &'a SecretsConfig,
+}
+
+impl fmt::Debug for PolicySecrets<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PolicySecrets")
+            .field("request_id", &self.request_id)
+            .field("principal", &self.principal.map(|p| &p.id))
+            .field("store", &self.config.store)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> PolicySecrets<'a> {
+    /// Creates a new PolicySecrets for a request using the gate's store and
+    /// authorizer.
+    ///
+    /// This is `pub(crate)` - only `Ctx` can create it.
+    pub(crate) fn new(
+        request_id: &'a str,
+        principal: Option<&'a Principal>,
+        config: &'a SecretsConfig,
+    ) -> Self {
+        Self {
+            request_id,
+            principal,
+            config,
+        }
+    }
+
+    /// Returns the request ID associated with this reader.
+    pub fn request_id(&self) -> &str {
+        self.request_id
+    }
+
+    /// Fetches the secret called `name` from the configured store.
+    ///
+    /// The principal must be authorized for `secrets:read:<name>`. The fetch
+    /// is audited whether or not it succeeds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SecretErrorKind::InvalidName`] if `name` is not a valid
+    /// secret name, [`SecretErrorKind::Unauthorized`] if the gate's
+    /// authorizer denies `secrets:read:<name>` (or there is no principal),
+    /// [`SecretErrorKind::NotConfigured`] if the gate has no store, and
+    /// otherwise whatever error the store reports.
+    pub fn fetch(&self, name: &str) -> Result<Secret<String>, SecretError> {
+        let valid_name = is_valid_name(name);
+        let action = format!("{}:{}", SecretsCap::ACTION, name);
+        let result = if !valid_name {
+            Err(SecretError::with_message(
+                SecretErrorKind::InvalidName,
+                "secret names are 1-128 ASCII letters, digits, '_', '-' or '.'",
+            ))
+        } else if !self
+            .principal
+            .is_some_and(|p| self.config.authorizer.is_authorized(p, &action))
+        {
+            // BREAKING CHANGE WARNING: This check MUST run before the store is
+            // consulted. SecretsCap alone unlocking every name lets any holder
+            // read credentials of unrelated services (CWE-863).
+            Err(SecretError::with_message(
+                SecretErrorKind::Unauthorized,
+                format!("not authorized to read secret '{}'", name),
+            ))
+        } else if let Some(store) = &self.config.store {
+            store.load(&SecretRequest::new(self.request_id, name))
+        } else {
+            Err(SecretError::new(SecretErrorKind::NotConfigured))
+        };
+
+        // BREAKING CHANGE WARNING: The event MUST NOT carry the secret value.
+        // Invalid names are left out as well, since callers may have passed
+        // the value itself by mistake.
+        let outcome = match &result {
+            Ok(_) => AuditOutcome::Success,
+            Err(err) if err.kind() == SecretErrorKind::Unauthorized => AuditOutcome::Denied,
+            Err(_) => AuditOutcome::Error,
+        };
+        let mut event = AuditEvent::new(
+            self.request_id,
+            self.principal.map(|p| p.id.as_str()),
+            AuditEventKind::ResourceAccess,
+            outcome,
+        );
+        event = if valid_name {
+            event.with_action(action).with_resource_id(name)
+        } else {
+            event.with_action(SecretsCap::ACTION)
+        };
+        emit_event(&event);
+
+        result
+    }
+}
+
+/// Returns whether `name` may be passed to a [`SecretStore`].
+///
+/// BREAKING CHANGE WARNING: `DirSecretStore` joins names onto its directory.
+/// Allowing `/`, `\` or a leading `.` permits path traversal (CWE-22).
+pub(crate) fn is_valid_name(name: &str) -> bool {
+    let mut bytes = name.bytes();
+    let Some(first) = bytes.next() else {
+        return false;
+    };
+    name.len() <= MAX_NAME_LEN
+        && (first.is_ascii_alphanumeric() || first == b'_')
+        && bytes.all(|b| b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-' | b'.'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::authorizer::AllowAuthenticated;
+
+    #[derive(Debug)]
+    struct Fixed;
+
+    fn config(store: Option<Arc<dyn SecretStore>>) -> SecretsConfig {
+        SecretsConfig {
+            store,
+            authorizer: Arc::new(AllowAuthenticated),
+        }
+    }
+
+    impl SecretStore for Fixed {
+        fn load(&self, request: &SecretRequest<'_>) -> Result<Secret<String>, SecretError> {
+            match request.name() {
+                "api_key" => Ok(Secret::new("sk-live-123".to_string())),
+                _ => Err(SecretError::new(SecretErrorKind::NotFound)),
+            }
+        }
+    }
+
+    #[test]
+    fn secret_names_are_restricted() {
+        for name in ["DB_PASSWORD", "api-key", "tls.key", "_private", "a"] {
+            assert!(is_valid_name(name), "{name}");
+        }
+        for name in [
+            "",
+            ".env",
+            "..",
+            "../etc/passwd",
+            "a/b",
+            "a\\b",
+            "-flag",
+            "key with space",
+            "k\u{e9}y",
+            "nul\0",
+        ] {
+            assert!(!is_valid_name(name), "{name:?}");
+        }
+        assert!(is_valid_name(&"a".repeat(MAX_NAME_LEN)));
+        assert!(!is_valid_name(&"a".repeat(MAX_NAME_LEN + 1)));
+    }
+
+    #[test]
+    fn policy_secrets_fetches_from_store() {
+        let config = config(Some(Arc::new(Fixed)));
+        let principal = Principal::new("svc-1", "Billing");
+        let secrets = PolicySecrets::new("req-1", Some(&principal), &config);
+
+        let key = secrets.fetch("api_key").unwrap();
+
+        assert_eq!(key.expose_secret(), "sk-live-123");
+        assert_eq!(
+            secrets.fetch("other").unwrap_err().kind(),
+            SecretErrorKind::NotFound
+        );
+    }
+
+    #[test]
+    fn policy_secrets_rejects_invalid_names_before_the_store() {
+        #[derive(Debug)]
+        struct Unreachable;
+
+        impl SecretStore for Unreachable {
+            fn load(&self, _: &SecretRequest<'_>) -> Result<Secret<String>, SecretError> {
+                panic!("invalid names must not reach the store");
+            }
+        }
+
+        let config = config(Some(Arc::new(Unreachable)));
+        let principal = Principal::new("svc-1", "Billing");
+        let secrets = PolicySecrets::new("req-2", Some(&principal), &config);
+
+        let err = secrets.fetch("../../etc/shadow").unwrap_err();
+
+        assert_eq!(err.kind(), SecretErrorKind::InvalidName);
+    }
+
+    #[test]
+    fn policy_secrets_without_store_is_not_configured() {
+        let config = config(None);
+        let principal = Principal::new("svc-1", "Billing");
+        let secrets = PolicySecrets::new("req-3", Some(&principal), &config);
+
+        let err = secrets.fetch("api_key").unwrap_err();
+
+        assert_eq!(err.kind(), SecretErrorKind::NotConfigured);
+    }
+
+    #[test]
+    fn policy_secrets_authorizes_each_name() {
+        #[derive(Debug)]
+        struct OnlyApiKey;
+
+        impl SecretStore for OnlyApiKey {
+            fn load(&self, request: &SecretRequest<'_>) -> Result<Secret<String>, SecretError> {
+                assert_eq!(
+                    request.name(),
+                    "api_key",
+                    "denied names must not reach the store"
+                );
+                Ok(Secret::new("sk-live-123".to_string()))
+            }
+        }
+
+        let config = SecretsConfig {
+            store: Some(Arc::new(OnlyApiKey)),
+            authorizer: Arc::new(|_: &Principal, action: &str| action == "secrets:read:api_key"),
+        };
+        let principal = Principal::new("svc-1", "Billing");
+        let secrets = PolicySecrets::new("req-4", Some(&principal), &config);
+
+        assert!(secrets.fetch("api_key").is_ok());
+        assert_eq!(
+            secrets.fetch("db_password").unwrap_err().kind(),
+            SecretErrorKind::Unauthorized
+        );
+
+        let anonymous = PolicySecrets::new("req-5", None, &config);
+        assert_eq!(
+            anonymous.fetch("api_key").unwrap_err().kind(),
+            SecretErrorKind::Unauthorized
+        );
+    }
+
+    #[test]
+    fn secret_error_display() {
+        assert_eq!(
+            SecretError::new(SecretErrorKind::NotConfigured).to_string(),
+            "secret error (no secret store configured)"
+        );
+        assert_eq!(
+            SecretError::with_message(SecretErrorKind::NotFound, "secret 'x' is not set")
+                .to_string(),
+            "secret error (not found): secret 'x' is not set"
+        );
+    }
+}
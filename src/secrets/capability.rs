@@ -0,0 +1,78 @@
+//! Secrets capability type.
+//!
+//! `SecretsCap` is a zero-sized, unforgeable proof that a context has been
+//! authorized to read secrets from the configured [`SecretStore`]. It cannot
+//! be constructed outside this crate, so every secret fetch goes through
+//! policy validation.
+//!
+//! [`SecretStore`]: crate::SecretStore
+
+use crate::capability::{Capability, Grant};
+use crate::policy::actions;
+
+/// Capability proving authorization to read secrets.
+///
+/// Granted when the [`actions::SECRETS_READ`] action (`"secrets:read"`) is
+/// authorized. Required by [`Ctx::secrets`](crate::Ctx::secrets).
+///
+/// # Example
+///
+/// ```
+/// use policy_core::{actions, AllowAuthenticated, Authorized, PolicyGate, Principal, RequestMeta};
+///
+/// let meta = RequestMeta {
+///     request_id: "req-123".to_string(),
+///     principal: Some(Principal::new("svc-1", "Billing")),
+/// };
+/// let ctx = PolicyGate::new(meta)
+///     .with_authorizer(AllowAuthenticated)
+///     .require(Authorized::for_action(actions::SECRETS_READ))
+///     .build()
+///     .unwrap();
+///
+/// assert!(ctx.secrets_cap().is_some());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SecretsCap {
+    // BREAKING CHANGE WARNING: This field MUST remain private.
+    // Making it public allows external code to forge secrets capabilities via struct literal,
+    // reading credentials without any authorization check.
+    _private: (),
+}
+
+impl SecretsCap {
+    /// Creates a new `SecretsCap`.
+    ///
+    /// This is `pub(crate)` to prevent external forgery. Only the policy
+    /// gate can create capabilities after validating authorization.
+    ///
+    /// BREAKING CHANGE WARNING: Changing visibility to `pub` allows CAPABILITY FORGERY.
+    /// External code could read credentials without policy validation
+    /// (CWE-522: Insufficiently Protected Credentials).
+    pub(crate) fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+impl Capability for SecretsCap {
+    const ACTION: &'static str = actions::SECRETS_READ;
+
+    fn from_grant(_grant: Grant<Self>) -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secrets_cap_is_zero_sized() {
+        assert_eq!(std::mem::size_of::<SecretsCap>(), 0);
+    }
+
+    #[test]
+    fn secrets_cap_is_bound_to_secrets_read() {
+        assert_eq!(SecretsCap::ACTION, "secrets:read");
+    }
+}
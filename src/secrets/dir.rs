@@ -0,0 +1,191 @@
+//! Secret store backed by a directory of files.
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use zeroize::Zeroize;
+
+use super::{SecretError, SecretErrorKind, SecretRequest, SecretStore};
+use crate::Secret;
+
+/// Reads secrets from one file per secret in a directory.
+///
+/// The secret `name` is the contents of `{dir}/{name}`, the layout used by
+/// Docker and Kubernetes secret mounts (for example `/run/secrets`). A single
+/// trailing `\n` or `\r\n` is removed. Files larger than the size limit,
+/// 64 KiB by default, or not valid UTF-8 are reported as
+/// [`SecretErrorKind::Unavailable`].
+///
+/// Secret names cannot contain path separators or start with `.`, so
+/// fetches never leave the directory.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::DirSecretStore;
+///
+/// let store = DirSecretStore::new("/run/secrets").with_max_size(4096);
+/// assert_eq!(store.dir(), std::path::Path::new("/run/secrets"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct DirSecretStore {
+    dir: PathBuf,
+    max_size: usize,
+}
+
+impl DirSecretStore {
+    /// Default limit on the size of a secret file in bytes.
+    pub const DEFAULT_MAX_SIZE: usize = 64 * 1024;
+
+    /// Creates a store reading secrets from files in `dir`.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            max_size: Self::DEFAULT_MAX_SIZE,
+        }
+    }
+
+    /// Sets the largest secret file, in bytes, the store will read.
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Returns the directory secrets are read from.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn unavailable(name: &str, reason: impl std::fmt::Display) -> SecretError {
+        SecretError::with_message(
+            SecretErrorKind::Unavailable,
+            format!("secret file '{}': {}", name, reason),
+        )
+    }
+}
+
+impl SecretStore for DirSecretStore {
+    fn load(&self, request: &SecretRequest<'_>) -> Result<Secret<String>, SecretError> {
+        let name = request.name();
+        let not_found = || {
+            SecretError::with_message(
+                SecretErrorKind::NotFound,
+                format!("secret file '{}' does not exist", name),
+            )
+        };
+
+        let file = match File::open(self.dir.join(name)) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Err(not_found()),
+            Err(e) => return Err(Self::unavailable(name, e)),
+        };
+        let metadata = file.metadata().map_err(|e| Self::unavailable(name, e))?;
+        if !metadata.is_file() {
+            return Err(not_found());
+        }
+
+        // Reserve the whole file up front: growing the buffer would leave
+        // copies of the secret behind in freed memory.
+        let expected = usize::try_from(metadata.len()).unwrap_or(usize::MAX);
+        let mut bytes = Vec::with_capacity(expected.min(self.max_size) + 1);
+        let limit = u64::try_from(self.max_size).unwrap_or(u64::MAX);
+        if let Err(e) = file.take(limit.saturating_add(1)).read_to_end(&mut bytes) {
+            bytes.zeroize();
+            return Err(Self::unavailable(name, e));
+        }
+        if bytes.len() > self.max_size {
+            bytes.zeroize();
+            return Err(Self::unavailable(
+                name,
+                format_args!("larger than {} bytes", self.max_size),
+            ));
+        }
+
+        let mut value = String::from_utf8(bytes).map_err(|e| {
+            e.into_bytes().zeroize();
+            Self::unavailable(name, "not valid UTF-8")
+        })?;
+        if value.ends_with('\n') {
+            value.pop();
+            if value.ends_with('\r') {
+                value.pop();
+            }
+        }
+        Ok(Secret::new(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /// Creates an empty directory unique to one test.
+    fn scratch_dir(test: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("policy-core-{}-{}", test, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn load(store: &DirSecretStore, name: &str) -> Result<Secret<String>, SecretError> {
+        store.load(&SecretRequest::new("req-1", name))
+    }
+
+    #[test]
+    fn dir_store_reads_file_and_strips_trailing_newline() {
+        let dir = scratch_dir("dir-store-read");
+        fs::write(dir.join("db_password"), "hunter2\n").unwrap();
+        fs::write(dir.join("crlf"), "line\r\n").unwrap();
+        fs::write(dir.join("multi"), "a\nb\n\n").unwrap();
+        let store = DirSecretStore::new(&dir);
+
+        assert_eq!(
+            load(&store, "db_password").unwrap().expose_secret(),
+            "hunter2"
+        );
+        assert_eq!(load(&store, "crlf").unwrap().expose_secret(), "line");
+        assert_eq!(load(&store, "multi").unwrap().expose_secret(), "a\nb\n");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn dir_store_reports_missing_files_and_directories_as_not_found() {
+        let dir = scratch_dir("dir-store-missing");
+        fs::create_dir(dir.join("nested")).unwrap();
+        let store = DirSecretStore::new(&dir);
+
+        assert_eq!(
+            load(&store, "absent").unwrap_err().kind(),
+            SecretErrorKind::NotFound
+        );
+        assert_eq!(
+            load(&store, "nested").unwrap_err().kind(),
+            SecretErrorKind::NotFound
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn dir_store_rejects_oversized_and_non_utf8_files() {
+        let dir = scratch_dir("dir-store-limits");
+        fs::write(dir.join("big"), "x".repeat(17)).unwrap();
+        fs::write(dir.join("exact"), "x".repeat(16)).unwrap();
+        fs::write(dir.join("binary"), [0xff, 0xfe]).unwrap();
+        let store = DirSecretStore::new(&dir).with_max_size(16);
+
+        let err = load(&store, "big").unwrap_err();
+        assert_eq!(err.kind(), SecretErrorKind::Unavailable);
+        assert!(!err.to_string().contains("xxx"));
+        assert_eq!(load(&store, "exact").unwrap().expose_secret().len(), 16);
+        assert_eq!(
+            load(&store, "binary").unwrap_err().kind(),
+            SecretErrorKind::Unavailable
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
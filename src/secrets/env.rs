@@ -0,0 +1,98 @@
+//! Secret store backed by environment variables.
+
+use zeroize::Zeroize;
+
+use super::{SecretError, SecretErrorKind, SecretRequest, SecretStore};
+use crate::Secret;
+
+/// Reads secrets from environment variables.
+///
+/// The secret `name` is read from the variable `{prefix}{name}`. A prefix
+/// keeps `PolicySecrets` away from unrelated variables such as `PATH`.
+///
+/// The environment is read on every fetch, so values exported after startup
+/// are picked up.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::EnvSecretStore;
+///
+/// // Secret "DB_PASSWORD" is read from $MYAPP_DB_PASSWORD
+/// let store = EnvSecretStore::new().with_prefix("MYAPP_");
+/// assert_eq!(store.prefix(), "MYAPP_");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EnvSecretStore {
+    prefix: String,
+}
+
+impl EnvSecretStore {
+    /// Creates a store that reads variables named exactly like the secret.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the prefix prepended to secret names.
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Returns the prefix prepended to secret names.
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+}
+
+impl SecretStore for EnvSecretStore {
+    fn load(&self, request: &SecretRequest<'_>) -> Result<Secret<String>, SecretError> {
+        let var = format!("{}{}", self.prefix, request.name());
+        match std::env::var_os(&var) {
+            None => Err(SecretError::with_message(
+                SecretErrorKind::NotFound,
+                format!("environment variable '{}' is not set", var),
+            )),
+            Some(value) => match value.into_string() {
+                Ok(value) => Ok(Secret::new(value)),
+                Err(value) => {
+                    value.into_encoded_bytes().zeroize();
+                    Err(SecretError::with_message(
+                        SecretErrorKind::Unavailable,
+                        format!("environment variable '{}' is not valid UTF-8", var),
+                    ))
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_store_reads_prefixed_variable() {
+        std::env::set_var("POLICY_CORE_TEST_ENV_STORE_TOKEN", "t0ken");
+        let store = EnvSecretStore::new().with_prefix("POLICY_CORE_TEST_ENV_STORE_");
+
+        let secret = store.load(&SecretRequest::new("req-1", "TOKEN")).unwrap();
+
+        assert_eq!(secret.expose_secret(), "t0ken");
+    }
+
+    #[test]
+    fn env_store_reports_missing_variable_without_value() {
+        let store = EnvSecretStore::new().with_prefix("POLICY_CORE_TEST_ENV_STORE_");
+
+        let err = store
+            .load(&SecretRequest::new("req-1", "MISSING"))
+            .unwrap_err();
+
+        assert_eq!(err.kind(), SecretErrorKind::NotFound);
+        assert_eq!(
+            err.message(),
+            Some("environment variable 'POLICY_CORE_TEST_ENV_STORE_MISSING' is not set")
+        );
+    }
+}
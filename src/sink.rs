@@ -1,6 +1,8 @@
 use std::cell::RefCell;
 use std::fmt;
 
+use crate::contexts::{LogLine, VerificationContext};
+use crate::labels::{Join, LabelSet, Labeled, ThirdPartySupplied, Unlabeled, UserSupplied, Within};
 use crate::{Tainted, Verified};
 
 /// Error returned when sinking a value fails.
@@ -85,6 +87,72 @@ impl fmt::Display for SinkErrorKind {
     }
 }
 
+mod sealed {
+    // BREAKING CHANGE WARNING: This module MUST remain private.
+    // SinkInput decides which labels a sink sees. A user-implementable input
+    // could report no labels for a labeled value, and a constructible Token
+    // would let callers borrow the Verified value out of a Labeled one.
+    pub trait Sealed {}
+
+    pub struct Token(pub(super) ());
+}
+
+/// Proof that [`Sink::sink`] checked a value's labels before calling
+/// [`Sink::write`].
+///
+/// It cannot be constructed outside policy-core, so `write` is only
+/// reachable through `sink`.
+#[derive(Debug)]
+pub struct LabelsChecked {
+    // BREAKING CHANGE WARNING: This field MUST remain private.
+    // A constructible LabelsChecked lets callers invoke Sink::write directly
+    // and skip the label check.
+    _private: (),
+}
+
+impl LabelsChecked {
+    fn new() -> Self {
+        Self { _private: () }
+    }
+}
+
+/// A value that can be passed to [`Sink::sink`].
+///
+/// Implemented for `Verified<T, C>`, which carries no labels, and for
+/// [`Labeled<Verified<T, C>, L>`](Labeled), which carries `L`. This trait is
+/// sealed and cannot be implemented outside policy-core.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` cannot be written to a sink for the `{C}` context",
+    note = "sinks accept `Verified<T, C>` or `Labeled<Verified<T, C>, L>` values verified for their own context"
+)]
+pub trait SinkInput<T, C: VerificationContext>: sealed::Sealed {
+    /// The labels the value carries.
+    type Labels: LabelSet;
+
+    #[doc(hidden)]
+    fn verified(&self, token: sealed::Token) -> &Verified<T, C>;
+}
+
+impl<T, C: VerificationContext> sealed::Sealed for Verified<T, C> {}
+
+impl<T, C: VerificationContext> SinkInput<T, C> for Verified<T, C> {
+    type Labels = Unlabeled;
+
+    fn verified(&self, _token: sealed::Token) -> &Verified<T, C> {
+        self
+    }
+}
+
+impl<T, C: VerificationContext, L: LabelSet> sealed::Sealed for Labeled<Verified<T, C>, L> {}
+
+impl<T, C: VerificationContext, L: LabelSet> SinkInput<T, C> for Labeled<Verified<T, C>, L> {
+    type Labels = L;
+
+    fn verified(&self, _token: sealed::Token) -> &Verified<T, C> {
+        self.value()
+    }
+}
+
 /// Trait for sinks that accept only verified values.
 ///
 /// `Sink<T>` defines the interface for writing verified data to side-effecting
@@ -92,12 +160,21 @@ impl fmt::Display for SinkErrorKind {
 /// that only `Verified<T>` values can be written, preventing tainted data from
 /// flowing into sinks.
 ///
+/// Implementors provide [`write`](Self::write) and declare their
+/// [`Context`](Self::Context) and the information-flow [`Labels`](Self::Labels)
+/// they tolerate. Callers use [`sink`](Self::sink), which checks both.
+///
 /// # Compile-Time Safety
 ///
-/// The `sink` method accepts only `&Verified<T>`, which means:
+/// The `sink` method accepts only `Verified<T>` values, alone or
+/// [`Labeled`], which means:
 /// - Raw values of type `T` cannot be sunk directly (compile error)
 /// - `Tainted<T>` values cannot be sunk directly (compile error)
 /// - Only values that have passed through a `Sanitizer` can be sunk
+/// - Only values verified for the sink's [`Context`](Self::Context) can be
+///   sunk, so a value checked for one output context cannot reach another
+/// - Only values whose labels are all in [`Labels`](Self::Labels) can be
+///   sunk; see the [`labels`](crate::labels) module
 ///
 /// # Examples
 ///
@@ -119,29 +196,96 @@ impl fmt::Display for SinkErrorKind {
 /// // sink.sink(&tainted); // Type mismatch!
 /// ```
 // BREAKING CHANGE WARNING: Do NOT modify the Sink trait signature.
-// The sink() method MUST accept &Verified<T>, not &T or &Tainted<T>.
-// Changing this defeats the entire validation bottleneck and allows unvalidated data into sinks.
+// sink() MUST accept only SinkInput values of Self::Context, never &T, &Tainted<T>
+// or a Verified value of any context, and MUST require V::Labels: Within<Self::Labels>.
+// write() MUST take a LabelsChecked so that it is only reachable through sink().
+// Changing this defeats the entire validation bottleneck and allows unvalidated or
+// untolerated data into sinks.
 pub trait Sink<T> {
-    /// Writes a verified value to the sink.
+    /// The output context this sink accepts values for.
+    type Context: VerificationContext;
+
+    /// Every information-flow label this sink tolerates.
+    ///
+    /// Use [`Unlabeled`] for a sink that accepts no labeled data. A sink that
+    /// tolerates `UserSupplied` accepts values labeled `UserSupplied` or
+    /// nothing, but not values that also carry `PersonalData`.
+    type Labels: LabelSet;
+
+    /// Writes a value whose context and labels [`sink`](Self::sink) checked.
+    ///
+    /// `checked` cannot be constructed outside policy-core, so this method
+    /// can only be called through `sink`.
     ///
     /// # Errors
     ///
     /// Returns `SinkError` if the sink operation fails (e.g., I/O error, capacity exceeded).
+    fn write(
+        &self,
+        value: &Verified<T, Self::Context>,
+        checked: LabelsChecked,
+    ) -> Result<(), SinkError>;
+
+    /// Writes a verified value to the sink.
+    ///
+    /// `value` is a `Verified<T, Self::Context>`, or one wrapped in
+    /// [`Labeled`] with labels this sink tolerates; anything else fails to
+    /// compile.
+    ///
+    /// # Errors
+    ///
+    /// Returns `SinkError` if [`write`](Self::write) fails.
     ///
     /// # Examples
     ///
-    /// ```ignore
-    /// use policy_core::{Verified, Sink, VecSink};
+    /// ```
+    /// use policy_core::web::{ExtractTaintedInputs, RequestAdapter};
+    /// use policy_core::{Sink, StringSanitizer, VecSink};
+    ///
+    /// let mut request = RequestAdapter::new("req-1".to_string());
+    /// request.add_query_param("comment".to_string(), "nice".to_string());
+    /// let inputs = request.extract_tainted_inputs();
     ///
-    /// # let sink = VecSink::new();
-    /// # let verified = Verified::new_unchecked("data".to_string());
-    /// // Only verified values can be sunk
-    /// sink.sink(&verified).expect("should succeed");
+    /// let sink = VecSink::new(); // tolerates user-supplied and third-party data
+    /// let comment = inputs
+    ///     .get_query("comment")
+    ///     .unwrap()
+    ///     .sanitize(&StringSanitizer::new(64).unwrap())
+    ///     .unwrap();
+    ///
+    /// sink.sink(&comment).unwrap();
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use policy_core::labels::PersonalData;
+    /// use policy_core::web::{ExtractTaintedInputs, RequestAdapter};
+    /// use policy_core::{Sink, StringSanitizer, VecSink};
+    ///
+    /// let mut request = RequestAdapter::new("req-1".to_string());
+    /// request.add_query_param("email".to_string(), "ann@example.com".to_string());
+    /// let inputs = request.extract_tainted_inputs();
+    ///
+    /// let sink = VecSink::new();
+    /// let email = inputs
+    ///     .get_query("email")
+    ///     .unwrap()
+    ///     .sanitize(&StringSanitizer::new(64).unwrap())
+    ///     .unwrap()
+    ///     .add_labels::<PersonalData>();
+    ///
+    /// sink.sink(&email); // Error: `Pii` is not tolerated
     /// ```
-    /// BREAKING CHANGE WARNING: This signature MUST accept `&Verified<T>`, not `&T` or `&Tainted<T>`.
-    /// Changing it to accept `&T` or `&Tainted<T>` bypasses validation and enables injection attacks
-    /// (CWE-74, CWE-89, CWE-117, CWE-79).
-    fn sink(&self, value: &Verified<T>) -> Result<(), SinkError>;
+    /// BREAKING CHANGE WARNING: This signature MUST accept only `SinkInput` values, not `&T`
+    /// or `&Tainted<T>`, and MUST keep the `Within` bound. Changing it bypasses validation
+    /// and enables injection attacks (CWE-74, CWE-89, CWE-117, CWE-79) or lets labeled data
+    /// reach sinks that do not tolerate it (CWE-200).
+    fn sink<V>(&self, value: &V) -> Result<(), SinkError>
+    where
+        V: SinkInput<T, Self::Context>,
+        V::Labels: Within<Self::Labels>,
+    {
+        self.write(value.verified(sealed::Token(())), LabelsChecked::new())
+    }
 
     /// Attempts to sink an unverified value (always fails).
     ///
@@ -176,11 +320,12 @@ pub trait Sink<T> {
 /// A demonstration sink that collects verified strings into an in-memory vector.
 ///
 /// `VecSink` provides a simple, observable sink for testing and demonstration.
-/// It accepts only `Verified<String>` values and stores them in an internal vector.
+/// It accepts only `Verified<String, LogLine>` values, such as those produced by
+/// [`StringSanitizer`](crate::StringSanitizer), and stores them in an internal vector.
 ///
 /// # Security Properties
 ///
-/// - Accepts only `Verified<String>` on the main path (compile-time enforcement)
+/// - Accepts only `Verified<String, LogLine>` on the main path (compile-time enforcement)
 /// - Rejects `Tainted<String>` at runtime if `sink_untrusted` is called
 /// - Does not perform sanitization itself (delegates to `Sanitizer` implementations)
 /// - Uses interior mutability to allow shared access while collecting values
@@ -202,6 +347,9 @@ pub trait Sink<T> {
 /// let values = sink.into_vec();
 /// assert_eq!(values, vec!["hello"]);
 /// ```
+///
+/// For [labels](crate::labels), `VecSink` behaves like a log: it tolerates
+/// user-supplied and third-party data but not PII or financial data.
 #[derive(Debug)]
 pub struct VecSink {
     values: RefCell<Vec<String>>,
@@ -351,7 +499,7 @@ impl VecSink {
     /// use policy_core::VecSink;
     ///
     /// let sink = VecSink::new();
-    /// let values = sink.to_vec();
+    /// let values = sink.into_vec();
     /// assert!(values.is_empty());
     /// ```
     #[deprecated(
@@ -370,7 +518,10 @@ impl Default for VecSink {
 }
 
 impl Sink<String> for VecSink {
-    fn sink(&self, value: &Verified<String>) -> Result<(), SinkError> {
+    type Context = LogLine;
+    type Labels = Join<UserSupplied, ThirdPartySupplied>;
+
+    fn write(&self, value: &Verified<String, LogLine>, _: LabelsChecked) -> Result<(), SinkError> {
         // Extract the verified string and push it to the vector
         let verified_str = value.as_ref();
         self.values.borrow_mut().push(verified_str.clone());
@@ -421,7 +572,7 @@ mod tests {
 
         assert!(result.is_ok());
         assert_eq!(sink.len(), 1);
-        assert_eq!(sink.to_vec(), vec!["test"]);
+        assert_eq!(sink.into_vec(), vec!["test"]);
     }
 
     #[test]
@@ -469,7 +620,7 @@ mod tests {
         sink.sink(&verified).expect("should succeed");
 
         // Verify the trimmed value was stored
-        assert_eq!(sink.to_vec(), vec!["hello world"]);
+        assert_eq!(sink.into_vec(), vec!["hello world"]);
     }
 
     #[test]
@@ -521,7 +672,7 @@ mod tests {
         }
 
         // All values should be trimmed
-        assert_eq!(sink.to_vec(), vec!["one", "two", "three"]);
+        assert_eq!(sink.into_vec(), vec!["one", "two", "three"]);
     }
 
     #[test]
@@ -542,6 +693,6 @@ mod tests {
 
         // Should not have modified the sink
         assert_eq!(sink.len(), 1);
-        assert_eq!(sink.to_vec(), vec!["safe"]);
+        assert_eq!(sink.into_vec(), vec!["safe"]);
     }
 }
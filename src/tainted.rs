@@ -1,5 +1,7 @@
 use std::fmt;
 
+use crate::{Provenance, SanitizationError};
+
 /// A wrapper for untrusted data that must be explicitly sanitized before use.
 ///
 /// `Tainted<T>` marks data from untrusted sources (user input, external APIs, etc.)
@@ -25,6 +27,25 @@ use std::fmt;
 /// // But you CANNOT use the value directly:
 /// // let query = format!("SELECT * FROM users WHERE name = '{}'", user_input); // Won't compile!
 /// ```
+///
+/// # Provenance
+///
+/// A tainted value can carry a [`Provenance`] label naming the input channel
+/// it came from. Values from [`TaintedInputs`](crate::web::TaintedInputs) and
+/// [`HttpResponse`](crate::HttpResponse) are labeled automatically. The label
+/// survives taint-preserving transformations and is attached to the
+/// [`SanitizationError`] if the value is rejected:
+///
+/// ```
+/// use policy_core::{Provenance, Sanitizer, StringSanitizer, Tainted};
+///
+/// let forwarded = Tainted::new("10.0.0.1\r\nX-Admin: 1".to_string())
+///     .with_provenance(Provenance::header("x-forwarded-for"));
+///
+/// let err = StringSanitizer::new(64).unwrap().sanitize(forwarded).unwrap_err();
+/// assert_eq!(err.provenance(), Some(&Provenance::header("x-forwarded-for")));
+/// assert!(err.to_string().ends_with("(from header 'x-forwarded-for')"));
+/// ```
 // BREAKING CHANGE WARNING: Do NOT remove Clone - tainted values need to be duplicated for validation flow.
 #[derive(Clone)]
 pub struct Tainted<T> {
@@ -32,6 +53,7 @@ pub struct Tainted<T> {
     // Making it public bypasses taint tracking entirely (CWE-20: Improper Input Validation).
     // External code must go through Sanitizer trait to access the value.
     inner: T,
+    provenance: Option<Provenance>,
 }
 
 impl<T> Tainted<T> {
@@ -39,7 +61,31 @@ impl<T> Tainted<T> {
     ///
     /// Use this for any data from external sources that has not been validated.
     pub fn new(value: T) -> Self {
-        Self { inner: value }
+        Self {
+            inner: value,
+            provenance: None,
+        }
+    }
+
+    /// Labels the value with the input channel it came from.
+    pub fn with_provenance(mut self, provenance: Provenance) -> Self {
+        self.provenance = Some(provenance);
+        self
+    }
+
+    /// Returns where the value came from, if known.
+    ///
+    /// Provenance is metadata about the channel, never the value itself.
+    pub fn provenance(&self) -> Option<&Provenance> {
+        self.provenance.as_ref()
+    }
+
+    /// Wraps a value with the provenance of the value it was derived from.
+    pub(crate) fn from_parts(value: T, provenance: Option<Provenance>) -> Self {
+        Self {
+            inner: value,
+            provenance,
+        }
     }
 
     /// Extracts the inner value for sanitization.
@@ -58,6 +104,212 @@ impl<T> Tainted<T> {
     pub(crate) fn into_inner(self) -> T {
         self.inner
     }
+
+    /// Borrows the inner value for sanitization.
+    ///
+    /// The same restrictions as [`into_inner`](Self::into_inner) apply.
+    ///
+    /// BREAKING CHANGE WARNING: Changing visibility to `pub` creates a CRITICAL SECURITY BYPASS.
+    pub(crate) fn as_inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Runs a sanitizer body on the inner value, labeling any error it
+    /// returns with this value's provenance.
+    ///
+    /// Built-in sanitizers use this instead of [`into_inner`](Self::into_inner)
+    /// so rejections say which input channel they came from. An error that
+    /// already carries a more specific provenance keeps it.
+    pub(crate) fn sanitize_inner<U>(
+        self,
+        check: impl FnOnce(T) -> Result<U, SanitizationError>,
+    ) -> Result<U, SanitizationError> {
+        let provenance = self.provenance.clone();
+        check(self.into_inner()).map_err(|err| err.or_provenance(provenance))
+    }
+}
+
+/// Taint-preserving transformations.
+///
+/// These reshape or convert an untrusted value without sanitizing it: every
+/// result is still `Tainted` and still has to pass a [`Sanitizer`]. Results
+/// keep the [`Provenance`] of the value they were derived from.
+///
+/// Transformations take function pointers (`fn(T) -> U`), so closures that
+/// capture local variables are rejected, which rules out the easy mistake of
+/// copying the raw value into a variable outside the wrapper:
+///
+/// ```compile_fail
+/// use policy_core::Tainted;
+///
+/// let mut leaked = String::new();
+/// let tainted = Tainted::new("untrusted".to_string());
+///
+/// // Error: closures can only be coerced to `fn` types if they do not capture
+/// let _ = tainted.map(|s| {
+///     leaked = s.clone();
+///     s
+/// });
+/// ```
+///
+/// This is not a confinement boundary. A `fn` still runs arbitrary code with
+/// the raw value and can write it to a static, a thread-local, a file or
+/// stdout. Treat the function as code that handles untrusted data, and keep
+/// it to reshaping the value.
+///
+/// [`Sanitizer`]: crate::Sanitizer
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{IntegerSanitizer, Sanitizer, Tainted};
+///
+/// let header = Tainted::new("Bearer abc123".to_string());
+/// let (scheme, token) = header.split_once(" ").unwrap();
+/// let scheme = scheme.map(|s| s.to_ascii_lowercase());
+///
+/// let ids = Tainted::new("3,5,8".to_string());
+/// let ids = ids.split(",");
+/// assert_eq!(ids.len(), 3);
+///
+/// // Each piece is still tainted and must be sanitized before use
+/// let parser = IntegerSanitizer::new(1u32, 10).unwrap();
+/// let ids: Vec<u32> = ids
+///     .into_iter()
+///     .map(|id| parser.sanitize(id).map(|v| v.into_inner()))
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(ids, [3, 5, 8]);
+/// # let _ = (scheme, token);
+/// ```
+impl<T> Tainted<T> {
+    /// Applies `f` to the untrusted value, keeping the result tainted.
+    pub fn map<U>(self, f: fn(T) -> U) -> Tainted<U> {
+        Tainted::from_parts(f(self.inner), self.provenance)
+    }
+
+    /// Applies `f`, which itself returns a tainted value, and flattens.
+    ///
+    /// The result keeps its own provenance if `f` set one, and this value's
+    /// otherwise.
+    pub fn and_then<U>(self, f: fn(T) -> Tainted<U>) -> Tainted<U> {
+        let result = f(self.inner);
+        let provenance = result.provenance.or(self.provenance);
+        Tainted::from_parts(result.inner, provenance)
+    }
+
+    /// Combines two untrusted values into one tainted pair.
+    ///
+    /// The pair keeps a provenance only if both values share it.
+    pub fn zip<U>(self, other: Tainted<U>) -> Tainted<(T, U)> {
+        let provenance = common(self.provenance, other.provenance);
+        Tainted::from_parts((self.inner, other.inner), provenance)
+    }
+}
+
+/// Returns the provenance shared by two values, if they have the same one.
+fn common(a: Option<Provenance>, b: Option<Provenance>) -> Option<Provenance> {
+    if a == b {
+        a
+    } else {
+        None
+    }
+}
+
+impl<A, B> Tainted<(A, B)> {
+    /// Splits a tainted pair into two tainted values.
+    pub fn unzip(self) -> (Tainted<A>, Tainted<B>) {
+        let (a, b) = self.inner;
+        (
+            Tainted::from_parts(a, self.provenance.clone()),
+            Tainted::from_parts(b, self.provenance),
+        )
+    }
+}
+
+impl<T> Tainted<Option<T>> {
+    /// Turns a tainted `Option` into an optional tainted value.
+    ///
+    /// Whether a value is present is revealed; the value itself stays
+    /// tainted.
+    pub fn transpose(self) -> Option<Tainted<T>> {
+        let provenance = self.provenance;
+        self.inner
+            .map(|value| Tainted::from_parts(value, provenance))
+    }
+}
+
+impl<T, E> Tainted<Result<T, E>> {
+    /// Turns a tainted `Result` into a result of tainted values.
+    ///
+    /// The error stays tainted as well, since errors such as `serde_json`'s
+    /// often quote the input they failed on.
+    ///
+    /// ```
+    /// use policy_core::Tainted;
+    ///
+    /// let port = Tainted::new("8080".to_string()).map(|s| s.parse::<u16>());
+    /// assert!(port.transpose().is_ok());
+    ///
+    /// let port = Tainted::new("80; rm -rf /".to_string()).map(|s| s.parse::<u16>());
+    /// assert!(port.transpose().is_err());
+    /// ```
+    pub fn transpose(self) -> Result<Tainted<T>, Tainted<E>> {
+        match self.inner {
+            Ok(value) => Ok(Tainted::from_parts(value, self.provenance)),
+            Err(err) => Err(Tainted::from_parts(err, self.provenance)),
+        }
+    }
+}
+
+impl<T> Tainted<Vec<T>> {
+    /// Splits a tainted collection into individually tainted items.
+    pub fn into_items(self) -> Vec<Tainted<T>> {
+        let provenance = self.provenance;
+        self.inner
+            .into_iter()
+            .map(|item| Tainted::from_parts(item, provenance.clone()))
+            .collect()
+    }
+}
+
+impl<T> FromIterator<Tainted<T>> for Tainted<Vec<T>> {
+    /// Collects tainted items into one tainted collection.
+    ///
+    /// The collection keeps a provenance only if every item shares it.
+    fn from_iter<I: IntoIterator<Item = Tainted<T>>>(iter: I) -> Self {
+        let mut items = Vec::new();
+        let mut provenance = None;
+        for (index, item) in iter.into_iter().enumerate() {
+            provenance = match index {
+                0 => item.provenance,
+                _ => common(provenance, item.provenance),
+            };
+            items.push(item.inner);
+        }
+        Tainted::from_parts(items, provenance)
+    }
+}
+
+impl Tainted<String> {
+    /// Splits the string on every occurrence of `delimiter`.
+    pub fn split(self, delimiter: &str) -> Vec<Tainted<String>> {
+        self.inner
+            .split(delimiter)
+            .map(|part| Tainted::from_parts(part.to_string(), self.provenance.clone()))
+            .collect()
+    }
+
+    /// Splits the string on the first occurrence of `delimiter`.
+    ///
+    /// Returns `None` if the delimiter does not occur.
+    pub fn split_once(self, delimiter: &str) -> Option<(Tainted<String>, Tainted<String>)> {
+        let (a, b) = self.inner.split_once(delimiter)?;
+        Some((
+            Tainted::from_parts(a.to_string(), self.provenance.clone()),
+            Tainted::from_parts(b.to_string(), self.provenance),
+        ))
+    }
 }
 
 // BREAKING CHANGE WARNING: Do NOT add Deref, AsRef, Borrow, From<T>, Into<T>, or any other
@@ -69,9 +321,12 @@ impl<T> fmt::Debug for Tainted<T> {
         // SECURITY: Do not expose the raw tainted value in debug output.
         // This prevents PII leakage and log injection if debug output is captured in logs.
         // See issue #82: Tainted::Debug prints raw untrusted input
-        f.debug_struct("Tainted")
-            .field("inner", &"<redacted>")
-            .finish()
+        let mut debug = f.debug_struct("Tainted");
+        debug.field("inner", &"<redacted>");
+        if let Some(provenance) = &self.provenance {
+            debug.field("provenance", provenance);
+        }
+        debug.finish()
     }
 }
 
@@ -118,6 +373,113 @@ mod tests {
         let _ = tainted_str;
     }
 
+    #[test]
+    fn map_and_and_then_stay_tainted() {
+        fn parse(s: String) -> Tainted<Result<u8, std::num::ParseIntError>> {
+            Tainted::new(s.trim().parse())
+        }
+
+        let lower: Tainted<String> = Tainted::new("ADMIN".to_string()).map(|s| s.to_lowercase());
+        assert_eq!(lower.into_inner(), "admin");
+
+        let parsed = Tainted::new(" 42 ".to_string()).and_then(parse);
+        assert_eq!(parsed.transpose().unwrap().into_inner(), 42);
+        let parsed = Tainted::new("4x".to_string()).and_then(parse);
+        assert!(parsed.transpose().is_err());
+    }
+
+    #[test]
+    fn zip_and_unzip_round_trip() {
+        let pair = Tainted::new("user").zip(Tainted::new(7));
+        let (name, id) = pair.unzip();
+        assert_eq!((name.into_inner(), id.into_inner()), ("user", 7));
+    }
+
+    #[test]
+    fn transpose_option_reveals_only_presence() {
+        assert!(Tainted::new(None::<String>).transpose().is_none());
+        let some = Tainted::new(Some("x".to_string())).transpose().unwrap();
+        assert!(!format!("{:?}", some).contains('x'));
+    }
+
+    #[test]
+    fn split_produces_tainted_parts() {
+        let parts = Tainted::new("a,b,,c".to_string()).split(",");
+        let parts: Vec<String> = parts.into_iter().map(Tainted::into_inner).collect();
+        assert_eq!(parts, ["a", "b", "", "c"]);
+
+        let (key, value) = Tainted::new("k=v=w".to_string()).split_once("=").unwrap();
+        assert_eq!(
+            (key.into_inner(), value.into_inner()),
+            ("k".into(), "v=w".into())
+        );
+        assert!(Tainted::new("kv".to_string()).split_once("=").is_none());
+    }
+
+    #[test]
+    fn items_collect_back_into_a_tainted_vec() {
+        let items = Tainted::new(vec![1, 2, 3]).into_items();
+        assert_eq!(items.len(), 3);
+
+        let doubled: Tainted<Vec<i32>> = items.into_iter().map(|i| i.map(|n| n * 2)).collect();
+        assert_eq!(doubled.into_inner(), [2, 4, 6]);
+    }
+
+    #[test]
+    fn provenance_survives_transformations() {
+        let header = Provenance::header("authorization");
+        let tainted = Tainted::new("Bearer abc".to_string()).with_provenance(header.clone());
+
+        let (scheme, token) = tainted.clone().split_once(" ").unwrap();
+        assert_eq!(scheme.provenance(), Some(&header));
+        assert_eq!(token.map(|t| t.len()).provenance(), Some(&header));
+
+        let parts = tainted.split(" ");
+        assert!(parts.iter().all(|part| part.provenance() == Some(&header)));
+        let joined: Tainted<Vec<String>> = parts.into_iter().collect();
+        assert_eq!(joined.provenance(), Some(&header));
+    }
+
+    #[test]
+    fn combining_channels_drops_provenance() {
+        let a = Tainted::new(1).with_provenance(Provenance::query("a"));
+        let b = Tainted::new(2).with_provenance(Provenance::query("b"));
+        assert!(a.clone().zip(b.clone()).provenance().is_none());
+        assert!(Tainted::<Vec<i32>>::from_iter([a.clone(), b])
+            .provenance()
+            .is_none());
+        assert_eq!(a.clone().zip(a).provenance(), Some(&Provenance::query("a")));
+    }
+
+    #[test]
+    fn debug_shows_provenance_but_not_value() {
+        let tainted =
+            Tainted::new("secret-ish".to_string()).with_provenance(Provenance::path("id"));
+        let debug = format!("{:?}", tainted);
+        assert!(debug.contains("Path"));
+        assert!(debug.contains("\"id\""));
+        assert!(!debug.contains("secret-ish"));
+    }
+
+    #[test]
+    fn sanitizer_errors_are_labeled_with_provenance() {
+        use crate::{sanitizer::StringSanitizer, Sanitizer};
+
+        let input = Tainted::new("\0".to_string()).with_provenance(Provenance::query("name"));
+        let err = StringSanitizer::new(8)
+            .unwrap()
+            .sanitize(input)
+            .unwrap_err();
+        assert_eq!(err.provenance(), Some(&Provenance::query("name")));
+        assert!(err.to_string().ends_with("(from query 'name')"));
+
+        let unlabeled = StringSanitizer::new(8)
+            .unwrap()
+            .sanitize(Tainted::new("\0".to_string()))
+            .unwrap_err();
+        assert!(unlabeled.provenance().is_none());
+    }
+
     mod proptests {
         use super::*;
         use crate::{sanitizer::StringSanitizer, test_utils::arb_valid_string, Sanitizer};
@@ -141,6 +503,18 @@ mod tests {
                 prop_assert_eq!(verified1.as_ref(), verified2.as_ref());
                 prop_assert_eq!(verified1.as_ref(), &input);
             }
+
+            /// Property: Splitting loses nothing; the parts rejoin to the input
+            #[test]
+            fn proptest_split_rejoins_to_input(input in "[a-c,]{0,30}") {
+                let parts: Vec<String> = Tainted::new(input.clone())
+                    .split(",")
+                    .into_iter()
+                    .map(Tainted::into_inner)
+                    .collect();
+
+                prop_assert_eq!(parts.join(","), input);
+            }
         }
     }
 }
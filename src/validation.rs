@@ -0,0 +1,862 @@
+//! Multi-field validation that reports every failing field at once.
+
+use std::fmt;
+
+use crate::contexts::VerificationContext;
+use crate::error::{Violation, ViolationKind};
+use crate::labels::{Join, LabelSet, Labeled, Unlabeled, UserSupplied};
+use crate::web::TaintedInputs;
+use crate::{Provenance, SanitizationError, SanitizationErrorKind, Sanitizer, Tainted, Verified};
+
+/// A validation session over many named tainted inputs.
+///
+/// [`Sanitizer::sanitize`] stops at the first error. A `Validation` instead
+/// sanitizes every field, records each failure with its field name, and
+/// only hands out the verified values once all of them passed:
+///
+/// 1. Call [`field`](Self::field), [`required`](Self::required) or
+///    [`optional`](Self::optional) once per input. Each returns `None` if the
+///    field failed. Labeled inputs, such as the values read from
+///    [`TaintedInputs`], come back as labeled verified values.
+/// 2. Pass the returned values, as a tuple, to [`finish`](Self::finish). It
+///    returns the unwrapped values, or a [`ValidationErrors`] report listing
+///    every failed field.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::web::{ExtractTaintedInputs, RequestAdapter};
+/// use policy_core::{
+///     EmailSanitizer, IntegerSanitizer, SanitizationErrorKind, StringSanitizer, Validation,
+/// };
+///
+/// let mut request = RequestAdapter::new("req-1".to_string());
+/// request.add_query_param("name".to_string(), "Alice".to_string());
+/// request.add_query_param("email".to_string(), "not-an-email".to_string());
+/// request.add_query_param("age".to_string(), "250".to_string());
+/// let inputs = request.extract_tainted_inputs();
+///
+/// let names = StringSanitizer::new(64).unwrap();
+/// let emails = EmailSanitizer::new();
+/// let ages = IntegerSanitizer::new(13u8, 120).unwrap();
+///
+/// let mut validation = Validation::new();
+/// let name = validation.required("name", inputs.get_query("name"), &names);
+/// let email = validation.required("email", inputs.get_query("email"), &emails);
+/// let age = validation.optional("age", inputs.get_query("age"), &ages);
+///
+/// let report = validation.finish((name, email, age)).unwrap_err();
+/// assert_eq!(report.fields().collect::<Vec<_>>(), ["email", "age"]);
+/// assert_eq!(report.get("age").unwrap().kind(), SanitizationErrorKind::OutOfRange);
+/// ```
+#[derive(Debug, Default)]
+pub struct Validation {
+    errors: Vec<FieldError>,
+}
+
+impl Validation {
+    /// Starts an empty validation session.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sanitizes one field, recording the error under `name` if it fails.
+    ///
+    /// `input` is a [`Tainted`] value or a [`Labeled`] one; the verified
+    /// value keeps the input's labels. The recorded error carries the
+    /// input's [`Provenance`] even if `sanitizer` did not attach it.
+    pub fn field<T, U, S, I>(
+        &mut self,
+        name: &str,
+        input: I,
+        sanitizer: &S,
+    ) -> Option<I::Output<U, S::Context>>
+    where
+        S: Sanitizer<T, U>,
+        I: FieldInput<T>,
+    {
+        let provenance = input.provenance().cloned();
+        match input.sanitize_with(sanitizer) {
+            Ok(verified) => Some(verified),
+            Err(err) => {
+                self.errors
+                    .push(FieldError::new(name, err.or_provenance(provenance)));
+                None
+            }
+        }
+    }
+
+    /// Sanitizes a field that must be present.
+    ///
+    /// A missing input is recorded as [`SanitizationErrorKind::Empty`].
+    pub fn required<T, U, S, I>(
+        &mut self,
+        name: &str,
+        input: Option<I>,
+        sanitizer: &S,
+    ) -> Option<I::Output<U, S::Context>>
+    where
+        S: Sanitizer<T, U>,
+        I: FieldInput<T>,
+    {
+        match input {
+            Some(input) => self.field(name, input, sanitizer),
+            None => {
+                self.errors.push(FieldError::new(
+                    name,
+                    SanitizationError::new(SanitizationErrorKind::Empty, "field is required"),
+                ));
+                None
+            }
+        }
+    }
+
+    /// Sanitizes a field that may be absent.
+    ///
+    /// A missing input is not an error and completes as `None`; a present
+    /// input must pass `sanitizer`.
+    pub fn optional<T, U, S, I>(
+        &mut self,
+        name: &str,
+        input: Option<I>,
+        sanitizer: &S,
+    ) -> Option<Option<I::Output<U, S::Context>>>
+    where
+        S: Sanitizer<T, U>,
+        I: FieldInput<T>,
+    {
+        match input {
+            Some(input) => self.field(name, input, sanitizer).map(Some),
+            None => Some(None),
+        }
+    }
+
+    /// Sanitizes one field of a tainted struct, selected by `project`.
+    ///
+    /// `project` must only select the field; the selected value is cloned and
+    /// handed to the sanitizer as tainted input. Like [`Tainted::map`], it is
+    /// a `fn` pointer, so closures that capture local variables are rejected:
+    ///
+    /// ```compile_fail
+    /// use policy_core::{StringSanitizer, Tainted, Validation};
+    ///
+    /// let mut leaked = String::new();
+    /// let form = Tainted::new(("untrusted".to_string(), 0u8));
+    /// let names = StringSanitizer::new(16).unwrap();
+    /// let mut validation = Validation::new();
+    ///
+    /// // Error: closures can only be coerced to `fn` types if they do not capture
+    /// let _ = validation.field_of(
+    ///     "name",
+    ///     &form,
+    ///     |form| {
+    ///         leaked = form.0.clone();
+    ///         &form.0
+    ///     },
+    ///     &names,
+    /// );
+    /// ```
+    ///
+    /// This only stops captures. `project` runs with the raw struct and can
+    /// still write it to a static, a file or stdout, so it must be a plain
+    /// field access.
+    pub fn field_of<S, F, U, Z>(
+        &mut self,
+        name: &str,
+        source: &Tainted<S>,
+        project: fn(&S) -> &F,
+        sanitizer: &Z,
+    ) -> Option<Verified<U, Z::Context>>
+    where
+        F: Clone,
+        Z: Sanitizer<F, U>,
+    {
+        let raw = project(source.as_inner()).clone();
+        let provenance = source.provenance().cloned();
+        self.field(name, Tainted::from_parts(raw, provenance), sanitizer)
+    }
+
+    /// Sanitizes an optional field of a tainted struct, selected by `project`.
+    ///
+    /// Like [`optional`](Self::optional), an absent value completes as `None`.
+    /// `project` is a `fn` pointer, as in [`field_of`](Self::field_of).
+    pub fn optional_field_of<S, F, U, Z>(
+        &mut self,
+        name: &str,
+        source: &Tainted<S>,
+        project: fn(&S) -> Option<&F>,
+        sanitizer: &Z,
+    ) -> Option<Option<Verified<U, Z::Context>>>
+    where
+        F: Clone,
+        Z: Sanitizer<F, U>,
+    {
+        let raw = project(source.as_inner()).cloned();
+        let provenance = source.provenance().cloned();
+        let input = raw.map(|raw| Tainted::from_parts(raw, provenance));
+        self.optional(name, input, sanitizer)
+    }
+
+    /// Records a failure that no sanitizer reported, such as a cross-field
+    /// rule ("end must be after start").
+    pub fn reject(&mut self, name: &str, error: SanitizationError) {
+        self.errors.push(FieldError::new(name, error));
+    }
+
+    /// Returns `true` if no field has failed so far.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Ends the session.
+    ///
+    /// `values` is the value returned for each field, or a tuple of them (up
+    /// to 12, and tuples may nest). Returns the verified values if every
+    /// field passed.
+    ///
+    /// # Errors
+    ///
+    /// Returns every recorded [`FieldError`], in the order the fields were
+    /// validated.
+    pub fn finish<V: FieldValues>(self, values: V) -> Result<V::Output, ValidationErrors> {
+        if !self.errors.is_empty() {
+            return Err(ValidationErrors {
+                errors: self.errors,
+            });
+        }
+        // Every field method records an error whenever it returns None, so
+        // this only fails for values that did not come from this session.
+        values.complete().ok_or_else(|| ValidationErrors {
+            errors: vec![FieldError::new(
+                "",
+                SanitizationError::new(
+                    SanitizationErrorKind::InvalidInput,
+                    "value was not validated by this session",
+                ),
+            )],
+        })
+    }
+}
+
+/// Structs whose fields `#[derive(Sanitize)]` fills from a [`Validation`]
+/// session. Not public API.
+///
+/// The derive implements `assemble` by moving each verified value into its
+/// field, nothing else.
+///
+/// BREAKING CHANGE WARNING: Implementing this trait by hand is the same as
+/// calling `Verified::new_unchecked`: [`finish_derived`] trusts whatever
+/// `assemble` builds. Only the derive macro may implement it.
+#[doc(hidden)]
+pub trait DerivedSanitize: Sized {
+    /// The field types, nested in tuples the same way as the session values.
+    type Fields;
+
+    /// Builds the struct from its verified field values.
+    fn assemble(fields: Self::Fields) -> Self;
+}
+
+/// Ends a `#[derive(Sanitize)]` session. Not public API.
+///
+/// `values` can only hold `Verified` values returned by the session's field
+/// methods; their inner values are moved into the struct by
+/// [`DerivedSanitize::assemble`], and the struct carries the labels of all
+/// of them.
+#[doc(hidden)]
+pub fn finish_derived<T, V>(
+    validation: Validation,
+    values: V,
+) -> Result<Labeled<Verified<T>, V::Labels>, ValidationErrors>
+where
+    T: DerivedSanitize,
+    V: FieldValues<Inner = T::Fields>,
+{
+    let values = validation.finish(values)?;
+    let fields = V::into_inner(values, sealed::Token(()));
+    Ok(Labeled::new(Verified::new_unchecked(T::assemble(fields))))
+}
+
+mod sealed {
+    pub trait Sealed {}
+
+    // BREAKING CHANGE WARNING: The field MUST stay private to this file.
+    // FieldValues::into_inner strips labels, so only finish_derived, which
+    // puts them back on the assembled struct, may call it.
+    #[derive(Clone, Copy)]
+    pub struct Token(pub(super) ());
+}
+
+/// An input accepted by [`Validation`]'s field methods.
+///
+/// Implemented for [`Tainted<T>`] and for [`Labeled<Tainted<T>, L>`](Labeled),
+/// such as the values read from [`TaintedInputs`]. The verified value keeps
+/// the labels of its input. This trait is sealed and cannot be implemented
+/// outside policy-core.
+pub trait FieldInput<T>: sealed::Sealed {
+    /// The verified value, with the labels of this input.
+    type Output<U, C: VerificationContext>;
+
+    /// Returns the provenance of the input, if it has one.
+    #[doc(hidden)]
+    fn provenance(&self) -> Option<&Provenance>;
+
+    /// Sanitizes the input, keeping its labels.
+    #[doc(hidden)]
+    fn sanitize_with<U, S: Sanitizer<T, U>>(
+        self,
+        sanitizer: &S,
+    ) -> Result<Self::Output<U, S::Context>, SanitizationError>;
+}
+
+impl<T> sealed::Sealed for Tainted<T> {}
+
+impl<T> FieldInput<T> for Tainted<T> {
+    type Output<U, C: VerificationContext> = Verified<U, C>;
+
+    fn provenance(&self) -> Option<&Provenance> {
+        Tainted::provenance(self)
+    }
+
+    fn sanitize_with<U, S: Sanitizer<T, U>>(
+        self,
+        sanitizer: &S,
+    ) -> Result<Verified<U, S::Context>, SanitizationError> {
+        sanitizer.sanitize(self)
+    }
+}
+
+impl<T, L: LabelSet> sealed::Sealed for Labeled<Tainted<T>, L> {}
+
+impl<T, L: LabelSet> FieldInput<T> for Labeled<Tainted<T>, L> {
+    type Output<U, C: VerificationContext> = Labeled<Verified<U, C>, L>;
+
+    fn provenance(&self) -> Option<&Provenance> {
+        Labeled::provenance(self)
+    }
+
+    fn sanitize_with<U, S: Sanitizer<T, U>>(
+        self,
+        sanitizer: &S,
+    ) -> Result<Labeled<Verified<U, S::Context>, L>, SanitizationError> {
+        self.sanitize(sanitizer)
+    }
+}
+
+/// Values returned by [`Validation`]'s field methods, alone or in tuples.
+///
+/// Implemented for `Option<Verified<T, C>>` (from [`field`](Validation::field)
+/// and [`required`](Validation::required)),
+/// `Option<Option<Verified<T, C>>>` (from [`optional`](Validation::optional)),
+/// the same wrapped in [`Labeled`] for labeled inputs, and tuples of those.
+/// This trait is sealed and cannot be implemented outside policy-core.
+pub trait FieldValues: sealed::Sealed {
+    /// The values with the `Option` layer added by the session removed.
+    type Output;
+
+    /// The values with the `Verified` and `Labeled` layers removed as well.
+    #[doc(hidden)]
+    type Inner;
+
+    /// The union of the labels of every value.
+    type Labels: LabelSet;
+
+    /// Unwraps every value, or returns `None` if any field failed.
+    #[doc(hidden)]
+    fn complete(self) -> Option<Self::Output>;
+
+    /// Removes the `Verified` and `Labeled` layers from completed values.
+    #[doc(hidden)]
+    fn into_inner(output: Self::Output, token: sealed::Token) -> Self::Inner;
+}
+
+// BREAKING CHANGE WARNING: Do NOT implement FieldValues for arbitrary
+// `Option<T>`. `finish_derived` wraps whatever the values complete to in a
+// `Verified`, so any other leaf type lets callers mint verified values from
+// unvalidated data.
+impl<T, C: VerificationContext> sealed::Sealed for Option<Verified<T, C>> {}
+
+impl<T, C: VerificationContext> FieldValues for Option<Verified<T, C>> {
+    type Output = Verified<T, C>;
+    type Inner = T;
+    type Labels = Unlabeled;
+
+    fn complete(self) -> Option<Self::Output> {
+        self
+    }
+
+    fn into_inner(output: Self::Output, _token: sealed::Token) -> T {
+        output.into_inner()
+    }
+}
+
+impl<T, C: VerificationContext> sealed::Sealed for Option<Option<Verified<T, C>>> {}
+
+impl<T, C: VerificationContext> FieldValues for Option<Option<Verified<T, C>>> {
+    type Output = Option<Verified<T, C>>;
+    type Inner = Option<T>;
+    type Labels = Unlabeled;
+
+    fn complete(self) -> Option<Self::Output> {
+        self
+    }
+
+    fn into_inner(output: Self::Output, _token: sealed::Token) -> Option<T> {
+        output.map(Verified::into_inner)
+    }
+}
+
+impl<T, C: VerificationContext, L: LabelSet> sealed::Sealed for Option<Labeled<Verified<T, C>, L>> {}
+
+impl<T, C: VerificationContext, L: LabelSet> FieldValues for Option<Labeled<Verified<T, C>, L>> {
+    type Output = Labeled<Verified<T, C>, L>;
+    type Inner = T;
+    type Labels = L;
+
+    fn complete(self) -> Option<Self::Output> {
+        self
+    }
+
+    fn into_inner(output: Self::Output, _token: sealed::Token) -> T {
+        output.into_value().into_inner()
+    }
+}
+
+impl<T, C: VerificationContext, L: LabelSet> sealed::Sealed
+    for Option<Option<Labeled<Verified<T, C>, L>>>
+{
+}
+
+impl<T, C: VerificationContext, L: LabelSet> FieldValues
+    for Option<Option<Labeled<Verified<T, C>, L>>>
+{
+    type Output = Option<Labeled<Verified<T, C>, L>>;
+    type Inner = Option<T>;
+    type Labels = L;
+
+    fn complete(self) -> Option<Self::Output> {
+        self
+    }
+
+    fn into_inner(output: Self::Output, _token: sealed::Token) -> Option<T> {
+        output.map(|value| value.into_value().into_inner())
+    }
+}
+
+/// The union of the labels of the listed `FieldValues` type parameters.
+macro_rules! joined_labels {
+    ($name:ident) => { $name::Labels };
+    ($name:ident, $($rest:ident),+) => { Join<$name::Labels, joined_labels!($($rest),+)> };
+}
+
+macro_rules! tuple_field_values {
+    ($($name:ident),+) => {
+        impl<$($name: FieldValues),+> sealed::Sealed for ($($name,)+) {}
+
+        impl<$($name: FieldValues),+> FieldValues for ($($name,)+) {
+            type Output = ($($name::Output,)+);
+            type Inner = ($($name::Inner,)+);
+            type Labels = joined_labels!($($name),+);
+
+            #[allow(non_snake_case)]
+            fn complete(self) -> Option<Self::Output> {
+                let ($($name,)+) = self;
+                Some(($($name.complete()?,)+))
+            }
+
+            #[allow(non_snake_case)]
+            fn into_inner(output: Self::Output, token: sealed::Token) -> Self::Inner {
+                let ($($name,)+) = output;
+                ($($name::into_inner($name, token),)+)
+            }
+        }
+    };
+}
+
+tuple_field_values!(A);
+tuple_field_values!(A, B);
+tuple_field_values!(A, B, C);
+tuple_field_values!(A, B, C, D);
+tuple_field_values!(A, B, C, D, E);
+tuple_field_values!(A, B, C, D, E, F);
+tuple_field_values!(A, B, C, D, E, F, G);
+tuple_field_values!(A, B, C, D, E, F, G, H);
+tuple_field_values!(A, B, C, D, E, F, G, H, I);
+tuple_field_values!(A, B, C, D, E, F, G, H, I, J);
+tuple_field_values!(A, B, C, D, E, F, G, H, I, J, K);
+tuple_field_values!(A, B, C, D, E, F, G, H, I, J, K, L);
+
+/// A sanitization failure for one named field.
+///
+/// Sanitizer messages never include the rejected input, so a `FieldError`
+/// is safe to return to the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldError {
+    field: String,
+    error: SanitizationError,
+}
+
+impl FieldError {
+    fn new(field: &str, error: SanitizationError) -> Self {
+        Self {
+            field: field.to_string(),
+            error,
+        }
+    }
+
+    /// Returns the name of the field that failed.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// Returns the error kind.
+    pub fn kind(&self) -> SanitizationErrorKind {
+        self.error.kind()
+    }
+
+    /// Returns the error message.
+    pub fn message(&self) -> &str {
+        self.error.message()
+    }
+
+    /// Returns where the rejected value came from, if known.
+    pub fn provenance(&self) -> Option<&Provenance> {
+        self.error.provenance()
+    }
+
+    /// Returns the underlying sanitization error.
+    pub fn error(&self) -> &SanitizationError {
+        &self.error
+    }
+}
+
+impl fmt::Display for FieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.error.message())
+    }
+}
+
+/// Every field that failed in a [`Validation`] session.
+///
+/// Never empty. Fields appear in the order they were validated.
+///
+/// # Examples
+///
+/// ```
+/// use policy_core::{Sanitizer, StringSanitizer, Tainted, Validation};
+///
+/// let names = StringSanitizer::new(8).unwrap();
+/// let mut validation = Validation::new();
+/// let first = validation.field("first", Tainted::new("".to_string()), &names);
+/// let last = validation.field("last", Tainted::new("Featherstonehaugh".to_string()), &names);
+///
+/// let report = validation.finish((first, last)).unwrap_err();
+/// assert_eq!(
+///     report.to_string(),
+///     "2 invalid fields: first: input is empty or contains only whitespace; \
+///      last: input exceeds maximum length of 8"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationErrors {
+    errors: Vec<FieldError>,
+}
+
+impl ValidationErrors {
+    /// Returns the errors in the order the fields were validated.
+    pub fn errors(&self) -> &[FieldError] {
+        &self.errors
+    }
+
+    /// Returns the names of the failed fields.
+    pub fn fields(&self) -> impl Iterator<Item = &str> + '_ {
+        self.errors.iter().map(FieldError::field)
+    }
+
+    /// Returns the error recorded for `field`, if it failed.
+    pub fn get(&self, field: &str) -> Option<&FieldError> {
+        self.errors.iter().find(|error| error.field == field)
+    }
+
+    /// Returns the number of failed fields.
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    /// Always returns `false`; a report holds at least one error.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+impl fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.errors.len() {
+            1 => write!(f, "1 invalid field: ")?,
+            n => write!(f, "{} invalid fields: ", n)?,
+        }
+        for (i, error) in self.errors.iter().enumerate() {
+            if i > 0 {
+                f.write_str("; ")?;
+            }
+            write!(f, "{}", error)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ValidationErrors {}
+
+impl<'a> IntoIterator for &'a ValidationErrors {
+    type Item = &'a FieldError;
+    type IntoIter = std::slice::Iter<'a, FieldError>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.iter()
+    }
+}
+
+impl From<ValidationErrors> for Violation {
+    /// Converts the report into an `InvalidInput` violation naming every
+    /// failed field.
+    fn from(errors: ValidationErrors) -> Self {
+        Violation::new(
+            ViolationKind::InvalidInput,
+            format!("Validation failed: {}", errors),
+        )
+    }
+}
+
+/// A struct that can be read from a request and sanitized as a whole.
+///
+/// Implement it with `#[derive(Sanitize)]` (requires the `macros` feature),
+/// which declares a sanitizer and a request source for every field. Every
+/// field is sanitized and all failures are reported together. The result is
+/// labeled [`UserSupplied`], like the request values it was built from.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "macros")] {
+/// use policy_core::web::{ExtractMetadata, ExtractTaintedInputs, RequestAdapter};
+/// use policy_core::{
+///     actions, AllowAuthenticated, Authorized, IntegerSanitizer, PolicyGate, Principal, Sanitize,
+///     StringSanitizer,
+/// };
+///
+/// #[derive(Debug, Sanitize)]
+/// struct Search {
+///     #[input(query = "q", with = StringSanitizer::new(100).unwrap())]
+///     text: String,
+///     #[input(query, with = IntegerSanitizer::new(1u32, 100).unwrap())]
+///     limit: Option<u32>,
+/// }
+///
+/// let mut request = RequestAdapter::new("req-1".to_string());
+/// request.set_principal(Some(Principal::new("user-1", "Alice")));
+/// request.add_query_param("q".to_string(), " rust ".to_string());
+/// let search = Search::from_inputs(&request.extract_tainted_inputs()).unwrap();
+/// assert_eq!(search.labels(), ["user"]);
+///
+/// let ctx = PolicyGate::new(request.extract_metadata())
+///     .with_authorizer(AllowAuthenticated)
+///     .require(Authorized::for_action(actions::DECLASSIFY))
+///     .build()
+///     .unwrap();
+/// let search = ctx
+///     .declassify()
+///     .unwrap()
+///     .unlabel(search, "echo the search back to the user")
+///     .unwrap();
+/// assert_eq!(search.as_ref().text, "rust");
+/// assert_eq!(search.as_ref().limit, None);
+/// # }
+/// ```
+pub trait Sanitize: Sized {
+    /// Reads every field from its declared request source and sanitizes it.
+    ///
+    /// Errors name fields by their request key, so they can be shown to the
+    /// client as-is. A missing required field is reported as
+    /// [`SanitizationErrorKind::Empty`].
+    ///
+    /// Request values are [`UserSupplied`], and so is the verified struct.
+    fn from_inputs(
+        inputs: &TaintedInputs,
+    ) -> Result<Labeled<Verified<Self>, UserSupplied>, ValidationErrors>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::contexts::LogLine;
+    use crate::{IntegerSanitizer, StringSanitizer};
+
+    fn tainted(value: &str) -> Tainted<String> {
+        Tainted::new(value.to_string())
+    }
+
+    #[test]
+    fn finish_returns_all_values_when_every_field_passes() {
+        let names = StringSanitizer::new(16).unwrap();
+        let counts = IntegerSanitizer::new(0u32, 100).unwrap();
+
+        let mut validation = Validation::new();
+        let name = validation.field("name", tainted(" alice "), &names);
+        let count = validation.required("count", Some(tainted("3")), &counts);
+        let note = validation.optional("note", None::<Tainted<String>>, &names);
+        assert!(validation.is_valid());
+
+        let (name, count, note): (Verified<String, LogLine>, _, _) =
+            validation.finish((name, count, note)).unwrap();
+        assert_eq!(name.as_ref(), "alice");
+        assert_eq!(count.into_inner(), 3);
+        assert!(note.is_none());
+    }
+
+    #[test]
+    fn every_failing_field_is_reported_in_order() {
+        let names = StringSanitizer::new(4).unwrap();
+        let counts = IntegerSanitizer::new(0u32, 100).unwrap();
+
+        let mut validation = Validation::new();
+        let a = validation.field("a", tainted("toolong"), &names);
+        let b = validation.field("b", tainted("ok"), &names);
+        let c = validation.required("c", None::<Tainted<String>>, &counts);
+        let d = validation.optional("d", Some(tainted("x")), &counts);
+        assert!(!validation.is_valid());
+
+        let report = validation.finish((a, b, c, d)).unwrap_err();
+        assert_eq!(report.len(), 3);
+        assert_eq!(report.fields().collect::<Vec<_>>(), ["a", "c", "d"]);
+        assert_eq!(
+            report.get("a").unwrap().kind(),
+            SanitizationErrorKind::TooLong
+        );
+        assert_eq!(
+            report.get("c").unwrap().kind(),
+            SanitizationErrorKind::Empty
+        );
+        assert_eq!(report.get("c").unwrap().message(), "field is required");
+        assert_eq!(
+            report.get("d").unwrap().kind(),
+            SanitizationErrorKind::MalformedInput
+        );
+        assert!(report.get("b").is_none());
+    }
+
+    #[test]
+    fn reject_records_cross_field_errors() {
+        let counts = IntegerSanitizer::new(0u32, 100).unwrap();
+
+        let mut validation = Validation::new();
+        let start = validation.field("start", tainted("9"), &counts);
+        let end = validation.field("end", tainted("5"), &counts);
+        if let (Some(start), Some(end)) = (&start, &end) {
+            if end.as_ref() < start.as_ref() {
+                validation.reject(
+                    "end",
+                    SanitizationError::new(
+                        SanitizationErrorKind::OutOfRange,
+                        "must not be before start",
+                    ),
+                );
+            }
+        }
+
+        let report = validation.finish((start, end)).unwrap_err();
+        assert_eq!(
+            report.to_string(),
+            "1 invalid field: end: must not be before start"
+        );
+    }
+
+    #[test]
+    fn report_does_not_echo_input() {
+        let names = StringSanitizer::new(8).unwrap();
+
+        let mut validation = Validation::new();
+        let a = validation.field("a", tainted("SECRET\nVALUE"), &names);
+        let b = validation.field("b", tainted("SECRET_VALUE_TOO_LONG"), &names);
+
+        let report = validation.finish((a, b)).unwrap_err();
+        assert!(!report.to_string().contains("SECRET"));
+        assert!(!format!("{:?}", report).contains("SECRET"));
+    }
+
+    #[test]
+    fn violation_names_failed_fields() {
+        let names = StringSanitizer::new(8).unwrap();
+
+        let mut validation = Validation::new();
+        let user = validation.field("username", tainted(""), &names);
+
+        let violation = Violation::from(validation.finish(user).unwrap_err());
+        assert_eq!(violation.kind, ViolationKind::InvalidInput);
+        assert_eq!(
+            violation.message,
+            "Validation failed: 1 invalid field: username: input is empty or contains only whitespace"
+        );
+    }
+
+    #[test]
+    fn field_of_sanitizes_fields_of_a_tainted_struct() {
+        struct Form {
+            name: String,
+            nickname: Option<String>,
+        }
+        let names = StringSanitizer::new(8).unwrap();
+
+        let form = Tainted::new(Form {
+            name: " ann ".to_string(),
+            nickname: None,
+        });
+        let mut validation = Validation::new();
+        let name = validation.field_of("name", &form, |f| &f.name, &names);
+        let nickname =
+            validation.optional_field_of("nickname", &form, |f| f.nickname.as_ref(), &names);
+        let (name, nickname) = validation.finish((name, nickname)).unwrap();
+        assert_eq!(name.as_ref(), "ann");
+        assert!(nickname.is_none());
+
+        let form = Tainted::new(Form {
+            name: "ann".to_string(),
+            nickname: Some("much too long".to_string()),
+        });
+        let mut validation = Validation::new();
+        let name = validation.field_of("name", &form, |f| &f.name, &names);
+        let nickname =
+            validation.optional_field_of("nickname", &form, |f| f.nickname.as_ref(), &names);
+        let report = validation.finish((name, nickname)).unwrap_err();
+        assert_eq!(report.fields().collect::<Vec<_>>(), ["nickname"]);
+    }
+
+    #[test]
+    fn finish_rejects_values_from_outside_the_session() {
+        let report = Validation::new()
+            .finish(None::<Verified<String>>)
+            .unwrap_err();
+        assert_eq!(
+            report.errors()[0].kind(),
+            SanitizationErrorKind::InvalidInput
+        );
+    }
+
+    #[test]
+    fn labeled_inputs_keep_their_labels() {
+        use crate::web::{ExtractTaintedInputs, RequestAdapter};
+
+        let mut adapter = RequestAdapter::new("req-labels".to_string());
+        adapter.add_query_param("name".to_string(), " alice ".to_string());
+        let inputs = adapter.extract_tainted_inputs();
+        let names = StringSanitizer::new(16).unwrap();
+
+        let mut validation = Validation::new();
+        let name = validation.required("name", inputs.get_query("name"), &names);
+        let note = validation.optional("note", inputs.get_query("note"), &names);
+        let plain = validation.field("plain", tainted("bob"), &names);
+
+        let (name, note, plain) = validation.finish((name, note, plain)).unwrap();
+        assert_eq!(name.labels(), ["user"]);
+        assert!(note.is_none());
+        assert_eq!(plain.as_ref(), "bob");
+    }
+}
@@ -1,3 +1,9 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::contexts::{Plain, VerificationContext};
+use crate::Approval;
+
 /// A wrapper for data that has been validated/sanitized and is safe to use.
 ///
 /// `Verified<T>` represents a value that has undergone validation or sanitization
@@ -30,6 +36,36 @@
 /// - Does NOT implement `Default` (no arbitrary "empty" verified values)
 /// - Safe to use in security-sensitive contexts
 ///
+/// # Approval
+///
+/// Values produced by the built-in sanitizers record an [`Approval`] naming
+/// the sanitizer, a fingerprint of its configuration and whether the value
+/// was transformed. Read it with [`approval`](Self::approval) and attach it
+/// to an [`AuditEvent`](crate::audit::AuditEvent) to trace which rule
+/// accepted a value. Composite values built by
+/// [`StructSanitizer`](crate::StructSanitizer) or a
+/// [`Validation`](crate::Validation) session have no single approval; each
+/// field carries its own.
+///
+/// # Contexts
+///
+/// The `C` parameter records which output context the value was verified for
+/// (see [`contexts`](crate::contexts)). It defaults to
+/// [`Plain`](crate::contexts::Plain). Sinks only accept values verified for
+/// their own context, so a string sanitized for a log line cannot be passed
+/// to a sink that expects HTML:
+///
+/// ```compile_fail
+/// use policy_core::contexts::{Html, LogLine};
+/// use policy_core::Verified;
+///
+/// fn render(_fragment: &Verified<String, Html>) {}
+///
+/// fn log_line(line: &Verified<String, LogLine>) {
+///     render(line); // Error: expected `Html`, found `LogLine`
+/// }
+/// ```
+///
 /// # Examples
 ///
 /// External callers cannot create `Verified<T>` directly:
@@ -63,15 +99,19 @@
 /// ```
 // BREAKING CHANGE WARNING: Do NOT add Default derive or implementation.
 // Default would allow creating "empty" verified values without validation.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Verified<T> {
+pub struct Verified<T, C = Plain> {
     // BREAKING CHANGE WARNING: This field MUST remain private.
     // Making it public bypasses the validation bottleneck, allowing external code
     // to construct Verified<T> with arbitrary values (CRITICAL SECURITY BYPASS).
     inner: T,
+    // BREAKING CHANGE WARNING: Do NOT add a method that changes `C` without
+    // re-running a sanitizer. Relabeling a value lets data checked for one sink
+    // reach another (e.g. log-safe text rendered as HTML, CWE-79).
+    _context: PhantomData<fn() -> C>,
+    approval: Option<Approval>,
 }
 
-impl<T> Verified<T> {
+impl<T, C> Verified<T, C> {
     /// Creates a `Verified<T>` without performing validation.
     ///
     /// # Safety (Policy-Level)
@@ -94,7 +134,35 @@ impl<T> Verified<T> {
     /// allowing unvalidated data into sinks (CWE-20, CWE-74, CWE-89, CWE-117, CWE-79).
     #[allow(dead_code)] // Used by future sanitization code and tests
     pub(crate) fn new_unchecked(value: T) -> Self {
-        Self { inner: value }
+        Self {
+            inner: value,
+            _context: PhantomData,
+            approval: None,
+        }
+    }
+
+    /// Records the sanitizer that approved this value.
+    pub(crate) fn approved_by(mut self, approval: Approval) -> Self {
+        self.approval = Some(approval);
+        self
+    }
+
+    /// Returns the sanitizer that approved this value, if known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use policy_core::{Sanitizer, StringSanitizer, Tainted};
+    ///
+    /// let sanitizer = StringSanitizer::new(100).unwrap();
+    /// let verified = sanitizer.sanitize(Tainted::new("hello".to_string())).unwrap();
+    ///
+    /// let approval = verified.approval().unwrap();
+    /// assert_eq!(approval.sanitizer(), "StringSanitizer");
+    /// assert!(!approval.transformed());
+    /// ```
+    pub fn approval(&self) -> Option<&Approval> {
+        self.approval.as_ref()
     }
 
     /// Consumes the `Verified<T>` and returns the inner value.
@@ -105,7 +173,7 @@ impl<T> Verified<T> {
     ///
     /// ```ignore
     /// # use policy_core::Verified;
-    /// # let verified = Verified::new_unchecked("data".to_string());
+    /// # let verified: Verified<_> = Verified::new_unchecked("data".to_string());
     /// let value = verified.into_inner();
     /// assert_eq!(value, "data");
     /// ```
@@ -122,19 +190,51 @@ impl<T> Verified<T> {
 ///
 /// This allows `Verified<T>` to integrate seamlessly with Rust's standard library
 /// and other code that expects `AsRef<T>`.
-impl<T> AsRef<T> for Verified<T> {
+impl<T, C> AsRef<T> for Verified<T, C> {
     fn as_ref(&self) -> &T {
         &self.inner
     }
 }
 
+// Manual impls so the uninhabited context markers need not implement these traits.
+
+impl<T: fmt::Debug, C: VerificationContext> fmt::Debug for Verified<T, C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("Verified");
+        debug.field("inner", &self.inner).field("context", &C::NAME);
+        if let Some(approval) = &self.approval {
+            debug.field("approval", &format_args!("{}", approval));
+        }
+        debug.finish()
+    }
+}
+
+impl<T: Clone, C> Clone for Verified<T, C> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            _context: PhantomData,
+            approval: self.approval,
+        }
+    }
+}
+
+impl<T: PartialEq, C> PartialEq for Verified<T, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner
+    }
+}
+
+impl<T: Eq, C> Eq for Verified<T, C> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::contexts::LogLine;
 
     #[test]
     fn verified_as_ref_returns_reference() {
-        let verified = Verified::new_unchecked("test-data".to_string());
+        let verified: Verified<_> = Verified::new_unchecked("test-data".to_string());
         let value_ref = verified.as_ref();
 
         assert_eq!(value_ref, "test-data");
@@ -143,7 +243,7 @@ mod tests {
 
     #[test]
     fn verified_into_inner_returns_value() {
-        let verified = Verified::new_unchecked(42);
+        let verified: Verified<_> = Verified::new_unchecked(42);
         let value = verified.into_inner();
 
         assert_eq!(value, 42);
@@ -151,7 +251,7 @@ mod tests {
 
     #[test]
     fn verified_as_ref_does_not_consume() {
-        let verified = Verified::new_unchecked(vec![1, 2, 3]);
+        let verified: Verified<_> = Verified::new_unchecked(vec![1, 2, 3]);
 
         // Can call as_ref multiple times
         let ref1 = verified.as_ref();
@@ -167,7 +267,7 @@ mod tests {
 
     #[test]
     fn verified_derives_work() {
-        let v1 = Verified::new_unchecked("data".to_string());
+        let v1: Verified<_> = Verified::new_unchecked("data".to_string());
         let v2 = v1.clone();
 
         // Clone works
@@ -177,6 +277,33 @@ mod tests {
         let debug_output = format!("{:?}", v1);
         assert!(debug_output.contains("Verified"));
         assert!(debug_output.contains("data"));
+        assert!(debug_output.contains("plain"));
+    }
+
+    #[test]
+    fn verified_debug_names_its_context() {
+        let verified: Verified<String, LogLine> = Verified::new_unchecked("line".to_string());
+
+        assert_eq!(
+            format!("{:?}", verified),
+            r#"Verified { inner: "line", context: "log_line" }"#
+        );
+    }
+
+    #[test]
+    fn verified_debug_shows_approval() {
+        let approval = Approval::new("StringSanitizer", &100usize, true);
+        let verified: Verified<String, LogLine> =
+            Verified::new_unchecked("line".to_string()).approved_by(approval);
+
+        assert_eq!(
+            format!("{:?}", verified),
+            format!(
+                r#"Verified {{ inner: "line", context: "log_line", approval: {} }}"#,
+                approval
+            )
+        );
+        assert_eq!(verified.clone().approval(), Some(&approval));
     }
 
     #[test]
@@ -189,12 +316,12 @@ mod tests {
         // let v: Verified<i32> = 42.into(); // ← no From impl
 
         // Only internal code can construct:
-        let _ = Verified::new_unchecked(42);
+        let _: Verified<i32> = Verified::new_unchecked(42);
     }
 
     #[test]
     fn verified_no_deref() {
-        let verified = Verified::new_unchecked(String::from("test"));
+        let verified: Verified<_> = Verified::new_unchecked(String::from("test"));
 
         // This would not compile if uncommented (good!):
         // let s: &str = &*verified; // ← no Deref
@@ -226,13 +353,13 @@ mod tests {
             /// Property: as_ref() returns the same value as into_inner()
             #[test]
             fn proptest_verified_as_ref_equals_inner(value in prop::string::string_regex("[a-zA-Z0-9 _-]{1,50}").unwrap()) {
-                let verified = Verified::new_unchecked(value.clone());
+                let verified: Verified<_> = Verified::new_unchecked(value.clone());
 
                 // Get reference via as_ref()
                 let ref_value = verified.as_ref();
 
                 // Clone to test again since into_inner consumes
-                let verified2 = Verified::new_unchecked(value.clone());
+                let verified2: Verified<_> = Verified::new_unchecked(value.clone());
                 let inner_value = verified2.into_inner();
 
                 // Both should be equal
@@ -3,8 +3,9 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use crate::labels::{Labeled, UserSupplied};
 use crate::request::{Principal, RequestMeta};
-use crate::Tainted;
+use crate::{Provenance, Tainted};
 
 use super::{ExtractMetadata, ExtractTaintedInputs};
 
@@ -29,10 +30,7 @@ use super::{ExtractMetadata, ExtractTaintedInputs};
 ///
 /// // Building from raw parts (framework integration would use From<FrameworkRequest>)
 /// let mut adapter = RequestAdapter::new("req-12345".to_string());
-/// adapter.set_principal(Some(Principal {
-///     id: "user-1".to_string(),
-///     name: "Alice".to_string(),
-/// }));
+/// adapter.set_principal(Some(Principal::new("user-1", "Alice")));
 /// adapter.add_query_param("search".to_string(), "user input".to_string());
 ///
 /// // Extract metadata for PolicyGate
@@ -187,8 +185,11 @@ impl ExtractTaintedInputs for RequestAdapter {
 
 /// Collection of tainted inputs extracted from an HTTP request.
 ///
-/// All values are wrapped in `Tainted<T>` to enforce sanitization before use.
-/// This type provides read-only access to inputs - modification is not allowed.
+/// All values are wrapped in `Tainted<T>` to enforce sanitization before use,
+/// with a [`Provenance`] naming the input channel and key they were read
+/// from, and carry the [`UserSupplied`] information-flow label, which stays
+/// on the sanitized value. This type provides read-only access to inputs -
+/// modification is not allowed.
 ///
 /// Uses `Arc` internally for efficient sharing of input data without cloning.
 ///
@@ -196,7 +197,7 @@ impl ExtractTaintedInputs for RequestAdapter {
 ///
 /// ```
 /// use policy_core::web::{RequestAdapter, ExtractTaintedInputs};
-/// use policy_core::{Sanitizer, StringSanitizer};
+/// use policy_core::StringSanitizer;
 ///
 /// let mut adapter = RequestAdapter::new("req-1".to_string());
 /// adapter.add_query_param("username".to_string(), "alice".to_string());
@@ -204,9 +205,10 @@ impl ExtractTaintedInputs for RequestAdapter {
 /// let inputs = adapter.extract_tainted_inputs();
 /// let tainted_username = inputs.get_query("username").unwrap();
 ///
-/// // Must sanitize before use
+/// // Must sanitize before use; the result is still labeled `UserSupplied`
 /// let sanitizer = StringSanitizer::new(50).unwrap();
-/// let verified_username = sanitizer.sanitize(tainted_username).unwrap();
+/// let verified_username = tainted_username.sanitize(&sanitizer).unwrap();
+/// assert_eq!(verified_username.labels(), ["user"]);
 /// ```
 #[derive(Debug, Clone)]
 pub struct TaintedInputs {
@@ -215,47 +217,64 @@ pub struct TaintedInputs {
     path_params: Arc<HashMap<String, String>>,
 }
 
+/// Wraps a request value as tainted, user-supplied input read from `source`.
+fn user_input(value: &str, source: Provenance) -> Labeled<Tainted<String>, UserSupplied> {
+    Labeled::new(Tainted::new(value.to_string()).with_provenance(source))
+}
+
 impl TaintedInputs {
     /// Gets a tainted query parameter by key.
     ///
     /// Returns `None` if the key doesn't exist.
-    pub fn get_query(&self, key: &str) -> Option<Tainted<String>> {
-        self.query_params.get(key).map(|v| Tainted::new(v.clone()))
+    pub fn get_query(&self, key: &str) -> Option<Labeled<Tainted<String>, UserSupplied>> {
+        self.query_params
+            .get(key)
+            .map(|v| user_input(v, Provenance::query(key)))
     }
 
     /// Gets a tainted header by key.
     ///
     /// Returns `None` if the key doesn't exist.
-    pub fn get_header(&self, key: &str) -> Option<Tainted<String>> {
-        self.headers.get(key).map(|v| Tainted::new(v.clone()))
+    pub fn get_header(&self, key: &str) -> Option<Labeled<Tainted<String>, UserSupplied>> {
+        self.headers
+            .get(key)
+            .map(|v| user_input(v, Provenance::header(key)))
     }
 
     /// Gets a tainted path parameter by key.
     ///
     /// Returns `None` if the key doesn't exist.
-    pub fn get_path_param(&self, key: &str) -> Option<Tainted<String>> {
-        self.path_params.get(key).map(|v| Tainted::new(v.clone()))
+    pub fn get_path_param(&self, key: &str) -> Option<Labeled<Tainted<String>, UserSupplied>> {
+        self.path_params
+            .get(key)
+            .map(|v| user_input(v, Provenance::path(key)))
     }
 
-    /// Returns an iterator over query parameters as (key, `Tainted<value>`) pairs.
-    pub fn query_params(&self) -> impl Iterator<Item = (&str, Tainted<String>)> + '_ {
+    /// Returns an iterator over query parameters as (key, labeled `Tainted<value>`) pairs.
+    pub fn query_params(
+        &self,
+    ) -> impl Iterator<Item = (&str, Labeled<Tainted<String>, UserSupplied>)> + '_ {
         self.query_params
             .iter()
-            .map(|(k, v)| (k.as_str(), Tainted::new(v.clone())))
+            .map(|(k, v)| (k.as_str(), user_input(v, Provenance::query(k))))
     }
 
-    /// Returns an iterator over headers as (key, `Tainted<value>`) pairs.
-    pub fn headers(&self) -> impl Iterator<Item = (&str, Tainted<String>)> + '_ {
+    /// Returns an iterator over headers as (key, labeled `Tainted<value>`) pairs.
+    pub fn headers(
+        &self,
+    ) -> impl Iterator<Item = (&str, Labeled<Tainted<String>, UserSupplied>)> + '_ {
         self.headers
             .iter()
-            .map(|(k, v)| (k.as_str(), Tainted::new(v.clone())))
+            .map(|(k, v)| (k.as_str(), user_input(v, Provenance::header(k))))
     }
 
-    /// Returns an iterator over path parameters as (key, `Tainted<value>`) pairs.
-    pub fn path_params(&self) -> impl Iterator<Item = (&str, Tainted<String>)> + '_ {
+    /// Returns an iterator over path parameters as (key, labeled `Tainted<value>`) pairs.
+    pub fn path_params(
+        &self,
+    ) -> impl Iterator<Item = (&str, Labeled<Tainted<String>, UserSupplied>)> + '_ {
         self.path_params
             .iter()
-            .map(|(k, v)| (k.as_str(), Tainted::new(v.clone())))
+            .map(|(k, v)| (k.as_str(), user_input(v, Provenance::path(k))))
     }
 
     /// Checks if a query parameter with the given key exists.
@@ -312,10 +331,7 @@ mod tests {
     #[test]
     fn request_adapter_set_principal() {
         let mut adapter = RequestAdapter::new("req-1".to_string());
-        let principal = Principal {
-            id: "user-1".to_string(),
-            name: "Alice".to_string(),
-        };
+        let principal = Principal::new("user-1", "Alice");
 
         adapter.set_principal(Some(principal.clone()));
         assert_eq!(adapter.principal().unwrap().id, "user-1");
@@ -358,10 +374,7 @@ mod tests {
     #[test]
     fn extract_metadata_includes_principal() {
         let mut adapter = RequestAdapter::new("req-1".to_string());
-        let principal = Principal {
-            id: "user-1".to_string(),
-            name: "Bob".to_string(),
-        };
+        let principal = Principal::new("user-1", "Bob");
         adapter.set_principal(Some(principal));
 
         let meta = adapter.extract_metadata();
@@ -394,6 +407,27 @@ mod tests {
         assert!(inputs.get_query("key").is_some());
     }
 
+    #[test]
+    fn tainted_inputs_are_labeled_with_their_channel() {
+        let mut adapter = RequestAdapter::new("req-1".to_string());
+        adapter.add_query_param("q".to_string(), "term".to_string());
+        adapter.add_header("x-forwarded-for".to_string(), "10.0.0.1".to_string());
+        adapter.add_path_param("id".to_string(), "7".to_string());
+
+        let inputs = adapter.extract_tainted_inputs();
+
+        let query = inputs.get_query("q").unwrap();
+        assert_eq!(query.labels(), ["user"]);
+        assert_eq!(query.provenance(), Some(&Provenance::query("q")));
+        let header = inputs.get_header("x-forwarded-for").unwrap();
+        assert_eq!(
+            header.provenance(),
+            Some(&Provenance::header("x-forwarded-for"))
+        );
+        let (key, path) = inputs.path_params().next().unwrap();
+        assert_eq!(path.provenance(), Some(&Provenance::path(key)));
+    }
+
     #[test]
     fn multiple_extractions_produce_independent_copies() {
         let mut adapter = RequestAdapter::new("req-1".to_string());
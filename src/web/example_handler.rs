@@ -7,23 +7,30 @@
 //! They demonstrate proper usage patterns without requiring actual HTTP infrastructure.
 
 use crate::audit::{AuditEvent, AuditEventKind, AuditOutcome};
+use crate::contexts::LogLine;
 use crate::error::Violation;
-use crate::{Authenticated, Authorized, PolicyGate, Sanitizer, StringSanitizer};
+use crate::labels::{Labeled, UserSupplied};
+use crate::policy::actions;
+use crate::{
+    AllowAuthenticated, Authenticated, Authorized, PolicyGate, StringSanitizer, UrlSanitizer,
+    Verified,
+};
 
 use super::{extract_authed, extract_unauthed, ExtractMetadata, RequestAdapter};
 
 /// Result of a public endpoint handler (no authentication required).
 ///
 /// This demonstrates how to handle requests that don't require authentication
-/// but still need taint-safe input processing.
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// but still need taint-safe input processing. The query and everything
+/// computed from it stay labeled [`UserSupplied`].
+#[derive(Debug, Clone)]
 pub struct PublicSearchResult {
     /// Request ID for tracing
     pub request_id: String,
     /// Sanitized search query
-    pub query: String,
+    pub query: Labeled<Verified<String, LogLine>, UserSupplied>,
     /// Number of results (simulated)
-    pub result_count: usize,
+    pub result_count: Labeled<usize, UserSupplied>,
 }
 
 /// Handles a public search endpoint.
@@ -31,18 +38,25 @@ pub struct PublicSearchResult {
 /// This handler demonstrates:
 /// - Unauthenticated request processing
 /// - Tainted input sanitization
+/// - Labels following the query into derived values
 /// - No capability requirements
 ///
 /// # Examples
 ///
 /// ```
 /// use policy_core::web::{RequestAdapter, example_handler::handle_public_search};
+/// use policy_core::{Sink, VecSink};
 ///
 /// let mut adapter = RequestAdapter::new("req-search-001".to_string());
 /// adapter.add_query_param("q".to_string(), "  rust policy  ".to_string());
 ///
 /// let result = handle_public_search(&adapter).expect("valid search");
-/// assert_eq!(result.query, "rust policy"); // Trimmed by sanitizer
+/// assert_eq!(result.query.labels(), ["user"]);
+///
+/// // VecSink accepts user-supplied values
+/// let sink = VecSink::new();
+/// sink.sink(&result.query).unwrap();
+/// assert_eq!(sink.into_vec(), ["rust policy"]); // Trimmed by sanitizer
 /// ```
 pub fn handle_public_search(adapter: &RequestAdapter) -> Result<PublicSearchResult, Violation> {
     // 1. Extract unauthenticated context and inputs
@@ -58,14 +72,16 @@ pub fn handle_public_search(adapter: &RequestAdapter) -> Result<PublicSearchResu
 
     // 3. Sanitize query (prevent injection, enforce length limits)
     let sanitizer = StringSanitizer::new(200).unwrap();
-    let verified_query = sanitizer.sanitize(tainted_query)?;
+    let query = tainted_query.sanitize(&sanitizer)?;
 
     // 4. Perform search (simulated - no capabilities needed for public endpoint)
-    let result_count = verified_query.as_ref().split_whitespace().count() * 10;
+    let result_count = query
+        .clone()
+        .map(|query| query.as_ref().split_whitespace().count() * 10);
 
     Ok(PublicSearchResult {
         request_id: extraction.context.request_id().to_string(),
-        query: verified_query.as_ref().clone(),
+        query,
         result_count,
     })
 }
@@ -96,10 +112,7 @@ pub struct UserDataResult {
 /// use policy_core::Principal;
 ///
 /// let mut adapter = RequestAdapter::new("req-user-001".to_string());
-/// adapter.set_principal(Some(Principal {
-///     id: "user-123".to_string(),
-///     name: "Alice".to_string(),
-/// }));
+/// adapter.set_principal(Some(Principal::new("user-123", "Alice")));
 ///
 /// let result = handle_user_data(&adapter).expect("authenticated and authorized");
 /// assert_eq!(result.user_id, "user-123");
@@ -113,6 +126,7 @@ pub fn handle_user_data(adapter: &RequestAdapter) -> Result<UserDataResult, Viol
 
     // 3. Apply policies to get authorized context with capabilities
     let ctx = PolicyGate::new(meta)
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action("log"))
         .build()?;
@@ -153,6 +167,7 @@ pub struct ApiCallResult {
 /// - Full authorization flow
 /// - HTTP capability gating
 /// - Tainted URL sanitization
+/// - Audited declassification of the user-supplied URL
 /// - Request-id propagation to external calls
 ///
 /// # Examples
@@ -162,10 +177,7 @@ pub struct ApiCallResult {
 /// use policy_core::Principal;
 ///
 /// let mut adapter = RequestAdapter::new("req-api-001".to_string());
-/// adapter.set_principal(Some(Principal {
-///     id: "user-456".to_string(),
-///     name: "Bob".to_string(),
-/// }));
+/// adapter.set_principal(Some(Principal::new("user-456", "Bob")));
 /// adapter.add_query_param("url".to_string(), "https://api.example.com/data".to_string());
 ///
 /// let result = handle_api_call(&adapter).expect("authorized");
@@ -179,14 +191,17 @@ pub fn handle_api_call(adapter: &RequestAdapter) -> Result<ApiCallResult, Violat
     // 2. Build authorized context with HTTP capability
     let meta = adapter.extract_metadata();
     let ctx = PolicyGate::new(meta)
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action("http"))
         .require(Authorized::for_action("log"))
+        .require(Authorized::for_action(actions::DECLASSIFY))
         .build()?;
 
     // 3. Get capability-gated HTTP client
     let http = ctx.http()?;
     let logger = ctx.log()?;
+    let declassify = ctx.declassify()?;
 
     // 4. Extract and sanitize tainted URL
     let tainted_url = extraction.inputs.get_query("url").ok_or_else(|| {
@@ -196,20 +211,26 @@ pub fn handle_api_call(adapter: &RequestAdapter) -> Result<ApiCallResult, Violat
         )
     })?;
 
-    let sanitizer = StringSanitizer::new(256).unwrap();
-    let verified_url = sanitizer.sanitize(tainted_url)?;
+    let sanitizer = UrlSanitizer::new().allow_scheme("https");
+    let verified_url = declassify.unlabel(
+        tainted_url.sanitize(&sanitizer)?,
+        "caller chooses the API endpoint to fetch",
+    )?;
 
     // 5. Make HTTP call with verified URL and request-id context
+    // (Url displays without its query string)
     logger.info(format_args!(
         "Making API call to: {}",
         verified_url.as_ref()
     ));
-    http.get(&verified_url);
+    let success = http
+        .get(&verified_url)
+        .is_ok_and(|response| response.is_success());
 
     Ok(ApiCallResult {
         request_id: ctx.request_id().to_string(),
         api_calls: http.request_count(),
-        success: true,
+        success,
     })
 }
 
@@ -218,6 +239,7 @@ pub fn handle_api_call(adapter: &RequestAdapter) -> Result<ApiCallResult, Violat
 /// This handler demonstrates:
 /// - Authentication and authorization
 /// - Audit capability gating
+/// - Audited declassification of the user-supplied action and target
 /// - Structured audit event emission
 /// - Request-id inclusion in audit events
 ///
@@ -228,10 +250,7 @@ pub fn handle_api_call(adapter: &RequestAdapter) -> Result<ApiCallResult, Violat
 /// use policy_core::Principal;
 ///
 /// let mut adapter = RequestAdapter::new("req-admin-001".to_string());
-/// adapter.set_principal(Some(Principal {
-///     id: "admin-1".to_string(),
-///     name: "Admin User".to_string(),
-/// }));
+/// adapter.set_principal(Some(Principal::new("admin-1", "Admin User")));
 /// adapter.add_query_param("action".to_string(), "delete_user".to_string());
 /// adapter.add_query_param("target".to_string(), "user-999".to_string());
 ///
@@ -245,14 +264,17 @@ pub fn handle_admin_action(adapter: &RequestAdapter) -> Result<AdminActionResult
     // 2. Require admin-level authorization
     let meta = adapter.extract_metadata();
     let ctx = PolicyGate::new(meta)
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action("audit"))
         .require(Authorized::for_action("log"))
+        .require(Authorized::for_action(actions::DECLASSIFY))
         .build()?;
 
     // 3. Get audit capability
     let audit = ctx.audit()?;
     let logger = ctx.log()?;
+    let declassify = ctx.declassify()?;
 
     // 4. Sanitize inputs
     let sanitizer = StringSanitizer::new(100).unwrap();
@@ -261,13 +283,19 @@ pub fn handle_admin_action(adapter: &RequestAdapter) -> Result<AdminActionResult
         Violation::new(crate::error::ViolationKind::InvalidInput, "Missing action")
     })?;
 
-    let verified_action = sanitizer.sanitize(tainted_action)?;
+    let verified_action = declassify.unlabel(
+        tainted_action.sanitize(&sanitizer)?,
+        "admin action is recorded in the audit log",
+    )?;
 
     let tainted_target = extraction.inputs.get_query("target").ok_or_else(|| {
         Violation::new(crate::error::ViolationKind::InvalidInput, "Missing target")
     })?;
 
-    let verified_target = sanitizer.sanitize(tainted_target)?;
+    let verified_target = declassify.unlabel(
+        tainted_target.sanitize(&sanitizer)?,
+        "admin action target is recorded in the audit log",
+    )?;
 
     // 5. Log the action
     logger.info(format_args!(
@@ -313,6 +341,7 @@ pub struct AdminActionResult {
 mod tests {
     use super::*;
     use crate::request::Principal;
+    use crate::{Sink, VecSink, ViolationKind};
 
     #[test]
     fn public_search_works_without_auth() {
@@ -322,8 +351,12 @@ mod tests {
         let result = handle_public_search(&adapter).expect("should succeed");
 
         assert_eq!(result.request_id, "req-search-test");
-        assert_eq!(result.query, "test query"); // Trimmed
-        assert_eq!(result.result_count, 20); // 2 words * 10
+        assert_eq!(result.query.labels(), ["user"]);
+        assert_eq!(result.result_count.labels(), ["user"]);
+
+        let sink = VecSink::new();
+        sink.sink(&result.query).unwrap();
+        assert_eq!(sink.into_vec(), ["test query"]); // Trimmed
     }
 
     #[test]
@@ -345,10 +378,7 @@ mod tests {
     #[test]
     fn user_data_succeeds_with_auth() {
         let mut adapter = RequestAdapter::new("req-user-test".to_string());
-        adapter.set_principal(Some(Principal {
-            id: "user-123".to_string(),
-            name: "Test User".to_string(),
-        }));
+        adapter.set_principal(Some(Principal::new("user-123", "Test User")));
 
         let result = handle_user_data(&adapter).expect("should succeed");
 
@@ -369,10 +399,7 @@ mod tests {
     #[test]
     fn api_call_succeeds_with_auth_and_valid_url() {
         let mut adapter = RequestAdapter::new("req-api-test".to_string());
-        adapter.set_principal(Some(Principal {
-            id: "user-456".to_string(),
-            name: "API User".to_string(),
-        }));
+        adapter.set_principal(Some(Principal::new("user-456", "API User")));
         adapter.add_query_param(
             "url".to_string(),
             "https://api.example.com/data".to_string(),
@@ -385,6 +412,22 @@ mod tests {
         assert!(result.success);
     }
 
+    #[test]
+    fn api_call_rejects_non_https_url() {
+        for url in [
+            "javascript:alert(1)",
+            "file:///etc/passwd",
+            "http://example.com",
+        ] {
+            let mut adapter = RequestAdapter::new("req-api-bad-url".to_string());
+            adapter.set_principal(Some(Principal::new("user-456", "API User")));
+            adapter.add_query_param("url".to_string(), url.to_string());
+
+            let err = handle_api_call(&adapter).unwrap_err();
+            assert_eq!(err.kind, ViolationKind::InvalidInput, "{url}");
+        }
+    }
+
     #[test]
     fn admin_action_requires_auth() {
         let mut adapter = RequestAdapter::new("req-admin-no-auth".to_string());
@@ -398,10 +441,7 @@ mod tests {
     #[test]
     fn admin_action_succeeds_with_full_auth() {
         let mut adapter = RequestAdapter::new("req-admin-test".to_string());
-        adapter.set_principal(Some(Principal {
-            id: "admin-1".to_string(),
-            name: "Admin".to_string(),
-        }));
+        adapter.set_principal(Some(Principal::new("admin-1", "Admin")));
         adapter.add_query_param("action".to_string(), "disable_account".to_string());
         adapter.add_query_param("target".to_string(), "user-999".to_string());
 
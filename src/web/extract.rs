@@ -40,10 +40,7 @@ use super::TaintedInputs;
 ///     fn extract_metadata(&self) -> RequestMeta {
 ///         RequestMeta {
 ///             request_id: self.request_id.clone(),
-///             principal: self.user.as_ref().map(|u| Principal {
-///                 id: u.clone(),
-///                 name: u.clone(),
-///             }),
+///             principal: self.user.as_ref().map(|u| Principal::new(u.clone(), u.clone())),
 ///         }
 ///     }
 /// }
@@ -126,10 +123,10 @@ mod tests {
         fn extract_metadata(&self) -> RequestMeta {
             RequestMeta {
                 request_id: self.id.clone(),
-                principal: self.user.as_ref().map(|u| Principal {
-                    id: u.clone(),
-                    name: u.clone(),
-                }),
+                principal: self
+                    .user
+                    .as_ref()
+                    .map(|u| Principal::new(u.clone(), u.clone())),
             }
         }
     }
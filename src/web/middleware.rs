@@ -82,10 +82,7 @@ pub struct UnauthenticatedExtraction {
 /// use policy_core::Principal;
 ///
 /// let mut adapter = RequestAdapter::new("req-002".to_string());
-/// adapter.set_principal(Some(Principal {
-///     id: "user-1".to_string(),
-///     name: "Alice".to_string(),
-/// }));
+/// adapter.set_principal(Some(Principal::new("user-1", "Alice")));
 ///
 /// let extraction = extract_authed(&adapter).expect("principal present");
 /// assert_eq!(extraction.context.request_id(), "req-002");
@@ -178,10 +175,7 @@ pub fn extract_unauthed(adapter: &RequestAdapter) -> UnauthenticatedExtraction {
 ///
 /// // With principal - succeeds
 /// let mut adapter = RequestAdapter::new("req-auth".to_string());
-/// adapter.set_principal(Some(Principal {
-///     id: "user-1".to_string(),
-///     name: "Alice".to_string(),
-/// }));
+/// adapter.set_principal(Some(Principal::new("user-1", "Alice")));
 ///
 /// let extraction = extract_authed(&adapter).expect("should succeed");
 /// assert_eq!(extraction.context.principal().unwrap().id, "user-1");
@@ -240,10 +234,7 @@ mod tests {
     #[test]
     fn extract_authed_succeeds_with_principal() {
         let mut adapter = RequestAdapter::new("req-auth-test".to_string());
-        adapter.set_principal(Some(Principal {
-            id: "user-123".to_string(),
-            name: "Test User".to_string(),
-        }));
+        adapter.set_principal(Some(Principal::new("user-123", "Test User")));
 
         let extraction = extract_authed(&adapter).expect("should succeed with principal");
 
@@ -263,10 +254,7 @@ mod tests {
     #[test]
     fn extract_authed_includes_tainted_inputs() {
         let mut adapter = RequestAdapter::new("req-2".to_string());
-        adapter.set_principal(Some(Principal {
-            id: "user-1".to_string(),
-            name: "Alice".to_string(),
-        }));
+        adapter.set_principal(Some(Principal::new("user-1", "Alice")));
         adapter.add_query_param("filter".to_string(), "active".to_string());
         adapter.add_path_param("id".to_string(), "42".to_string());
 
@@ -289,10 +277,7 @@ mod tests {
     #[test]
     fn authed_context_has_no_capabilities() {
         let mut adapter = RequestAdapter::new("req-4".to_string());
-        adapter.set_principal(Some(Principal {
-            id: "user-1".to_string(),
-            name: "Bob".to_string(),
-        }));
+        adapter.set_principal(Some(Principal::new("user-1", "Bob")));
 
         let extraction = extract_authed(&adapter).expect("should succeed");
 
@@ -41,6 +41,7 @@
 //!
 //! // 2. Validate policies
 //! let ctx = PolicyGate::new(meta)
+//!     .with_authorizer(AllowAuthenticated)
 //!     .require(Authenticated)
 //!     .require(Authorized::for_action("http"))
 //!     .build()?;
@@ -51,7 +52,7 @@
 //!
 //! // 4. Perform authorized operation
 //! let http = ctx.http()?;
-//! http.get(&verified_url);
+//! let response = http.get(&verified_url)?;
 //! ```
 
 mod adapter;
@@ -4,8 +4,11 @@
 //! using property-based testing.
 
 use policy_core::{
-    actions, Authenticated, Authorized, PolicyGate, Principal, RequestMeta, Sanitizer,
-    StringSanitizer, Tainted,
+    actions, AllowAuthenticated, Authenticated, Authorized, EmailSanitizer, EnumSanitizer,
+    HtmlAttributeEncoder, HtmlEncoder, IdentifierSanitizer, IntegerSanitizer, JsonStringEncoder,
+    Normalization, PathSegmentEncoder, PatternSanitizer, PolicyGate, Principal, QueryValueEncoder,
+    RequestMeta, SanitizationErrorKind, Sanitizer, ShellQuoter, StringSanitizer, Tainted,
+    TimestampSanitizer, UuidSanitizer,
 };
 use proptest::prelude::*;
 
@@ -15,7 +18,7 @@ fn arb_principal() -> impl Strategy<Value = Principal> {
         prop::string::string_regex("[a-z0-9-]{3,10}").unwrap(),
         prop::string::string_regex("[A-Za-z ]{3,15}").unwrap(),
     )
-        .prop_map(|(id, name)| Principal { id, name })
+        .prop_map(|(id, name)| Principal::new(id, name))
 }
 
 // Strategy: Generate arbitrary action names
@@ -29,6 +32,141 @@ fn arb_action_name() -> impl Strategy<Value = &'static str> {
     ]
 }
 
+// Strategy: Generate well-formed email addresses
+fn arb_email() -> impl Strategy<Value = String> {
+    (
+        prop::string::string_regex("[A-Za-z0-9_%+-]{1,10}(\\.[A-Za-z0-9_%+-]{1,10}){0,2}").unwrap(),
+        prop::string::string_regex("[a-z0-9]([a-z0-9-]{0,8}[a-z0-9])?").unwrap(),
+        prop::string::string_regex("[a-z]{2,6}").unwrap(),
+    )
+        .prop_map(|(local, label, tld)| format!("{}@{}.{}", local, label, tld))
+}
+
+// Strategy: Generate characters that have special meaning in shells, SQL or markup
+fn arb_injection_char() -> impl Strategy<Value = char> {
+    prop::sample::select(vec![
+        '\'', '"', '`', ';', '|', '&', '$', '<', '>', '(', ')', '{', '}', '\\', '/', ' ',
+        '\u{202E}',
+    ])
+}
+
+// Strategy: Generate RFC 3339 timestamps with valid fields
+fn arb_timestamp() -> impl Strategy<Value = (u16, u8, u8, u8, u8, u8, i16)> {
+    (
+        0u16..=9999,
+        1u8..=12,
+        1u8..=28,
+        0u8..24,
+        0u8..60,
+        0u8..60,
+        -1439i16..=1439,
+    )
+}
+
+// Strategy: Generate text dense in characters that are special in some output context
+fn arb_hostile_text() -> impl Strategy<Value = String> {
+    prop::collection::vec(
+        prop_oneof![
+            arb_injection_char(),
+            prop::sample::select(vec![
+                '=', '#', '?', '%', '+', '.', '\n', '\t', '\u{1}', '\u{2028}'
+            ]),
+            any::<char>(),
+        ],
+        0..40,
+    )
+    .prop_map(|chars| chars.into_iter().collect())
+}
+
+/// Decodes the character references produced by the HTML encoders.
+fn decode_html(encoded: &str) -> Option<String> {
+    let mut out = String::new();
+    let mut rest = encoded;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        let end = rest[start..].find(';')? + start;
+        let decoded = match &rest[start + 1..end] {
+            "amp" => '&',
+            "lt" => '<',
+            "gt" => '>',
+            "quot" => '"',
+            hex => char::from_u32(u32::from_str_radix(hex.strip_prefix("#x")?, 16).ok()?)?,
+        };
+        out.push(decoded);
+        rest = &rest[end + 1..];
+    }
+    out.push_str(rest);
+    Some(out)
+}
+
+/// Decodes `%XX` escapes, rejecting any other reserved character.
+fn decode_percent(encoded: &str) -> Option<String> {
+    let bytes = encoded.as_bytes();
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' => {
+                out.push(u8::from_str_radix(encoded.get(i + 1..i + 3)?, 16).ok()?);
+                i += 3;
+            }
+            b if b.is_ascii_alphanumeric() || b"-._~".contains(&b) => {
+                out.push(b);
+                i += 1;
+            }
+            _ => return None,
+        }
+    }
+    String::from_utf8(out).ok()
+}
+
+/// Splits a POSIX shell word made of single-quoted runs and `\'`, returning
+/// `None` if anything else would be interpreted by the shell.
+fn parse_shell_word(encoded: &str) -> Option<String> {
+    let mut out = String::new();
+    let mut rest = encoded;
+    while !rest.is_empty() {
+        if let Some(quoted) = rest.strip_prefix('\'') {
+            let end = quoted.find('\'')?;
+            out.push_str(&quoted[..end]);
+            rest = &quoted[end + 1..];
+        } else {
+            rest = rest.strip_prefix("\\'")?;
+            out.push('\'');
+        }
+    }
+    Some(out)
+}
+
+/// Parses a JSON string literal, returning `None` if it ends early or has
+/// trailing content.
+fn parse_json_string(encoded: &str) -> Option<String> {
+    let mut chars = encoded.strip_prefix('"')?.chars();
+    let mut out = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return chars.next().is_none().then_some(out),
+            '\\' => out.push(match chars.next()? {
+                '"' => '"',
+                '\\' => '\\',
+                '/' => '/',
+                'n' => '\n',
+                'r' => '\r',
+                't' => '\t',
+                'b' => '\u{8}',
+                'f' => '\u{c}',
+                'u' => {
+                    let hex: String = chars.by_ref().take(4).collect();
+                    char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?
+                }
+                _ => return None,
+            }),
+            c if c < '\u{20}' => return None,
+            c => out.push(c),
+        }
+    }
+}
+
 proptest! {
     /// Property: End-to-end authorization flow never panics
     ///
@@ -48,6 +186,7 @@ proptest! {
 
         // Build gate with requirements
         let gate = PolicyGate::new(meta)
+            .with_authorizer(AllowAuthenticated)
             .require(Authenticated)
             .require(Authorized::for_action(action));
 
@@ -170,4 +309,257 @@ proptest! {
             test_string
         );
     }
+
+    /// Property: Bidi controls anywhere in the input are reported as such
+    ///
+    /// Trojan Source (CVE-2021-42574) hides code or file extensions behind
+    /// right-to-left overrides and isolates, which are not control characters.
+    #[test]
+    fn proptest_string_sanitizer_rejects_bidi_controls(
+        text in "[a-z]{1,20}",
+        bidi in prop_oneof![
+            Just('\u{061C}'),
+            prop::char::range('\u{200E}', '\u{200F}'),
+            prop::char::range('\u{202A}', '\u{202E}'),
+            prop::char::range('\u{2066}', '\u{2069}'),
+        ],
+        position in any::<prop::sample::Index>(),
+    ) {
+        let mut chars: Vec<char> = text.chars().collect();
+        chars.insert(position.index(chars.len() + 1), bidi);
+        let input: String = chars.into_iter().collect();
+
+        let sanitizer = StringSanitizer::default_limits().hardened();
+        let err = sanitizer.sanitize(Tainted::new(input)).unwrap_err();
+        prop_assert_eq!(err.kind(), SanitizationErrorKind::BidiControl);
+    }
+
+    /// Property: Normalized output is a fixed point of the sanitizer
+    #[test]
+    fn proptest_string_sanitizer_normalization_is_idempotent(
+        input in "\\PC{1,40}",
+        nfkc in any::<bool>(),
+    ) {
+        let form = if nfkc { Normalization::Nfkc } else { Normalization::Nfc };
+        let sanitizer = StringSanitizer::default_limits().with_normalization(form);
+
+        if let Ok(verified) = sanitizer.sanitize(Tainted::new(input)) {
+            let once = verified.into_inner();
+            let twice = sanitizer.sanitize(Tainted::new(once.clone())).unwrap();
+            prop_assert_eq!(twice.into_inner(), once);
+        }
+    }
+
+    /// Property: A deny rule rejects its pattern wherever it appears
+    #[test]
+    fn proptest_pattern_sanitizer_denies_anywhere(
+        prefix in "[a-z ]{0,20}",
+        suffix in "[a-z ]{0,20}",
+        tag in "(?i)script",
+    ) {
+        let sanitizer = PatternSanitizer::new()
+            .deny("script_tag", "(?i)<script")
+            .unwrap();
+        let input = format!("x{}<{}{}x", prefix, tag, suffix);
+
+        let err = sanitizer.sanitize(Tainted::new(input)).unwrap_err();
+        prop_assert_eq!(err.kind(), SanitizationErrorKind::ForbiddenPattern);
+        prop_assert_eq!(err.message(), "input matches forbidden pattern `script_tag`");
+    }
+
+    /// Property: Allow rules accept exactly the inputs they fully match
+    #[test]
+    fn proptest_pattern_sanitizer_allow_is_anchored(input in "[a-z0-9;]{1,20}") {
+        let sanitizer = PatternSanitizer::new().allow("slug", "[a-z0-9]+").unwrap();
+
+        let fully_matches = input.bytes().all(|b| b.is_ascii_alphanumeric());
+        prop_assert_eq!(sanitizer.sanitize(Tainted::new(input)).is_ok(), fully_matches);
+    }
+
+    /// Property: Well-formed email addresses are accepted with a lowercased domain
+    #[test]
+    fn proptest_email_sanitizer_accepts_well_formed(email in arb_email()) {
+        let (local, domain) = email.split_once('@').unwrap();
+        let input = format!("{}@{}", local, domain.to_uppercase());
+
+        let verified = EmailSanitizer::new().sanitize(Tainted::new(input)).unwrap();
+
+        // Only the domain is case-folded
+        prop_assert_eq!(verified.into_inner(), email);
+    }
+
+    /// Property: Injection characters anywhere in an address are rejected
+    #[test]
+    fn proptest_email_sanitizer_rejects_injection_chars(
+        email in arb_email(),
+        c in arb_injection_char(),
+        position in any::<prop::sample::Index>(),
+    ) {
+        let mut chars: Vec<char> = email.chars().collect();
+        // Insert inside the address so trimming cannot remove it
+        let index = 1 + position.index(chars.len() - 1);
+        chars.insert(index, c);
+        let input: String = chars.into_iter().collect();
+
+        let result = EmailSanitizer::new().sanitize(Tainted::new(input));
+        prop_assert!(result.is_err());
+    }
+
+    /// Property: Identifiers contain only the allowed charset
+    #[test]
+    fn proptest_identifier_sanitizer_output_charset(input in "\\PC{0,40}") {
+        let sanitizer = IdentifierSanitizer::new(32).unwrap();
+
+        if let Ok(verified) = sanitizer.sanitize(Tainted::new(input)) {
+            let value = verified.into_inner();
+            prop_assert!(!value.is_empty() && value.len() <= 32);
+            prop_assert!(value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-'));
+        }
+    }
+
+    /// Property: Slugs never start, end or repeat hyphens
+    #[test]
+    fn proptest_slug_sanitizer_shape(input in "[a-z0-9-]{1,20}") {
+        let result = IdentifierSanitizer::slug(20).unwrap().sanitize(Tainted::new(input.clone()));
+        let well_shaped = !input.starts_with('-') && !input.ends_with('-') && !input.contains("--");
+
+        prop_assert_eq!(result.is_ok(), well_shaped);
+    }
+
+    /// Property: Integers round-trip and are accepted exactly when in range
+    #[test]
+    fn proptest_integer_sanitizer_range(value in any::<i64>(), min in -1000i64..=0, max in 0i64..=1000) {
+        let sanitizer = IntegerSanitizer::new(min, max).unwrap();
+
+        match sanitizer.sanitize(Tainted::new(value.to_string())) {
+            Ok(verified) => prop_assert_eq!(verified.into_inner(), value),
+            Err(err) => {
+                prop_assert!(value < min || value > max);
+                prop_assert_eq!(err.kind(), SanitizationErrorKind::OutOfRange);
+            }
+        }
+    }
+
+    /// Property: Integer errors never echo the input
+    #[test]
+    fn proptest_integer_sanitizer_errors_never_leak_input(suffix in "[A-Z]{6,12}") {
+        let err = IntegerSanitizer::new(0u32, 10)
+            .unwrap()
+            .sanitize(Tainted::new(format!("1{}", suffix)))
+            .unwrap_err();
+
+        prop_assert_eq!(err.kind(), SanitizationErrorKind::MalformedInput);
+        prop_assert!(!err.to_string().contains(&suffix));
+    }
+
+    /// Property: Any hyphenated hex UUID is accepted and lowercased
+    #[test]
+    fn proptest_uuid_sanitizer_canonicalizes(bytes in any::<[u8; 16]>().prop_filter("non-nil", |b| b.iter().any(|&x| x != 0))) {
+        let hex: String = bytes.iter().map(|b| format!("{:02X}", b)).collect();
+        let uuid = format!("{}-{}-{}-{}-{}", &hex[0..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..32]);
+
+        let verified = UuidSanitizer::new().sanitize(Tainted::new(uuid.clone())).unwrap();
+        prop_assert_eq!(verified.into_inner(), uuid.to_lowercase());
+    }
+
+    /// Property: Valid timestamps parse and display back to the same instant
+    #[test]
+    fn proptest_timestamp_sanitizer_round_trips(
+        (year, month, day, hour, minute, second, offset) in arb_timestamp()
+    ) {
+        let zone = match offset {
+            0 => "Z".to_string(),
+            o => format!("{}{:02}:{:02}", if o < 0 { '-' } else { '+' }, o.unsigned_abs() / 60, o.unsigned_abs() % 60),
+        };
+        let input = format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}", year, month, day, hour, minute, second, zone);
+
+        let sanitizer = TimestampSanitizer::new();
+        let ts = sanitizer.sanitize(Tainted::new(input.clone())).unwrap().into_inner();
+        prop_assert_eq!(ts.to_string(), input);
+        prop_assert_eq!(ts.offset_minutes(), offset);
+
+        let again = sanitizer.sanitize(Tainted::new(ts.to_string())).unwrap().into_inner();
+        prop_assert_eq!(again.unix_timestamp(), ts.unix_timestamp());
+    }
+
+    /// Property: Only allow-listed values are accepted
+    #[test]
+    fn proptest_enum_sanitizer_allowlist(input in "[a-z]{1,6}") {
+        let sanitizer = EnumSanitizer::new([("asc", 1), ("desc", 2)]);
+
+        match sanitizer.sanitize(Tainted::new(input.clone())) {
+            Ok(verified) => prop_assert!(matches!((input.as_str(), verified.into_inner()), ("asc", 1) | ("desc", 2))),
+            Err(err) => {
+                prop_assert!(input != "asc" && input != "desc");
+                prop_assert_eq!(err.kind(), SanitizationErrorKind::NotAllowed);
+            }
+        }
+    }
+
+    /// Property: HTML-encoded text contains no markup or quote characters and decodes back
+    #[test]
+    fn proptest_html_encoder_cannot_break_out(input in arb_hostile_text()) {
+        let encoded = HtmlEncoder::new().sanitize(Tainted::new(input.clone())).unwrap().into_inner();
+
+        prop_assert!(!encoded.contains(['<', '>', '"', '\'']));
+        prop_assert_eq!(decode_html(&encoded), Some(input));
+    }
+
+    /// Property: Attribute-encoded text has no ASCII punctuation outside references
+    #[test]
+    fn proptest_html_attribute_encoder_cannot_break_out(input in arb_hostile_text()) {
+        let encoded = HtmlAttributeEncoder::new().sanitize(Tainted::new(input.clone())).unwrap().into_inner();
+
+        prop_assert!(encoded
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || !c.is_ascii() || matches!(c, '&' | '#' | ';')));
+        prop_assert_eq!(decode_html(&encoded), Some(input));
+    }
+
+    /// Property: An encoded path segment is one segment that decodes back
+    #[test]
+    fn proptest_path_segment_encoder_cannot_break_out(input in arb_hostile_text()) {
+        match PathSegmentEncoder::new().sanitize(Tainted::new(input.clone())) {
+            Ok(verified) => {
+                let encoded = verified.into_inner();
+                prop_assert!(encoded != "." && encoded != "..");
+                prop_assert_eq!(decode_percent(&encoded), Some(input));
+            }
+            Err(err) => {
+                prop_assert!(matches!(input.as_str(), "" | "." | ".."));
+                prop_assert_eq!(err.kind(), SanitizationErrorKind::ForbiddenPattern);
+            }
+        }
+    }
+
+    /// Property: An encoded query value has no delimiters and decodes back
+    #[test]
+    fn proptest_query_value_encoder_cannot_break_out(input in arb_hostile_text()) {
+        let encoded = QueryValueEncoder::new().sanitize(Tainted::new(input.clone())).unwrap().into_inner();
+
+        prop_assert!(!encoded.contains(['&', '=', '+', '#', ' ']));
+        prop_assert_eq!(decode_percent(&encoded), Some(input));
+    }
+
+    /// Property: Shell-quoted text is a single word with no expansions
+    #[test]
+    fn proptest_shell_quoter_cannot_break_out(input in arb_hostile_text()) {
+        match ShellQuoter::new().sanitize(Tainted::new(input.clone())) {
+            Ok(verified) => prop_assert_eq!(parse_shell_word(&verified.into_inner()), Some(input)),
+            Err(err) => {
+                prop_assert!(input.contains('\0'));
+                prop_assert_eq!(err.kind(), SanitizationErrorKind::ContainsControlChars);
+            }
+        }
+    }
+
+    /// Property: A JSON-encoded string is exactly one literal that decodes back
+    #[test]
+    fn proptest_json_string_encoder_cannot_break_out(input in arb_hostile_text()) {
+        let encoded = JsonStringEncoder::new().sanitize(Tainted::new(input.clone())).unwrap().into_inner();
+
+        let script_breakers = ['<', '>', '&', '\u{2028}', '\u{2029}'];
+        prop_assert!(!encoded.contains(script_breakers));
+        prop_assert_eq!(parse_json_string(&encoded), Some(input));
+    }
 }
@@ -7,7 +7,11 @@ use policy_core::web::example_handler::{
     handle_admin_action, handle_api_call, handle_public_search, handle_user_data,
 };
 use policy_core::web::{extract_authed, extract_unauthed, ExtractMetadata, RequestAdapter};
-use policy_core::{Authenticated, Authorized, PolicyGate, Principal, Sanitizer, StringSanitizer};
+use policy_core::{
+    actions, AllowAuthenticated, Authenticated, Authorized, EmailSanitizer, IdentifierSanitizer,
+    PolicyGate, Principal, Provenance, Sanitizer, Sink, StringSanitizer, UrlSanitizer, Validation,
+    VecSink, Violation, ViolationKind,
+};
 
 #[test]
 fn unauthed_extraction_full_flow() {
@@ -33,10 +37,7 @@ fn unauthed_extraction_full_flow() {
 fn authed_extraction_full_flow() {
     // Simulate an authenticated endpoint
     let mut adapter = RequestAdapter::new("req-authed-001".to_string());
-    adapter.set_principal(Some(Principal {
-        id: "user-alice".to_string(),
-        name: "Alice".to_string(),
-    }));
+    adapter.set_principal(Some(Principal::new("user-alice", "Alice")));
     adapter.add_query_param("filter".to_string(), "active".to_string());
 
     // Extract authenticated context
@@ -82,10 +83,7 @@ fn complete_flow_unauthed_to_authorized() {
 fn complete_flow_authed_to_authorized() {
     // 1. Extract authenticated context from request
     let mut adapter = RequestAdapter::new("req-flow-002".to_string());
-    adapter.set_principal(Some(Principal {
-        id: "user-bob".to_string(),
-        name: "Bob".to_string(),
-    }));
+    adapter.set_principal(Some(Principal::new("user-bob", "Bob")));
     adapter.add_query_param("url".to_string(), "https://example.com/api".to_string());
 
     let extraction = extract_authed(&adapter).expect("authentication succeeds");
@@ -96,8 +94,10 @@ fn complete_flow_authed_to_authorized() {
 
     // 3. Apply policies via PolicyGate to get Ctx<Authorized>
     let ctx = PolicyGate::new(meta)
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action("http"))
+        .require(Authorized::for_action(actions::DECLASSIFY))
         .build()
         .expect("policies satisfied");
 
@@ -109,14 +109,21 @@ fn complete_flow_authed_to_authorized() {
     // 5. Sanitize tainted inputs
     let tainted_url = extraction.inputs.get_query("url").unwrap().clone();
 
-    let sanitizer = StringSanitizer::new(256).unwrap();
-    let verified_url = sanitizer
-        .sanitize(tainted_url)
+    let sanitizer = UrlSanitizer::new();
+    let labeled_url = tainted_url
+        .sanitize(&sanitizer)
         .expect("sanitization succeeds");
 
-    // 6. Use capability-gated sink with verified input
+    // 6. The URL is user supplied; sending it out is an audited decision
+    let verified_url = ctx
+        .declassify()
+        .expect("DeclassifyCap granted")
+        .unlabel(labeled_url, "fetch the URL the user asked for")
+        .unwrap();
+
+    // 7. Use capability-gated sink with verified input
     let http = ctx.http().expect("HttpCap granted");
-    http.get(&verified_url); // This would make the HTTP call (simulated in our impl)
+    http.get(&verified_url).unwrap(); // This would make the HTTP call (simulated in our impl)
 
     // Success! Complete flow from extraction to authorized operation
 }
@@ -131,6 +138,7 @@ fn flow_fails_without_authentication() {
     let meta = adapter.extract_metadata();
 
     let result = PolicyGate::new(meta)
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action("log"))
         .build();
@@ -143,10 +151,7 @@ fn flow_fails_without_authentication() {
 fn flow_sanitization_required_for_sinks() {
     // 1. Extract context with tainted input
     let mut adapter = RequestAdapter::new("req-sanitize".to_string());
-    adapter.set_principal(Some(Principal {
-        id: "user-charlie".to_string(),
-        name: "Charlie".to_string(),
-    }));
+    adapter.set_principal(Some(Principal::new("user-charlie", "Charlie")));
     adapter.add_query_param("name".to_string(), "  Alice  ".to_string());
 
     let extraction = extract_authed(&adapter).expect("authenticated");
@@ -154,6 +159,7 @@ fn flow_sanitization_required_for_sinks() {
     // 2. Get authorized context
     let meta = adapter.extract_metadata();
     let ctx = PolicyGate::new(meta)
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action("http"))
         .build()
@@ -163,10 +169,13 @@ fn flow_sanitization_required_for_sinks() {
     let tainted_name = extraction.inputs.get_query("name").unwrap().clone();
 
     let sanitizer = StringSanitizer::new(100).unwrap();
-    let verified_name = sanitizer.sanitize(tainted_name).expect("valid");
+    let verified_name = tainted_name.sanitize(&sanitizer).expect("valid");
 
-    // Verified value is trimmed
-    assert_eq!(verified_name.as_ref(), "Alice");
+    // Verified value is trimmed, and still labeled as user supplied
+    let sink = VecSink::new();
+    sink.sink(&verified_name).unwrap();
+    assert_eq!(sink.into_vec(), ["Alice"]);
+    assert_eq!(verified_name.labels(), ["user"]);
 
     // 4. Can use verified value safely
     let http = ctx.http().unwrap();
@@ -199,10 +208,7 @@ fn request_id_flows_through_entire_chain() {
 
     // 1. Start with adapter
     let mut adapter = RequestAdapter::new(request_id.to_string());
-    adapter.set_principal(Some(Principal {
-        id: "user-1".to_string(),
-        name: "User".to_string(),
-    }));
+    adapter.set_principal(Some(Principal::new("user-1", "User")));
 
     // 2. Extract context
     let extraction = extract_authed(&adapter).expect("authenticated");
@@ -211,6 +217,7 @@ fn request_id_flows_through_entire_chain() {
     // 3. Build authorized context via PolicyGate
     let meta = adapter.extract_metadata();
     let ctx = PolicyGate::new(meta)
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action("log"))
         .require(Authorized::for_action("http"))
@@ -234,28 +241,34 @@ fn request_id_included_in_http_metadata() {
     let request_id = "req-http-001";
 
     let mut adapter = RequestAdapter::new(request_id.to_string());
-    adapter.set_principal(Some(Principal {
-        id: "user-1".to_string(),
-        name: "Alice".to_string(),
-    }));
+    adapter.set_principal(Some(Principal::new("user-1", "Alice")));
     adapter.add_query_param("url".to_string(), "https://api.example.com".to_string());
 
     let extraction = extract_authed(&adapter).expect("authenticated");
     let meta = adapter.extract_metadata();
 
     let ctx = PolicyGate::new(meta)
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action("http"))
+        .require(Authorized::for_action(actions::DECLASSIFY))
         .build()
         .expect("authorized");
 
     // Make HTTP request
     let http = ctx.http().expect("HttpCap granted");
-    let sanitizer = StringSanitizer::new(256).unwrap();
+    let sanitizer = UrlSanitizer::new();
     let tainted_url = extraction.inputs.get_query("url").unwrap().clone();
-    let verified_url = sanitizer.sanitize(tainted_url).expect("valid");
+    let verified_url = ctx
+        .declassify()
+        .unwrap()
+        .unlabel(
+            tainted_url.sanitize(&sanitizer).expect("valid"),
+            "test fetch",
+        )
+        .unwrap();
 
-    http.get(&verified_url);
+    http.get(&verified_url).unwrap();
 
     // Verify request ID is in recorded metadata
     http.with_requests(|requests| {
@@ -269,27 +282,25 @@ fn multiple_requests_preserve_request_id() {
     let request_id = "req-multi-001";
 
     let mut adapter = RequestAdapter::new(request_id.to_string());
-    adapter.set_principal(Some(Principal {
-        id: "user-1".to_string(),
-        name: "Bob".to_string(),
-    }));
+    adapter.set_principal(Some(Principal::new("user-1", "Bob")));
 
     let meta = adapter.extract_metadata();
     let ctx = PolicyGate::new(meta)
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action("http"))
         .build()
         .expect("authorized");
 
     let http = ctx.http().expect("HttpCap granted");
-    let sanitizer = StringSanitizer::new(256).unwrap();
+    let sanitizer = UrlSanitizer::new();
 
     // Make multiple HTTP requests
     for i in 1..=3 {
         let url = format!("https://api.example.com/{}", i);
         let tainted = policy_core::Tainted::new(url);
         let verified = sanitizer.sanitize(tainted).expect("valid");
-        http.get(&verified);
+        http.get(&verified).unwrap();
     }
 
     // All requests should have the same request ID
@@ -314,8 +325,12 @@ fn end_to_end_public_search_no_auth_required() {
     let result = handle_public_search(&adapter).expect("public endpoint succeeds");
 
     assert_eq!(result.request_id, "req-e2e-search");
-    assert_eq!(result.query, "policy enforcement"); // Trimmed by sanitizer
-    assert!(result.result_count > 0);
+    assert_eq!(result.query.labels(), ["user"]);
+    assert_eq!(result.result_count.labels(), ["user"]);
+
+    let sink = VecSink::new();
+    sink.sink(&result.query).unwrap();
+    assert_eq!(sink.into_vec(), ["policy enforcement"]); // Trimmed by sanitizer
 }
 
 #[test]
@@ -327,10 +342,7 @@ fn end_to_end_user_data_requires_auth() {
 
     // With authentication - succeeds
     let mut adapter = RequestAdapter::new("req-e2e-with-auth".to_string());
-    adapter.set_principal(Some(Principal {
-        id: "user-e2e-1".to_string(),
-        name: "EndToEnd User".to_string(),
-    }));
+    adapter.set_principal(Some(Principal::new("user-e2e-1", "EndToEnd User")));
 
     let result = handle_user_data(&adapter).expect("Should succeed with authentication");
     assert_eq!(result.request_id, "req-e2e-with-auth");
@@ -341,10 +353,7 @@ fn end_to_end_user_data_requires_auth() {
 fn end_to_end_api_call_full_flow() {
     // Set up authenticated request with tainted URL
     let mut adapter = RequestAdapter::new("req-e2e-api".to_string());
-    adapter.set_principal(Some(Principal {
-        id: "user-api".to_string(),
-        name: "API Caller".to_string(),
-    }));
+    adapter.set_principal(Some(Principal::new("user-api", "API Caller")));
     adapter.add_query_param(
         "url".to_string(),
         "  https://external-api.example.com/v1/users  ".to_string(),
@@ -366,10 +375,7 @@ fn end_to_end_api_call_full_flow() {
 fn end_to_end_admin_action_with_audit() {
     // Admin action requires authentication and audit capability
     let mut adapter = RequestAdapter::new("req-e2e-admin".to_string());
-    adapter.set_principal(Some(Principal {
-        id: "admin-e2e".to_string(),
-        name: "E2E Admin".to_string(),
-    }));
+    adapter.set_principal(Some(Principal::new("admin-e2e", "E2E Admin")));
     adapter.add_query_param("action".to_string(), "suspend_user".to_string());
     adapter.add_query_param("target".to_string(), "user-suspicious".to_string());
 
@@ -397,7 +403,9 @@ fn end_to_end_taint_prevents_injection() {
 
     // Sanitizer accepts the string but trims it
     // In a real SQL context, this would be parameterized or escaped
-    assert_eq!(result.query, "'; DROP TABLE users; --");
+    let sink = VecSink::new();
+    sink.sink(&result.query).unwrap();
+    assert_eq!(sink.into_vec(), ["'; DROP TABLE users; --"]);
     // The key point: this tainted input went through Verified<T>, enforcing conscious handling
 }
 
@@ -407,15 +415,13 @@ fn end_to_end_compile_time_rejection_documented() {
     // Actual compilation failures are tested in other modules
 
     let mut adapter = RequestAdapter::new("req-compile-test".to_string());
-    adapter.set_principal(Some(Principal {
-        id: "user-1".to_string(),
-        name: "Test".to_string(),
-    }));
+    adapter.set_principal(Some(Principal::new("user-1", "Test")));
 
     let meta = adapter.extract_metadata();
 
     // Build Ctx<Authorized> WITHOUT log capability
     let ctx = PolicyGate::new(meta)
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         // NOT requiring Authorized::for_action("log")
         .build()
@@ -435,10 +441,7 @@ fn end_to_end_compile_time_rejection_documented() {
 fn end_to_end_authorized_path_succeeds() {
     // Complete happy path: authentication → authorization → operation
     let mut adapter = RequestAdapter::new("req-happy-path".to_string());
-    adapter.set_principal(Some(Principal {
-        id: "user-happy".to_string(),
-        name: "Happy User".to_string(),
-    }));
+    adapter.set_principal(Some(Principal::new("user-happy", "Happy User")));
     adapter.add_query_param(
         "url".to_string(),
         "https://api.example.com/resource".to_string(),
@@ -449,9 +452,11 @@ fn end_to_end_authorized_path_succeeds() {
 
     // Apply all required policies
     let ctx = PolicyGate::new(meta)
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action("log"))
         .require(Authorized::for_action("http"))
+        .require(Authorized::for_action(actions::DECLASSIFY))
         .build()
         .expect("fully authorized");
 
@@ -463,12 +468,19 @@ fn end_to_end_authorized_path_succeeds() {
     let http = ctx.http().unwrap();
     let logger = ctx.log().unwrap();
 
-    let sanitizer = StringSanitizer::new(256).unwrap();
+    let sanitizer = UrlSanitizer::new();
     let tainted_url = extraction.inputs.get_query("url").unwrap().clone();
-    let verified_url = sanitizer.sanitize(tainted_url).expect("valid URL");
+    let verified_url = ctx
+        .declassify()
+        .unwrap()
+        .unlabel(
+            tainted_url.sanitize(&sanitizer).expect("valid URL"),
+            "test fetch",
+        )
+        .unwrap();
 
     logger.info(format_args!("Making request"));
-    http.get(&verified_url);
+    http.get(&verified_url).unwrap();
 
     assert_eq!(http.request_count(), 1);
 }
@@ -477,15 +489,13 @@ fn end_to_end_authorized_path_succeeds() {
 fn end_to_end_unauthorized_path_fails() {
     // Attempt to access privileged endpoint without authorization
     let mut adapter = RequestAdapter::new("req-unauthorized".to_string());
-    adapter.set_principal(Some(Principal {
-        id: "user-noauth".to_string(),
-        name: "Unauthorized User".to_string(),
-    }));
+    adapter.set_principal(Some(Principal::new("user-noauth", "Unauthorized User")));
 
     let meta = adapter.extract_metadata();
 
     // PolicyGate WITH authentication but WITHOUT authorization for "admin" actions
     let ctx = PolicyGate::new(meta)
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         // NOT requiring Authorized::for_action("audit")
         .build()
@@ -505,13 +515,11 @@ fn end_to_end_request_id_in_all_sinks() {
     let request_id = "req-e2e-all-sinks";
 
     let mut adapter = RequestAdapter::new(request_id.to_string());
-    adapter.set_principal(Some(Principal {
-        id: "user-1".to_string(),
-        name: "Test User".to_string(),
-    }));
+    adapter.set_principal(Some(Principal::new("user-1", "Test User")));
 
     let meta = adapter.extract_metadata();
     let ctx = PolicyGate::new(meta)
+        .with_authorizer(AllowAuthenticated)
         .require(Authenticated)
         .require(Authorized::for_action("log"))
         .require(Authorized::for_action("http"))
@@ -542,3 +550,67 @@ fn end_to_end_request_id_in_all_sinks() {
     audit.emit(&event);
     // Success - request-id propagated through all layers
 }
+
+#[test]
+fn form_validation_reports_every_invalid_field() {
+    let mut adapter = RequestAdapter::new("req-form-001".to_string());
+    adapter.add_query_param("username".to_string(), "alice smith".to_string());
+    adapter.add_query_param("email".to_string(), "alice@example.com".to_string());
+    adapter.add_query_param("bio".to_string(), "line one\nline two".to_string());
+
+    let extraction = extract_unauthed(&adapter);
+    let inputs = extraction.inputs;
+
+    let usernames = IdentifierSanitizer::new(32).unwrap();
+    let emails = EmailSanitizer::new();
+    let text = StringSanitizer::new(200).unwrap();
+
+    let mut validation = Validation::new();
+    let username = validation.required("username", inputs.get_query("username"), &usernames);
+    let email = validation.required("email", inputs.get_query("email"), &emails);
+    let bio = validation.optional("bio", inputs.get_query("bio"), &text);
+    let website = validation.required("website", inputs.get_query("website"), &text);
+
+    let report = validation
+        .finish((username, email, bio, website))
+        .unwrap_err();
+
+    // All three problems are reported at once, and none echo the input
+    assert_eq!(
+        report.fields().collect::<Vec<_>>(),
+        ["username", "bio", "website"]
+    );
+    assert!(!report.to_string().contains("alice smith"));
+
+    let violation = Violation::from(report);
+    assert_eq!(violation.kind, ViolationKind::InvalidInput);
+    assert!(violation.message.contains("username:"));
+    assert!(violation.message.contains("website: field is required"));
+}
+
+#[test]
+fn rejected_header_reports_its_input_channel() {
+    let mut adapter = RequestAdapter::new("req-prov-001".to_string());
+    adapter.add_header(
+        "x-forwarded-for".to_string(),
+        "10.0.0.1\r\nx-admin: true".to_string(),
+    );
+
+    let extraction = extract_unauthed(&adapter);
+    let forwarded = extraction.inputs.get_header("x-forwarded-for").unwrap();
+
+    let err = forwarded
+        .sanitize(&StringSanitizer::new(64).unwrap())
+        .unwrap_err();
+    let violation = Violation::from(err);
+
+    assert_eq!(violation.kind, ViolationKind::InvalidInput);
+    assert_eq!(
+        violation.provenance,
+        Some(Provenance::header("x-forwarded-for"))
+    );
+    assert!(violation
+        .to_string()
+        .ends_with("(from header 'x-forwarded-for')"));
+    assert!(!violation.to_string().contains("x-admin"));
+}